@@ -42,6 +42,8 @@ pub enum AddressAttribute {
     Broadcast(Ipv4Addr),
     /// IPv6 only
     Anycast(Ipv6Addr),
+    /// Lifetimes and timestamps, no reference count is carried even for
+    /// multicast and anycast addresses
     CacheInfo(CacheInfo),
     /// IPv6 only
     Multicast(Ipv6Addr),
@@ -5,6 +5,13 @@ use netlink_packet_utils::{
     DecodeError,
 };
 
+/// The `struct ifa_cacheinfo`. Lifetimes are in seconds with `u32::MAX`
+/// meaning forever, timestamps are in hundredths of a second since boot.
+///
+/// In the replies of `RTM_GETMULTICAST` and `RTM_GETANYCAST` dumps,
+/// `cstamp` and `tstamp` are the time the group was joined and last
+/// updated, and both lifetimes are always forever. The kernel does not
+/// report the reference count of multicast or anycast addresses.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 #[non_exhaustive]
 pub struct CacheInfo {
@@ -5,6 +5,9 @@ const AF_QIPCRTR: u8 = 42;
 const AF_SMC: u8 = 43;
 const AF_XDP: u8 = 44;
 const AF_MCTP: u8 = 45;
+// Not socket address families, only used by rtnetlink multicast routing
+const RTNL_FAMILY_IPMR: u8 = 128;
+const RTNL_FAMILY_IP6MR: u8 = 129;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
 #[non_exhaustive]
@@ -58,6 +61,10 @@ pub enum AddressFamily {
     Smc,
     Xdp,
     Mctp,
+    /// IPv4 multicast routing, `RTNL_FAMILY_IPMR`.
+    Ipmr,
+    /// IPv6 multicast routing, `RTNL_FAMILY_IP6MR`.
+    Ip6mr,
     Other(u8),
 }
 
@@ -199,6 +206,8 @@ impl From<u8> for AddressFamily {
             d if d == AF_SMC => Self::Smc,
             d if d == AF_XDP => Self::Xdp,
             d if d == AF_MCTP => Self::Mctp,
+            d if d == RTNL_FAMILY_IPMR => Self::Ipmr,
+            d if d == RTNL_FAMILY_IP6MR => Self::Ip6mr,
             _ => Self::Other(d),
         }
     }
@@ -298,6 +307,8 @@ impl From<AddressFamily> for u8 {
             AddressFamily::Smc => AF_SMC,
             AddressFamily::Xdp => AF_XDP,
             AddressFamily::Mctp => AF_MCTP,
+            AddressFamily::Ipmr => RTNL_FAMILY_IPMR,
+            AddressFamily::Ip6mr => RTNL_FAMILY_IP6MR,
             AddressFamily::Other(d) => d,
         }
     }
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::Ipv6Addr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const IFAL_ADDRESS: u16 = 1;
+const IFAL_LABEL: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum AddressLabelAttribute {
+    /// Prefix of the label policy entry
+    Address(Ipv6Addr),
+    /// Label used by RFC 6724 source and destination address selection
+    Label(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for AddressLabelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Address(_) => IPV6_ADDR_LEN,
+            Self::Label(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Address(addr) => buffer.copy_from_slice(&addr.octets()),
+            Self::Label(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Address(_) => IFAL_ADDRESS,
+            Self::Label(_) => IFAL_LABEL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for AddressLabelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFAL_ADDRESS => Self::Address(parse_ipv6_addr(payload)?),
+            IFAL_LABEL => Self::Label(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
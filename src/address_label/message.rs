@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::address_label::{
+    AddressLabelAttribute, AddressLabelHeader, AddressLabelMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct AddressLabelMessage {
+    pub header: AddressLabelHeader,
+    pub attributes: Vec<AddressLabelAttribute>,
+}
+
+impl Emitable for AddressLabelMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressLabelMessageBuffer<&'a T>>
+    for AddressLabelMessage
+{
+    fn parse(
+        buf: &AddressLabelMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let header = AddressLabelHeader::parse(buf)?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(AddressLabelAttribute::parse(&nla_buf?)?);
+        }
+        Ok(AddressLabelMessage { header, attributes })
+    }
+}
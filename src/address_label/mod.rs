@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+
+pub use self::attribute::AddressLabelAttribute;
+pub use self::header::{AddressLabelHeader, AddressLabelMessageBuffer};
+pub use self::message::AddressLabelMessage;
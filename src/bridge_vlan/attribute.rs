@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::bridge_vlan::{BridgeVlanEntryAttribute, BridgeVlanGlobalAttribute};
+
+const BRIDGE_VLANDB_ENTRY: u16 = 1;
+const BRIDGE_VLANDB_GLOBAL_OPTIONS: u16 = 2;
+
+const BRIDGE_VLANDB_DUMP_FLAGS: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanAttribute {
+    /// VLAN or VLAN range of the bridge or bridge port
+    Entry(Vec<BridgeVlanEntryAttribute>),
+    /// Global options of VLAN or VLAN range, only for bridge.
+    GlobalOptions(Vec<BridgeVlanGlobalAttribute>),
+    /// Used by `RTM_GETVLAN` dump request only.
+    DumpFlags(BridgeVlanDumpFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(attrs) => attrs.as_slice().buffer_len(),
+            Self::GlobalOptions(attrs) => attrs.as_slice().buffer_len(),
+            Self::DumpFlags(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(attrs) => attrs.as_slice().emit(buffer),
+            Self::GlobalOptions(attrs) => attrs.as_slice().emit(buffer),
+            Self::DumpFlags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => BRIDGE_VLANDB_ENTRY | NLA_F_NESTED,
+            Self::GlobalOptions(_) => {
+                BRIDGE_VLANDB_GLOBAL_OPTIONS | NLA_F_NESTED
+            }
+            Self::DumpFlags(_) => BRIDGE_VLANDB_DUMP_FLAGS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl BridgeVlanAttribute {
+    pub(crate) fn parse_with_param<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        is_dump_request: bool,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match (buf.kind(), is_dump_request) {
+            (BRIDGE_VLANDB_DUMP_FLAGS, true) => Self::DumpFlags(
+                BridgeVlanDumpFlags::from_bits_retain(parse_u32(payload)?),
+            ),
+            (BRIDGE_VLANDB_ENTRY, false) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(BridgeVlanEntryAttribute::parse(&nla?)?);
+                }
+                Self::Entry(attrs)
+            }
+            (BRIDGE_VLANDB_GLOBAL_OPTIONS, false) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(BridgeVlanGlobalAttribute::parse(&nla?)?);
+                }
+                Self::GlobalOptions(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const BRIDGE_VLANDB_DUMPF_STATS: u32 = 1 << 0;
+const BRIDGE_VLANDB_DUMPF_GLOBAL: u32 = 1 << 1;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct BridgeVlanDumpFlags: u32 {
+        /// Include [crate::bridge_vlan::BridgeVlanEntryAttribute::Stats]
+        const Stats = BRIDGE_VLANDB_DUMPF_STATS;
+        /// Dump [BridgeVlanAttribute::GlobalOptions] instead of entries
+        const Global = BRIDGE_VLANDB_DUMPF_GLOBAL;
+        const _ = !0;
+    }
+}
+
+impl Default for BridgeVlanDumpFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
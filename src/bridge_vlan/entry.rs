@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::{BridgePortMulticastRouter, BridgePortState, BridgeVlanInfo};
+
+const BRIDGE_VLANDB_ENTRY_INFO: u16 = 1;
+const BRIDGE_VLANDB_ENTRY_RANGE: u16 = 2;
+const BRIDGE_VLANDB_ENTRY_STATE: u16 = 3;
+const BRIDGE_VLANDB_ENTRY_TUNNEL_INFO: u16 = 4;
+const BRIDGE_VLANDB_ENTRY_STATS: u16 = 5;
+const BRIDGE_VLANDB_ENTRY_MCAST_ROUTER: u16 = 6;
+const BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS: u16 = 7;
+const BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS: u16 = 8;
+const BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS: u16 = 9;
+
+/// Member of [crate::bridge_vlan::BridgeVlanAttribute::Entry].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanEntryAttribute {
+    /// VLAN ID and flags, the first VLAN of range if [Self::Range] exists.
+    Info(BridgeVlanInfo),
+    /// The last VLAN ID of range
+    Range(u16),
+    /// Per-VLAN STP state
+    State(BridgePortState),
+    TunnelInfo(Vec<BridgeVlanTunnelAttribute>),
+    Stats(Vec<BridgeVlanStatsAttribute>),
+    MulticastRouter(BridgePortMulticastRouter),
+    MulticastNumGroups(u32),
+    MulticastMaxGroups(u32),
+    NeighSuppress(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Info(_) => 4,
+            Self::Range(_) => 2,
+            Self::State(_)
+            | Self::MulticastRouter(_)
+            | Self::NeighSuppress(_) => 1,
+            Self::TunnelInfo(attrs) => attrs.as_slice().buffer_len(),
+            Self::Stats(attrs) => attrs.as_slice().buffer_len(),
+            Self::MulticastNumGroups(_) | Self::MulticastMaxGroups(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Info(info) => {
+                buffer[..4].copy_from_slice(<[u8; 4]>::from(info).as_slice())
+            }
+            Self::Range(v) => NativeEndian::write_u16(buffer, *v),
+            Self::State(v) => buffer[0] = (*v).into(),
+            Self::TunnelInfo(attrs) => attrs.as_slice().emit(buffer),
+            Self::Stats(attrs) => attrs.as_slice().emit(buffer),
+            Self::MulticastRouter(v) => buffer[0] = (*v).into(),
+            Self::MulticastNumGroups(v) | Self::MulticastMaxGroups(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::NeighSuppress(v) => buffer[0] = *v as u8,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Info(_) => BRIDGE_VLANDB_ENTRY_INFO,
+            Self::Range(_) => BRIDGE_VLANDB_ENTRY_RANGE,
+            Self::State(_) => BRIDGE_VLANDB_ENTRY_STATE,
+            Self::TunnelInfo(_) => {
+                BRIDGE_VLANDB_ENTRY_TUNNEL_INFO | NLA_F_NESTED
+            }
+            Self::Stats(_) => BRIDGE_VLANDB_ENTRY_STATS | NLA_F_NESTED,
+            Self::MulticastRouter(_) => BRIDGE_VLANDB_ENTRY_MCAST_ROUTER,
+            Self::MulticastNumGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS,
+            Self::MulticastMaxGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS,
+            Self::NeighSuppress(_) => BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanEntryAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY_INFO => {
+                Self::Info(BridgeVlanInfo::try_from(payload)?)
+            }
+            BRIDGE_VLANDB_ENTRY_RANGE => Self::Range(parse_u16(payload)?),
+            BRIDGE_VLANDB_ENTRY_STATE => Self::State(parse_u8(payload)?.into()),
+            BRIDGE_VLANDB_ENTRY_TUNNEL_INFO => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(BridgeVlanTunnelAttribute::parse(&nla?)?);
+                }
+                Self::TunnelInfo(attrs)
+            }
+            BRIDGE_VLANDB_ENTRY_STATS => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(BridgeVlanStatsAttribute::parse(&nla?)?);
+                }
+                Self::Stats(attrs)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_ROUTER => {
+                Self::MulticastRouter(parse_u8(payload)?.into())
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS => {
+                Self::MulticastNumGroups(parse_u32(payload)?)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS => {
+                Self::MulticastMaxGroups(parse_u32(payload)?)
+            }
+            BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS => {
+                Self::NeighSuppress(parse_u8(payload)? > 0)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const BRIDGE_VLANDB_TINFO_ID: u16 = 1;
+const BRIDGE_VLANDB_TINFO_CMD: u16 = 2;
+
+/// Member of [BridgeVlanEntryAttribute::TunnelInfo], the VLAN to tunnel
+/// ID mapping of bridge port with `vlan_tunnel` enabled.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanTunnelAttribute {
+    Id(u32),
+    /// `RTM_SETLINK` to add the mapping or `RTM_DELLINK` to remove it,
+    /// used by requests only.
+    Command(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanTunnelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) | Self::Command(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) | Self::Command(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => BRIDGE_VLANDB_TINFO_ID,
+            Self::Command(_) => BRIDGE_VLANDB_TINFO_CMD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanTunnelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_TINFO_ID => Self::Id(parse_u32(payload)?),
+            BRIDGE_VLANDB_TINFO_CMD => Self::Command(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const BRIDGE_VLANDB_STATS_RX_BYTES: u16 = 1;
+const BRIDGE_VLANDB_STATS_RX_PACKETS: u16 = 2;
+const BRIDGE_VLANDB_STATS_TX_BYTES: u16 = 3;
+const BRIDGE_VLANDB_STATS_TX_PACKETS: u16 = 4;
+// const BRIDGE_VLANDB_STATS_PAD: u16 = 5;
+
+/// Member of [BridgeVlanEntryAttribute::Stats].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BridgeVlanStatsAttribute {
+    RxBytes(u64),
+    RxPackets(u64),
+    TxBytes(u64),
+    TxPackets(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeVlanStatsAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::RxBytes(_)
+            | Self::RxPackets(_)
+            | Self::TxBytes(_)
+            | Self::TxPackets(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RxBytes(v)
+            | Self::RxPackets(v)
+            | Self::TxBytes(v)
+            | Self::TxPackets(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RxBytes(_) => BRIDGE_VLANDB_STATS_RX_BYTES,
+            Self::RxPackets(_) => BRIDGE_VLANDB_STATS_RX_PACKETS,
+            Self::TxBytes(_) => BRIDGE_VLANDB_STATS_TX_BYTES,
+            Self::TxPackets(_) => BRIDGE_VLANDB_STATS_TX_PACKETS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for BridgeVlanStatsAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_STATS_RX_BYTES => Self::RxBytes(parse_u64(payload)?),
+            BRIDGE_VLANDB_STATS_RX_PACKETS => {
+                Self::RxPackets(parse_u64(payload)?)
+            }
+            BRIDGE_VLANDB_STATS_TX_BYTES => Self::TxBytes(parse_u64(payload)?),
+            BRIDGE_VLANDB_STATS_TX_PACKETS => {
+                Self::TxPackets(parse_u64(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
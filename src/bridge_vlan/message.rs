@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::bridge_vlan::{
+    BridgeVlanAttribute, BridgeVlanHeader, BridgeVlanMessageBuffer,
+};
+
+const RTM_GETVLAN: u16 = 114;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct BridgeVlanMessage {
+    pub header: BridgeVlanHeader,
+    pub attributes: Vec<BridgeVlanAttribute>,
+}
+
+impl Emitable for BridgeVlanMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+/// The parameter is the netlink message type, as the attributes of
+/// `RTM_GETVLAN` dump requests differ from `RTM_NEWVLAN` and `RTM_DELVLAN`.
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<BridgeVlanMessageBuffer<&'a T>, u16>
+    for BridgeVlanMessage
+{
+    fn parse_with_param(
+        buf: &BridgeVlanMessageBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        let header = BridgeVlanHeader::parse(buf)?;
+        let is_dump_request = message_type == RTM_GETVLAN;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(BridgeVlanAttribute::parse_with_param(
+                &nla_buf?,
+                is_dump_request,
+            )?);
+        }
+        Ok(BridgeVlanMessage { header, attributes })
+    }
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod entry;
+mod global;
+mod header;
+mod message;
+
+pub use self::attribute::{BridgeVlanAttribute, BridgeVlanDumpFlags};
+pub use self::entry::{
+    BridgeVlanEntryAttribute, BridgeVlanStatsAttribute,
+    BridgeVlanTunnelAttribute,
+};
+pub use self::global::BridgeVlanGlobalAttribute;
+pub use self::header::{BridgeVlanHeader, BridgeVlanMessageBuffer};
+pub use self::message::BridgeVlanMessage;
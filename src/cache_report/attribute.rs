@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::IpAddr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{emit_ip_addr, ip_addr_len, parse_ip_addr};
+
+// IPMRA_CREPORT_* and IP6MRA_CREPORT_* share the same values
+const IPMRA_CREPORT_MSGTYPE: u16 = 1;
+const IPMRA_CREPORT_VIF_ID: u16 = 2;
+const IPMRA_CREPORT_SRC_ADDR: u16 = 3;
+const IPMRA_CREPORT_DST_ADDR: u16 = 4;
+const IPMRA_CREPORT_PKT: u16 = 5;
+const IPMRA_CREPORT_TABLE: u16 = 6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum CacheReportAttribute {
+    MessageType(CacheReportMessageType),
+    /// Index of the virtual interface the packet arrived on. Kernel
+    /// constant name is `IPMRA_CREPORT_VIF_ID` or `IP6MRA_CREPORT_MIF_ID`.
+    VifId(u32),
+    SourceAddress(IpAddr),
+    DestinationAddress(IpAddr),
+    /// The packet triggered this report. For
+    /// [CacheReportMessageType::NoCache] and
+    /// [CacheReportMessageType::WrongVif], only the network header is
+    /// included.
+    Packet(Vec<u8>),
+    /// Multicast routing table ID, IPv4 only.
+    Table(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for CacheReportAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::MessageType(_) => 1,
+            Self::VifId(_) | Self::Table(_) => 4,
+            Self::SourceAddress(addr) | Self::DestinationAddress(addr) => {
+                ip_addr_len(addr)
+            }
+            Self::Packet(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::MessageType(v) => buffer[0] = (*v).into(),
+            Self::VifId(v) | Self::Table(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::SourceAddress(addr) | Self::DestinationAddress(addr) => {
+                emit_ip_addr(addr, buffer)
+            }
+            Self::Packet(v) => buffer[..v.len()].copy_from_slice(v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::MessageType(_) => IPMRA_CREPORT_MSGTYPE,
+            Self::VifId(_) => IPMRA_CREPORT_VIF_ID,
+            Self::SourceAddress(_) => IPMRA_CREPORT_SRC_ADDR,
+            Self::DestinationAddress(_) => IPMRA_CREPORT_DST_ADDR,
+            Self::Packet(_) => IPMRA_CREPORT_PKT,
+            Self::Table(_) => IPMRA_CREPORT_TABLE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for CacheReportAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IPMRA_CREPORT_MSGTYPE => {
+                Self::MessageType(parse_u8(payload)?.into())
+            }
+            IPMRA_CREPORT_VIF_ID => Self::VifId(parse_u32(payload)?),
+            IPMRA_CREPORT_SRC_ADDR => {
+                Self::SourceAddress(parse_ip_addr(payload)?)
+            }
+            IPMRA_CREPORT_DST_ADDR => {
+                Self::DestinationAddress(parse_ip_addr(payload)?)
+            }
+            IPMRA_CREPORT_PKT => Self::Packet(payload.to_vec()),
+            IPMRA_CREPORT_TABLE => Self::Table(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+// IGMPMSG_* and MRT6MSG_* share the same values
+const IGMPMSG_NOCACHE: u8 = 1;
+const IGMPMSG_WRONGVIF: u8 = 2;
+const IGMPMSG_WHOLEPKT: u8 = 3;
+const IGMPMSG_WRVIFWHOLE: u8 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum CacheReportMessageType {
+    /// No multicast forwarding cache entry for the packet.
+    #[default]
+    NoCache,
+    /// Packet arrived on an interface other than the incoming interface
+    /// of the cache entry. Kernel constant name is `IGMPMSG_WRONGVIF` or
+    /// `MRT6MSG_WRONGMIF`.
+    WrongVif,
+    /// Whole packet received on PIM register interface.
+    WholePacket,
+    /// Combination of [Self::WrongVif] and [Self::WholePacket], kernel
+    /// constant name is `IGMPMSG_WRVIFWHOLE` or `MRT6MSG_WRMIFWHOLE`.
+    WrongVifWhole,
+    Other(u8),
+}
+
+impl From<u8> for CacheReportMessageType {
+    fn from(d: u8) -> Self {
+        match d {
+            IGMPMSG_NOCACHE => Self::NoCache,
+            IGMPMSG_WRONGVIF => Self::WrongVif,
+            IGMPMSG_WHOLEPKT => Self::WholePacket,
+            IGMPMSG_WRVIFWHOLE => Self::WrongVifWhole,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<CacheReportMessageType> for u8 {
+    fn from(v: CacheReportMessageType) -> u8 {
+        match v {
+            CacheReportMessageType::NoCache => IGMPMSG_NOCACHE,
+            CacheReportMessageType::WrongVif => IGMPMSG_WRONGVIF,
+            CacheReportMessageType::WholePacket => IGMPMSG_WHOLEPKT,
+            CacheReportMessageType::WrongVifWhole => IGMPMSG_WRVIFWHOLE,
+            CacheReportMessageType::Other(d) => d,
+        }
+    }
+}
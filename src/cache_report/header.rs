@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+// The `struct rtgenmsg` holds a single byte, but netlink aligns the
+// payload to 4 bytes.
+const CACHE_REPORT_HEADER_LEN: usize = 4;
+
+buffer!(CacheReportMessageBuffer(CACHE_REPORT_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..CACHE_REPORT_HEADER_LEN),
+    payload: (slice, CACHE_REPORT_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> CacheReportMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_NEWCACHEREPORT` message header
+/// (`struct rtgenmsg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct CacheReportHeader {
+    /// [AddressFamily::Ipmr] for IGMP messages, [AddressFamily::Ip6mr] for
+    /// MLD messages.
+    pub family: AddressFamily,
+}
+
+impl Emitable for CacheReportHeader {
+    fn buffer_len(&self) -> usize {
+        CACHE_REPORT_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = CacheReportMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<CacheReportMessageBuffer<T>>
+    for CacheReportHeader
+{
+    fn parse(buf: &CacheReportMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(CacheReportHeader {
+            family: buf.family().into(),
+        })
+    }
+}
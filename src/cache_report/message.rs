@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::cache_report::{
+    CacheReportAttribute, CacheReportHeader, CacheReportMessageBuffer,
+};
+
+/// Report of multicast routing daemon upcall(`struct igmpmsg` or
+/// `struct mrt6msg`), sent by kernel to `RTNLGRP_IPV4_MROUTE_R` and
+/// `RTNLGRP_IPV6_MROUTE_R` groups.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct CacheReportMessage {
+    pub header: CacheReportHeader,
+    pub attributes: Vec<CacheReportAttribute>,
+}
+
+impl Emitable for CacheReportMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<CacheReportMessageBuffer<&'a T>>
+    for CacheReportMessage
+{
+    fn parse(
+        buf: &CacheReportMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let header = CacheReportHeader::parse(buf)?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(CacheReportAttribute::parse(&nla_buf?)?);
+        }
+        Ok(CacheReportMessage { header, attributes })
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+
+pub use self::attribute::{CacheReportAttribute, CacheReportMessageType};
+pub use self::header::{CacheReportHeader, CacheReportMessageBuffer};
+pub use self::message::CacheReportMessage;
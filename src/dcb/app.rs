@@ -0,0 +1,300 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const DCB_APP_ATTR_IDTYPE: u16 = 1;
+const DCB_APP_ATTR_ID: u16 = 2;
+const DCB_APP_ATTR_PRIORITY: u16 = 3;
+
+/// Member of [crate::dcb::DcbAttribute::App].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbAppAttribute {
+    IdType(DcbAppIdType),
+    /// Ethertype or port number depending on [Self::IdType]
+    Id(u16),
+    /// Bitmap of user priorities
+    Priority(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbAppAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IdType(_) | Self::Priority(_) => 1,
+            Self::Id(_) => 2,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IdType(v) => buffer[0] = (*v).into(),
+            Self::Id(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Priority(v) => buffer[0] = *v,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IdType(_) => DCB_APP_ATTR_IDTYPE,
+            Self::Id(_) => DCB_APP_ATTR_ID,
+            Self::Priority(_) => DCB_APP_ATTR_PRIORITY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbAppAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_APP_ATTR_IDTYPE => Self::IdType(parse_u8(payload)?.into()),
+            DCB_APP_ATTR_ID => Self::Id(parse_u16(payload)?),
+            DCB_APP_ATTR_PRIORITY => Self::Priority(parse_u8(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const DCB_APP_IDTYPE_ETHTYPE: u8 = 0;
+const DCB_APP_IDTYPE_PORTNUM: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum DcbAppIdType {
+    #[default]
+    Ethertype,
+    PortNumber,
+    Other(u8),
+}
+
+impl From<u8> for DcbAppIdType {
+    fn from(d: u8) -> Self {
+        match d {
+            DCB_APP_IDTYPE_ETHTYPE => Self::Ethertype,
+            DCB_APP_IDTYPE_PORTNUM => Self::PortNumber,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<DcbAppIdType> for u8 {
+    fn from(v: DcbAppIdType) -> u8 {
+        match v {
+            DcbAppIdType::Ethertype => DCB_APP_IDTYPE_ETHTYPE,
+            DcbAppIdType::PortNumber => DCB_APP_IDTYPE_PORTNUM,
+            DcbAppIdType::Other(d) => d,
+        }
+    }
+}
+
+const DCB_APP_LEN: usize = 4;
+
+buffer!(DcbAppBuffer(DCB_APP_LEN) {
+    selector: (u8, 0),
+    priority: (u8, 1),
+    protocol: (u16, 2..DCB_APP_LEN),
+});
+
+/// The `struct dcb_app`, an entry of IEEE application priority table.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbApp {
+    pub selector: DcbAppSelector,
+    pub priority: u8,
+    /// Protocol identifier depending on [Self::selector], like ethertype,
+    /// port number or DSCP value.
+    pub protocol: u16,
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<DcbAppBuffer<&T>> for DcbApp {
+    fn parse(buf: &DcbAppBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            selector: buf.selector().into(),
+            priority: buf.priority(),
+            protocol: buf.protocol(),
+        })
+    }
+}
+
+impl Emitable for DcbApp {
+    fn buffer_len(&self) -> usize {
+        DCB_APP_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbAppBuffer::new(buffer);
+        buffer.set_selector(self.selector.into());
+        buffer.set_priority(self.priority);
+        buffer.set_protocol(self.protocol);
+    }
+}
+
+const IEEE_8021QAZ_APP_SEL_ETHERTYPE: u8 = 1;
+const IEEE_8021QAZ_APP_SEL_STREAM: u8 = 2;
+const IEEE_8021QAZ_APP_SEL_DGRAM: u8 = 3;
+const IEEE_8021QAZ_APP_SEL_ANY: u8 = 4;
+const IEEE_8021QAZ_APP_SEL_DSCP: u8 = 5;
+const DCB_APP_SEL_PCP: u8 = 255;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum DcbAppSelector {
+    #[default]
+    Ethertype,
+    /// TCP or SCTP port
+    Stream,
+    /// UDP or DCCP port
+    Dgram,
+    /// TCP, SCTP, UDP or DCCP port
+    Any,
+    Dscp,
+    /// PCP and DEI, not defined by IEEE.
+    Pcp,
+    Other(u8),
+}
+
+impl From<u8> for DcbAppSelector {
+    fn from(d: u8) -> Self {
+        match d {
+            IEEE_8021QAZ_APP_SEL_ETHERTYPE => Self::Ethertype,
+            IEEE_8021QAZ_APP_SEL_STREAM => Self::Stream,
+            IEEE_8021QAZ_APP_SEL_DGRAM => Self::Dgram,
+            IEEE_8021QAZ_APP_SEL_ANY => Self::Any,
+            IEEE_8021QAZ_APP_SEL_DSCP => Self::Dscp,
+            DCB_APP_SEL_PCP => Self::Pcp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<DcbAppSelector> for u8 {
+    fn from(v: DcbAppSelector) -> u8 {
+        match v {
+            DcbAppSelector::Ethertype => IEEE_8021QAZ_APP_SEL_ETHERTYPE,
+            DcbAppSelector::Stream => IEEE_8021QAZ_APP_SEL_STREAM,
+            DcbAppSelector::Dgram => IEEE_8021QAZ_APP_SEL_DGRAM,
+            DcbAppSelector::Any => IEEE_8021QAZ_APP_SEL_ANY,
+            DcbAppSelector::Dscp => IEEE_8021QAZ_APP_SEL_DSCP,
+            DcbAppSelector::Pcp => DCB_APP_SEL_PCP,
+            DcbAppSelector::Other(d) => d,
+        }
+    }
+}
+
+const DCB_ATTR_IEEE_APP: u16 = 1;
+const DCB_ATTR_DCB_APP: u16 = 2;
+
+/// Member of [crate::dcb::DcbIeeeAttribute::AppTable],
+/// [crate::dcb::DcbIeeeAttribute::PeerApp] and
+/// [crate::dcb::DcbIeeeAttribute::RewriteTable].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbIeeeAppAttribute {
+    /// Entry with selector defined by IEEE 802.1Q
+    Ieee(DcbApp),
+    /// Entry with selector not defined by IEEE, like
+    /// [DcbAppSelector::Pcp].
+    Dcb(DcbApp),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbIeeeAppAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ieee(v) | Self::Dcb(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ieee(v) | Self::Dcb(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ieee(_) => DCB_ATTR_IEEE_APP,
+            Self::Dcb(_) => DCB_ATTR_DCB_APP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbIeeeAppAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_ATTR_IEEE_APP => {
+                Self::Ieee(DcbApp::parse(&DcbAppBuffer::new_checked(payload)?)?)
+            }
+            DCB_ATTR_DCB_APP => {
+                Self::Dcb(DcbApp::parse(&DcbAppBuffer::new_checked(payload)?)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// Member of [crate::dcb::DcbIeeeAttribute::AppTrustTable], the selectors
+/// trusted for classifying packets, in order of precedence.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbAppTrustAttribute {
+    /// Selector defined by IEEE 802.1Q
+    Ieee(DcbAppSelector),
+    /// Selector not defined by IEEE, like [DcbAppSelector::Pcp].
+    Dcb(DcbAppSelector),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbAppTrustAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ieee(_) | Self::Dcb(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ieee(v) | Self::Dcb(v) => buffer[0] = (*v).into(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ieee(_) => DCB_ATTR_IEEE_APP,
+            Self::Dcb(_) => DCB_ATTR_DCB_APP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbAppTrustAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_ATTR_IEEE_APP => Self::Ieee(parse_u8(payload)?.into()),
+            DCB_ATTR_DCB_APP => Self::Dcb(parse_u8(payload)?.into()),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
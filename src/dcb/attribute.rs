@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::{string::String, vec::Vec};
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::{
+    DcbAppAttribute, DcbCapAttribute, DcbFeatureConfigAttribute,
+    DcbIeeeAttribute, DcbNumTcsAttribute, DcbPfcUpAttribute, DcbPgAttribute,
+};
+
+const DCB_ATTR_IFNAME: u16 = 1;
+const DCB_ATTR_STATE: u16 = 2;
+const DCB_ATTR_PFC_STATE: u16 = 3;
+const DCB_ATTR_PFC_CFG: u16 = 4;
+// const DCB_ATTR_NUM_TC: u16 = 5;
+const DCB_ATTR_PG_CFG: u16 = 6;
+const DCB_ATTR_SET_ALL: u16 = 7;
+// const DCB_ATTR_PERM_HWADDR: u16 = 8;
+const DCB_ATTR_CAP: u16 = 9;
+const DCB_ATTR_NUMTCS: u16 = 10;
+// const DCB_ATTR_BCN: u16 = 11;
+const DCB_ATTR_APP: u16 = 12;
+const DCB_ATTR_IEEE: u16 = 13;
+const DCB_ATTR_DCBX: u16 = 14;
+const DCB_ATTR_FEATCFG: u16 = 15;
+// const DCB_ATTR_CEE: u16 = 16;
+
+/// Data Center Bridging attribute.
+///
+/// Replies of set commands carry a `u8` status code in place of the
+/// nested attribute holding the request, those are parsed as
+/// [DcbAttribute::Other].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbAttribute {
+    /// Interface name, required by every request.
+    IfName(String),
+    /// 1 if DCB is enabled.
+    State(u8),
+    /// 1 if PFC is enabled.
+    PfcState(u8),
+    /// CEE Priority Flow Control configuration
+    PfcConfig(Vec<DcbPfcUpAttribute>),
+    /// CEE Priority Group configuration, for transmit or receive depending
+    /// on [crate::dcb::DcbCommand].
+    PgConfig(Vec<DcbPgAttribute>),
+    SetAll(u8),
+    Capability(Vec<DcbCapAttribute>),
+    NumTcs(Vec<DcbNumTcsAttribute>),
+    /// CEE application priority
+    App(Vec<DcbAppAttribute>),
+    /// IEEE 802.1Qaz and 802.1Qbb configuration
+    Ieee(Vec<DcbIeeeAttribute>),
+    Dcbx(DcbxMode),
+    FeatureConfig(Vec<DcbFeatureConfigAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IfName(s) => s.len() + 1,
+            Self::State(_)
+            | Self::PfcState(_)
+            | Self::SetAll(_)
+            | Self::Dcbx(_) => 1,
+            Self::PfcConfig(attrs) => attrs.as_slice().buffer_len(),
+            Self::PgConfig(attrs) => attrs.as_slice().buffer_len(),
+            Self::Capability(attrs) => attrs.as_slice().buffer_len(),
+            Self::NumTcs(attrs) => attrs.as_slice().buffer_len(),
+            Self::App(attrs) => attrs.as_slice().buffer_len(),
+            Self::Ieee(attrs) => attrs.as_slice().buffer_len(),
+            Self::FeatureConfig(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IfName(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::State(v) | Self::PfcState(v) | Self::SetAll(v) => {
+                buffer[0] = *v
+            }
+            Self::Dcbx(v) => buffer[0] = v.bits(),
+            Self::PfcConfig(attrs) => attrs.as_slice().emit(buffer),
+            Self::PgConfig(attrs) => attrs.as_slice().emit(buffer),
+            Self::Capability(attrs) => attrs.as_slice().emit(buffer),
+            Self::NumTcs(attrs) => attrs.as_slice().emit(buffer),
+            Self::App(attrs) => attrs.as_slice().emit(buffer),
+            Self::Ieee(attrs) => attrs.as_slice().emit(buffer),
+            Self::FeatureConfig(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IfName(_) => DCB_ATTR_IFNAME,
+            Self::State(_) => DCB_ATTR_STATE,
+            Self::PfcState(_) => DCB_ATTR_PFC_STATE,
+            Self::PfcConfig(_) => DCB_ATTR_PFC_CFG,
+            Self::PgConfig(_) => DCB_ATTR_PG_CFG,
+            Self::SetAll(_) => DCB_ATTR_SET_ALL,
+            Self::Capability(_) => DCB_ATTR_CAP,
+            Self::NumTcs(_) => DCB_ATTR_NUMTCS,
+            Self::App(_) => DCB_ATTR_APP,
+            Self::Ieee(_) => DCB_ATTR_IEEE,
+            Self::Dcbx(_) => DCB_ATTR_DCBX,
+            Self::FeatureConfig(_) => DCB_ATTR_FEATCFG,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for DcbAttribute {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        // Status code in reply of set command
+        if payload.len() == 1 && is_nested(buf.kind()) {
+            return Ok(Self::Other(DefaultNla::parse(buf)?));
+        }
+        Ok(match buf.kind() {
+            DCB_ATTR_IFNAME => Self::IfName(parse_string(payload)?),
+            DCB_ATTR_STATE => Self::State(parse_u8(payload)?),
+            DCB_ATTR_PFC_STATE => Self::PfcState(parse_u8(payload)?),
+            DCB_ATTR_PFC_CFG => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbPfcUpAttribute::parse(&nla?)?);
+                }
+                Self::PfcConfig(attrs)
+            }
+            DCB_ATTR_PG_CFG => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbPgAttribute::parse(&nla?)?);
+                }
+                Self::PgConfig(attrs)
+            }
+            DCB_ATTR_SET_ALL => Self::SetAll(parse_u8(payload)?),
+            DCB_ATTR_CAP => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbCapAttribute::parse(&nla?)?);
+                }
+                Self::Capability(attrs)
+            }
+            DCB_ATTR_NUMTCS => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbNumTcsAttribute::parse(&nla?)?);
+                }
+                Self::NumTcs(attrs)
+            }
+            DCB_ATTR_APP => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbAppAttribute::parse(&nla?)?);
+                }
+                Self::App(attrs)
+            }
+            DCB_ATTR_IEEE => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbIeeeAttribute::parse(&nla?)?);
+                }
+                Self::Ieee(attrs)
+            }
+            DCB_ATTR_DCBX => {
+                Self::Dcbx(DcbxMode::from_bits_retain(parse_u8(payload)?))
+            }
+            DCB_ATTR_FEATCFG => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbFeatureConfigAttribute::parse(&nla?)?);
+                }
+                Self::FeatureConfig(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn is_nested(kind: u16) -> bool {
+    matches!(
+        kind,
+        DCB_ATTR_PFC_CFG
+            | DCB_ATTR_PG_CFG
+            | DCB_ATTR_CAP
+            | DCB_ATTR_NUMTCS
+            | DCB_ATTR_APP
+            | DCB_ATTR_IEEE
+            | DCB_ATTR_FEATCFG
+    )
+}
+
+const DCB_CAP_DCBX_HOST: u8 = 1 << 0;
+const DCB_CAP_DCBX_LLD_MANAGED: u8 = 1 << 1;
+const DCB_CAP_DCBX_VER_CEE: u8 = 1 << 2;
+const DCB_CAP_DCBX_VER_IEEE: u8 = 1 << 3;
+const DCB_CAP_DCBX_STATIC: u8 = 1 << 4;
+
+bitflags! {
+    /// DCBX mode of the device, also used by
+    /// [crate::dcb::DcbCapAttribute::Dcbx].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct DcbxMode: u8 {
+        /// DCBX negotiation is done by user space agent like lldpad
+        const Host = DCB_CAP_DCBX_HOST;
+        /// DCBX negotiation is done by the device firmware
+        const LldManaged = DCB_CAP_DCBX_LLD_MANAGED;
+        const VerCee = DCB_CAP_DCBX_VER_CEE;
+        const VerIeee = DCB_CAP_DCBX_VER_IEEE;
+        /// No DCBX negotiation
+        const Static = DCB_CAP_DCBX_STATIC;
+        const _ = !0;
+    }
+}
+
+impl Default for DcbxMode {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::ieee::{
+    emit_u32_array, parse_u32_array, parse_u8_array, IEEE_8021QAZ_MAX_TCS,
+};
+
+const DCB_BUFFER_CONFIG_LEN: usize = 44;
+
+buffer!(DcbBufferConfigBuffer(DCB_BUFFER_CONFIG_LEN) {
+    prio2buffer: (slice, 0..8),
+    buffer_size: (slice, 8..40),
+    total_size: (u32, 40..DCB_BUFFER_CONFIG_LEN),
+});
+
+/// The `struct dcbnl_buffer`, configuration of port receive buffers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbBufferConfig {
+    /// Buffer index of each user priority
+    pub prio2buffer: [u8; IEEE_8021QAZ_MAX_TCS],
+    /// Size of each buffer in bytes
+    pub buffer_size: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Total size of the port buffer in bytes, read only.
+    pub total_size: u32,
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<DcbBufferConfigBuffer<&T>>
+    for DcbBufferConfig
+{
+    fn parse(buf: &DcbBufferConfigBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            prio2buffer: parse_u8_array(buf.prio2buffer()),
+            buffer_size: parse_u32_array(buf.buffer_size()),
+            total_size: buf.total_size(),
+        })
+    }
+}
+
+impl Emitable for DcbBufferConfig {
+    fn buffer_len(&self) -> usize {
+        DCB_BUFFER_CONFIG_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbBufferConfigBuffer::new(buffer);
+        buffer.prio2buffer_mut().copy_from_slice(&self.prio2buffer);
+        emit_u32_array(&self.buffer_size, buffer.buffer_size_mut());
+        buffer.set_total_size(self.total_size);
+    }
+}
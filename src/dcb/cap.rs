@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u8,
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::dcb::DcbxMode;
+
+const DCB_CAP_ATTR_ALL: u16 = 1;
+const DCB_CAP_ATTR_PG: u16 = 2;
+const DCB_CAP_ATTR_PFC: u16 = 3;
+const DCB_CAP_ATTR_UP2TC: u16 = 4;
+const DCB_CAP_ATTR_PG_TCS: u16 = 5;
+const DCB_CAP_ATTR_PFC_TCS: u16 = 6;
+const DCB_CAP_ATTR_GSP: u16 = 7;
+const DCB_CAP_ATTR_BCN: u16 = 8;
+const DCB_CAP_ATTR_DCBX: u16 = 9;
+
+/// Member of [crate::dcb::DcbAttribute::Capability].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbCapAttribute {
+    /// Request all capabilities.
+    All,
+    /// Priority groups supported
+    Pg(bool),
+    /// Priority Flow Control supported
+    Pfc(bool),
+    /// User priority to traffic class mapping supported
+    Up2Tc(bool),
+    /// Bitmap of supported numbers of traffic classes for priority groups,
+    /// bit `n - 1` set means `n` traffic classes supported.
+    PgTcs(u8),
+    /// Bitmap of supported numbers of traffic classes for PFC, same format
+    /// as [Self::PgTcs].
+    PfcTcs(u8),
+    /// Group strict priority supported
+    Gsp(bool),
+    /// Backward Congestion Notification supported
+    Bcn(bool),
+    Dcbx(DcbxMode),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbCapAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::All => 0,
+            Self::Pg(_)
+            | Self::Pfc(_)
+            | Self::Up2Tc(_)
+            | Self::PgTcs(_)
+            | Self::PfcTcs(_)
+            | Self::Gsp(_)
+            | Self::Bcn(_)
+            | Self::Dcbx(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::All => (),
+            Self::Pg(v)
+            | Self::Pfc(v)
+            | Self::Up2Tc(v)
+            | Self::Gsp(v)
+            | Self::Bcn(v) => buffer[0] = *v as u8,
+            Self::PgTcs(v) | Self::PfcTcs(v) => buffer[0] = *v,
+            Self::Dcbx(v) => buffer[0] = v.bits(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::All => DCB_CAP_ATTR_ALL,
+            Self::Pg(_) => DCB_CAP_ATTR_PG,
+            Self::Pfc(_) => DCB_CAP_ATTR_PFC,
+            Self::Up2Tc(_) => DCB_CAP_ATTR_UP2TC,
+            Self::PgTcs(_) => DCB_CAP_ATTR_PG_TCS,
+            Self::PfcTcs(_) => DCB_CAP_ATTR_PFC_TCS,
+            Self::Gsp(_) => DCB_CAP_ATTR_GSP,
+            Self::Bcn(_) => DCB_CAP_ATTR_BCN,
+            Self::Dcbx(_) => DCB_CAP_ATTR_DCBX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbCapAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_CAP_ATTR_ALL => Self::All,
+            DCB_CAP_ATTR_PG => Self::Pg(parse_u8(payload)? > 0),
+            DCB_CAP_ATTR_PFC => Self::Pfc(parse_u8(payload)? > 0),
+            DCB_CAP_ATTR_UP2TC => Self::Up2Tc(parse_u8(payload)? > 0),
+            DCB_CAP_ATTR_PG_TCS => Self::PgTcs(parse_u8(payload)?),
+            DCB_CAP_ATTR_PFC_TCS => Self::PfcTcs(parse_u8(payload)?),
+            DCB_CAP_ATTR_GSP => Self::Gsp(parse_u8(payload)? > 0),
+            DCB_CAP_ATTR_BCN => Self::Bcn(parse_u8(payload)? > 0),
+            DCB_CAP_ATTR_DCBX => {
+                Self::Dcbx(DcbxMode::from_bits_retain(parse_u8(payload)?))
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const DCB_NUMTCS_ATTR_ALL: u16 = 1;
+const DCB_NUMTCS_ATTR_PG: u16 = 2;
+const DCB_NUMTCS_ATTR_PFC: u16 = 3;
+
+/// Member of [crate::dcb::DcbAttribute::NumTcs].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbNumTcsAttribute {
+    /// Request all.
+    All,
+    /// Number of traffic classes for priority groups
+    Pg(u8),
+    /// Number of traffic classes for PFC
+    Pfc(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbNumTcsAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::All => 0,
+            Self::Pg(_) | Self::Pfc(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::All => (),
+            Self::Pg(v) | Self::Pfc(v) => buffer[0] = *v,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::All => DCB_NUMTCS_ATTR_ALL,
+            Self::Pg(_) => DCB_NUMTCS_ATTR_PG,
+            Self::Pfc(_) => DCB_NUMTCS_ATTR_PFC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbNumTcsAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_NUMTCS_ATTR_ALL => Self::All,
+            DCB_NUMTCS_ATTR_PG => Self::Pg(parse_u8(payload)?),
+            DCB_NUMTCS_ATTR_PFC => Self::Pfc(parse_u8(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const DCB_FEATCFG_ATTR_ALL: u16 = 1;
+const DCB_FEATCFG_ATTR_PG: u16 = 2;
+const DCB_FEATCFG_ATTR_PFC: u16 = 3;
+const DCB_FEATCFG_ATTR_APP: u16 = 4;
+
+/// Member of [crate::dcb::DcbAttribute::FeatureConfig], the DCBX
+/// negotiation state of CEE features.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbFeatureConfigAttribute {
+    /// Request all features.
+    All,
+    Pg(DcbFeatureFlags),
+    Pfc(DcbFeatureFlags),
+    App(DcbFeatureFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbFeatureConfigAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::All => 0,
+            Self::Pg(_) | Self::Pfc(_) | Self::App(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::All => (),
+            Self::Pg(v) | Self::Pfc(v) | Self::App(v) => buffer[0] = v.bits(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::All => DCB_FEATCFG_ATTR_ALL,
+            Self::Pg(_) => DCB_FEATCFG_ATTR_PG,
+            Self::Pfc(_) => DCB_FEATCFG_ATTR_PFC,
+            Self::App(_) => DCB_FEATCFG_ATTR_APP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbFeatureConfigAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_FEATCFG_ATTR_ALL => Self::All,
+            DCB_FEATCFG_ATTR_PG => {
+                Self::Pg(DcbFeatureFlags::from_bits_retain(parse_u8(payload)?))
+            }
+            DCB_FEATCFG_ATTR_PFC => {
+                Self::Pfc(DcbFeatureFlags::from_bits_retain(parse_u8(payload)?))
+            }
+            DCB_FEATCFG_ATTR_APP => {
+                Self::App(DcbFeatureFlags::from_bits_retain(parse_u8(payload)?))
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const DCB_FEATCFG_ERROR: u8 = 1 << 0;
+const DCB_FEATCFG_ENABLE: u8 = 1 << 1;
+const DCB_FEATCFG_WILLING: u8 = 1 << 2;
+const DCB_FEATCFG_ADVERTISE: u8 = 1 << 3;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct DcbFeatureFlags: u8 {
+        /// DCBX negotiation failed
+        const Error = DCB_FEATCFG_ERROR;
+        const Enable = DCB_FEATCFG_ENABLE;
+        /// Accept configuration of the peer
+        const Willing = DCB_FEATCFG_WILLING;
+        const Advertise = DCB_FEATCFG_ADVERTISE;
+        const _ = !0;
+    }
+}
+
+impl Default for DcbFeatureFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const DCB_PFC_UP_ATTR_0: u16 = 1;
+const DCB_PFC_UP_ATTR_7: u16 = 8;
+const DCB_PFC_UP_ATTR_ALL: u16 = 9;
+
+/// Member of [crate::dcb::DcbAttribute::PfcConfig].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbPfcUpAttribute {
+    /// User priority(0 to 7) and its PFC setting: 0 for disabled, 1 for
+    /// enabled on both direction, 2 for transmit only and 3 for receive
+    /// only.
+    Priority(u8, u8),
+    /// Request all user priorities.
+    All,
+    Other(DefaultNla),
+}
+
+impl Nla for DcbPfcUpAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Priority(..) => 1,
+            Self::All => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Priority(_, v) => buffer[0] = *v,
+            Self::All => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Priority(prio, _) => DCB_PFC_UP_ATTR_0 + *prio as u16,
+            Self::All => DCB_PFC_UP_ATTR_ALL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbPfcUpAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            kind @ DCB_PFC_UP_ATTR_0..=DCB_PFC_UP_ATTR_7 => Self::Priority(
+                (kind - DCB_PFC_UP_ATTR_0) as u8,
+                parse_u8(payload)?,
+            ),
+            DCB_PFC_UP_ATTR_ALL => Self::All,
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const DCB_PG_ATTR_TC_0: u16 = 1;
+const DCB_PG_ATTR_TC_7: u16 = 8;
+const DCB_PG_ATTR_TC_ALL: u16 = 10;
+const DCB_PG_ATTR_BW_ID_0: u16 = 11;
+const DCB_PG_ATTR_BW_ID_7: u16 = 18;
+const DCB_PG_ATTR_BW_ID_ALL: u16 = 20;
+
+/// Member of [crate::dcb::DcbAttribute::PgConfig].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbPgAttribute {
+    /// Traffic class(0 to 7) and its configuration.
+    TrafficClass(u8, Vec<DcbTcAttribute>),
+    /// Request all traffic classes.
+    TrafficClassAll(Vec<DcbTcAttribute>),
+    /// Priority group(0 to 7) and its percentage of link bandwidth.
+    BandwidthGroup(u8, u8),
+    /// Request all priority groups.
+    BandwidthGroupAll,
+    Other(DefaultNla),
+}
+
+impl Nla for DcbPgAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::TrafficClass(_, attrs) | Self::TrafficClassAll(attrs) => {
+                attrs.as_slice().buffer_len()
+            }
+            Self::BandwidthGroup(..) => 1,
+            Self::BandwidthGroupAll => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::TrafficClass(_, attrs) | Self::TrafficClassAll(attrs) => {
+                attrs.as_slice().emit(buffer)
+            }
+            Self::BandwidthGroup(_, v) => buffer[0] = *v,
+            Self::BandwidthGroupAll => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::TrafficClass(tc, _) => DCB_PG_ATTR_TC_0 + *tc as u16,
+            Self::TrafficClassAll(_) => DCB_PG_ATTR_TC_ALL,
+            Self::BandwidthGroup(pg, _) => DCB_PG_ATTR_BW_ID_0 + *pg as u16,
+            Self::BandwidthGroupAll => DCB_PG_ATTR_BW_ID_ALL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbPgAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            kind @ DCB_PG_ATTR_TC_0..=DCB_PG_ATTR_TC_7 => Self::TrafficClass(
+                (kind - DCB_PG_ATTR_TC_0) as u8,
+                parse_tc_attributes(payload)?,
+            ),
+            DCB_PG_ATTR_TC_ALL => {
+                Self::TrafficClassAll(parse_tc_attributes(payload)?)
+            }
+            kind @ DCB_PG_ATTR_BW_ID_0..=DCB_PG_ATTR_BW_ID_7 => {
+                Self::BandwidthGroup(
+                    (kind - DCB_PG_ATTR_BW_ID_0) as u8,
+                    parse_u8(payload)?,
+                )
+            }
+            DCB_PG_ATTR_BW_ID_ALL => Self::BandwidthGroupAll,
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn parse_tc_attributes(
+    payload: &[u8],
+) -> Result<Vec<DcbTcAttribute>, DecodeError> {
+    let mut attrs = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        attrs.push(DcbTcAttribute::parse(&nla?)?);
+    }
+    Ok(attrs)
+}
+
+const DCB_TC_ATTR_PARAM_PGID: u16 = 1;
+const DCB_TC_ATTR_PARAM_UP_MAPPING: u16 = 2;
+const DCB_TC_ATTR_PARAM_STRICT_PRIO: u16 = 3;
+const DCB_TC_ATTR_PARAM_BW_PCT: u16 = 4;
+const DCB_TC_ATTR_PARAM_ALL: u16 = 5;
+
+/// Member of [DcbPgAttribute::TrafficClass].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbTcAttribute {
+    /// Priority group ID
+    PgId(u8),
+    /// Bitmap of user priorities mapped to this traffic class
+    UpMapping(u8),
+    /// Strict priority setting: 0 for none, 1 for group strict and 2 for
+    /// link strict.
+    StrictPriority(u8),
+    /// Percentage of the priority group bandwidth
+    BandwidthPercent(u8),
+    /// Request all parameters.
+    All,
+    Other(DefaultNla),
+}
+
+impl Nla for DcbTcAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::PgId(_)
+            | Self::UpMapping(_)
+            | Self::StrictPriority(_)
+            | Self::BandwidthPercent(_) => 1,
+            Self::All => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::PgId(v)
+            | Self::UpMapping(v)
+            | Self::StrictPriority(v)
+            | Self::BandwidthPercent(v) => buffer[0] = *v,
+            Self::All => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::PgId(_) => DCB_TC_ATTR_PARAM_PGID,
+            Self::UpMapping(_) => DCB_TC_ATTR_PARAM_UP_MAPPING,
+            Self::StrictPriority(_) => DCB_TC_ATTR_PARAM_STRICT_PRIO,
+            Self::BandwidthPercent(_) => DCB_TC_ATTR_PARAM_BW_PCT,
+            Self::All => DCB_TC_ATTR_PARAM_ALL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbTcAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_TC_ATTR_PARAM_PGID => Self::PgId(parse_u8(payload)?),
+            DCB_TC_ATTR_PARAM_UP_MAPPING => Self::UpMapping(parse_u8(payload)?),
+            DCB_TC_ATTR_PARAM_STRICT_PRIO => {
+                Self::StrictPriority(parse_u8(payload)?)
+            }
+            DCB_TC_ATTR_PARAM_BW_PCT => {
+                Self::BandwidthPercent(parse_u8(payload)?)
+            }
+            DCB_TC_ATTR_PARAM_ALL => Self::All,
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
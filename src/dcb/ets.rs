@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::ieee::{parse_u8_array, IEEE_8021QAZ_MAX_TCS};
+
+const DCB_IEEE_ETS_LEN: usize = 59;
+
+buffer!(DcbIeeeEtsBuffer(DCB_IEEE_ETS_LEN) {
+    willing: (u8, 0),
+    ets_cap: (u8, 1),
+    cbs: (u8, 2),
+    tc_tx_bw: (slice, 3..11),
+    tc_rx_bw: (slice, 11..19),
+    tc_tsa: (slice, 19..27),
+    prio_tc: (slice, 27..35),
+    tc_reco_bw: (slice, 35..43),
+    tc_reco_tsa: (slice, 43..51),
+    reco_prio_tc: (slice, 51..DCB_IEEE_ETS_LEN),
+});
+
+/// The `struct ieee_ets`, arrays are indexed by traffic class except
+/// `prio_tc` and `reco_prio_tc` which are indexed by user priority.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeEts {
+    pub willing: bool,
+    /// Number of traffic classes supporting ETS, read only.
+    pub ets_cap: u8,
+    /// Credit based shaper supported, read only.
+    pub cbs: bool,
+    /// Percentage of transmit bandwidth
+    pub tc_tx_bw: [u8; IEEE_8021QAZ_MAX_TCS],
+    /// Percentage of receive bandwidth
+    pub tc_rx_bw: [u8; IEEE_8021QAZ_MAX_TCS],
+    /// Transmission selection algorithm
+    pub tc_tsa: [DcbEtsTsa; IEEE_8021QAZ_MAX_TCS],
+    /// Traffic class of each user priority
+    pub prio_tc: [u8; IEEE_8021QAZ_MAX_TCS],
+    /// Recommended percentage of bandwidth
+    pub tc_reco_bw: [u8; IEEE_8021QAZ_MAX_TCS],
+    /// Recommended transmission selection algorithm
+    pub tc_reco_tsa: [DcbEtsTsa; IEEE_8021QAZ_MAX_TCS],
+    /// Recommended traffic class of each user priority
+    pub reco_prio_tc: [u8; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeEtsBuffer<&T>> for DcbIeeeEts {
+    fn parse(buf: &DcbIeeeEtsBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            willing: buf.willing() > 0,
+            ets_cap: buf.ets_cap(),
+            cbs: buf.cbs() > 0,
+            tc_tx_bw: parse_u8_array(buf.tc_tx_bw()),
+            tc_rx_bw: parse_u8_array(buf.tc_rx_bw()),
+            tc_tsa: parse_u8_array(buf.tc_tsa()).map(DcbEtsTsa::from),
+            prio_tc: parse_u8_array(buf.prio_tc()),
+            tc_reco_bw: parse_u8_array(buf.tc_reco_bw()),
+            tc_reco_tsa: parse_u8_array(buf.tc_reco_tsa()).map(DcbEtsTsa::from),
+            reco_prio_tc: parse_u8_array(buf.reco_prio_tc()),
+        })
+    }
+}
+
+impl Emitable for DcbIeeeEts {
+    fn buffer_len(&self) -> usize {
+        DCB_IEEE_ETS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeEtsBuffer::new(buffer);
+        buffer.set_willing(self.willing as u8);
+        buffer.set_ets_cap(self.ets_cap);
+        buffer.set_cbs(self.cbs as u8);
+        buffer.tc_tx_bw_mut().copy_from_slice(&self.tc_tx_bw);
+        buffer.tc_rx_bw_mut().copy_from_slice(&self.tc_rx_bw);
+        buffer
+            .tc_tsa_mut()
+            .copy_from_slice(&self.tc_tsa.map(u8::from));
+        buffer.prio_tc_mut().copy_from_slice(&self.prio_tc);
+        buffer.tc_reco_bw_mut().copy_from_slice(&self.tc_reco_bw);
+        buffer
+            .tc_reco_tsa_mut()
+            .copy_from_slice(&self.tc_reco_tsa.map(u8::from));
+        buffer
+            .reco_prio_tc_mut()
+            .copy_from_slice(&self.reco_prio_tc);
+    }
+}
+
+const IEEE_8021QAZ_TSA_STRICT: u8 = 0;
+const IEEE_8021QAZ_TSA_CB_SHAPER: u8 = 1;
+const IEEE_8021QAZ_TSA_ETS: u8 = 2;
+const IEEE_8021QAZ_TSA_VENDOR: u8 = 255;
+
+/// Transmission selection algorithm of traffic class
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum DcbEtsTsa {
+    #[default]
+    Strict,
+    /// Credit based shaper
+    CbShaper,
+    Ets,
+    Vendor,
+    Other(u8),
+}
+
+impl From<u8> for DcbEtsTsa {
+    fn from(d: u8) -> Self {
+        match d {
+            IEEE_8021QAZ_TSA_STRICT => Self::Strict,
+            IEEE_8021QAZ_TSA_CB_SHAPER => Self::CbShaper,
+            IEEE_8021QAZ_TSA_ETS => Self::Ets,
+            IEEE_8021QAZ_TSA_VENDOR => Self::Vendor,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<DcbEtsTsa> for u8 {
+    fn from(v: DcbEtsTsa) -> u8 {
+        match v {
+            DcbEtsTsa::Strict => IEEE_8021QAZ_TSA_STRICT,
+            DcbEtsTsa::CbShaper => IEEE_8021QAZ_TSA_CB_SHAPER,
+            DcbEtsTsa::Ets => IEEE_8021QAZ_TSA_ETS,
+            DcbEtsTsa::Vendor => IEEE_8021QAZ_TSA_VENDOR,
+            DcbEtsTsa::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::{boxed::Box, vec::Vec};
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::{
+    DcbAppTrustAttribute, DcbBufferConfig, DcbBufferConfigBuffer,
+    DcbIeeeAppAttribute, DcbIeeeEts, DcbIeeeEtsBuffer, DcbIeeeMaxRate,
+    DcbIeeeMaxRateBuffer, DcbIeeePfc, DcbIeeePfcBuffer, DcbIeeeQcn,
+    DcbIeeeQcnBuffer, DcbIeeeQcnStats, DcbIeeeQcnStatsBuffer,
+};
+
+/// Maximum number of traffic classes, also the number of user priorities.
+pub(crate) const IEEE_8021QAZ_MAX_TCS: usize = 8;
+
+const DCB_ATTR_IEEE_ETS: u16 = 1;
+const DCB_ATTR_IEEE_PFC: u16 = 2;
+const DCB_ATTR_IEEE_APP_TABLE: u16 = 3;
+const DCB_ATTR_IEEE_PEER_ETS: u16 = 4;
+const DCB_ATTR_IEEE_PEER_PFC: u16 = 5;
+const DCB_ATTR_IEEE_PEER_APP: u16 = 6;
+const DCB_ATTR_IEEE_MAXRATE: u16 = 7;
+const DCB_ATTR_IEEE_QCN: u16 = 8;
+const DCB_ATTR_IEEE_QCN_STATS: u16 = 9;
+const DCB_ATTR_DCB_BUFFER: u16 = 10;
+const DCB_ATTR_DCB_APP_TRUST_TABLE: u16 = 11;
+const DCB_ATTR_DCB_REWR_TABLE: u16 = 12;
+
+/// Member of [crate::dcb::DcbAttribute::Ieee].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum DcbIeeeAttribute {
+    /// Enhanced Transmission Selection defined in IEEE 802.1Qaz
+    Ets(DcbIeeeEts),
+    /// Priority-based Flow Control defined in IEEE 802.1Qbb
+    Pfc(DcbIeeePfc),
+    /// Application priority table
+    AppTable(Vec<DcbIeeeAppAttribute>),
+    PeerEts(DcbIeeeEts),
+    PeerPfc(DcbIeeePfc),
+    /// Application priority table of the peer, the `dcb_peer_app_info` is
+    /// stored as [DcbIeeeAppAttribute::Other].
+    PeerApp(Vec<DcbIeeeAppAttribute>),
+    MaxRate(DcbIeeeMaxRate),
+    /// Congestion Notification defined in IEEE 802.1Qau
+    Qcn(Box<DcbIeeeQcn>),
+    QcnStats(DcbIeeeQcnStats),
+    Buffer(DcbBufferConfig),
+    AppTrustTable(Vec<DcbAppTrustAttribute>),
+    /// Priority rewrite table of egress packets
+    RewriteTable(Vec<DcbIeeeAppAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for DcbIeeeAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ets(v) | Self::PeerEts(v) => v.buffer_len(),
+            Self::Pfc(v) | Self::PeerPfc(v) => v.buffer_len(),
+            Self::AppTable(attrs)
+            | Self::PeerApp(attrs)
+            | Self::RewriteTable(attrs) => attrs.as_slice().buffer_len(),
+            Self::MaxRate(v) => v.buffer_len(),
+            Self::Qcn(v) => v.buffer_len(),
+            Self::QcnStats(v) => v.buffer_len(),
+            Self::Buffer(v) => v.buffer_len(),
+            Self::AppTrustTable(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ets(v) | Self::PeerEts(v) => v.emit(buffer),
+            Self::Pfc(v) | Self::PeerPfc(v) => v.emit(buffer),
+            Self::AppTable(attrs)
+            | Self::PeerApp(attrs)
+            | Self::RewriteTable(attrs) => attrs.as_slice().emit(buffer),
+            Self::MaxRate(v) => v.emit(buffer),
+            Self::Qcn(v) => v.emit(buffer),
+            Self::QcnStats(v) => v.emit(buffer),
+            Self::Buffer(v) => v.emit(buffer),
+            Self::AppTrustTable(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ets(_) => DCB_ATTR_IEEE_ETS,
+            Self::Pfc(_) => DCB_ATTR_IEEE_PFC,
+            Self::AppTable(_) => DCB_ATTR_IEEE_APP_TABLE,
+            Self::PeerEts(_) => DCB_ATTR_IEEE_PEER_ETS,
+            Self::PeerPfc(_) => DCB_ATTR_IEEE_PEER_PFC,
+            Self::PeerApp(_) => DCB_ATTR_IEEE_PEER_APP,
+            Self::MaxRate(_) => DCB_ATTR_IEEE_MAXRATE,
+            Self::Qcn(_) => DCB_ATTR_IEEE_QCN,
+            Self::QcnStats(_) => DCB_ATTR_IEEE_QCN_STATS,
+            Self::Buffer(_) => DCB_ATTR_DCB_BUFFER,
+            Self::AppTrustTable(_) => DCB_ATTR_DCB_APP_TRUST_TABLE,
+            Self::RewriteTable(_) => DCB_ATTR_DCB_REWR_TABLE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for DcbIeeeAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            DCB_ATTR_IEEE_ETS => Self::Ets(DcbIeeeEts::parse(
+                &DcbIeeeEtsBuffer::new_checked(payload)?,
+            )?),
+            DCB_ATTR_IEEE_PFC => Self::Pfc(DcbIeeePfc::parse(
+                &DcbIeeePfcBuffer::new_checked(payload)?,
+            )?),
+            DCB_ATTR_IEEE_APP_TABLE => {
+                Self::AppTable(parse_app_attributes(payload)?)
+            }
+            DCB_ATTR_IEEE_PEER_ETS => Self::PeerEts(DcbIeeeEts::parse(
+                &DcbIeeeEtsBuffer::new_checked(payload)?,
+            )?),
+            DCB_ATTR_IEEE_PEER_PFC => Self::PeerPfc(DcbIeeePfc::parse(
+                &DcbIeeePfcBuffer::new_checked(payload)?,
+            )?),
+            DCB_ATTR_IEEE_PEER_APP => {
+                Self::PeerApp(parse_app_attributes(payload)?)
+            }
+            DCB_ATTR_IEEE_MAXRATE => Self::MaxRate(DcbIeeeMaxRate::parse(
+                &DcbIeeeMaxRateBuffer::new_checked(payload)?,
+            )?),
+            DCB_ATTR_IEEE_QCN => Self::Qcn(Box::new(DcbIeeeQcn::parse(
+                &DcbIeeeQcnBuffer::new_checked(payload)?,
+            )?)),
+            DCB_ATTR_IEEE_QCN_STATS => Self::QcnStats(DcbIeeeQcnStats::parse(
+                &DcbIeeeQcnStatsBuffer::new_checked(payload)?,
+            )?),
+            DCB_ATTR_DCB_BUFFER => Self::Buffer(DcbBufferConfig::parse(
+                &DcbBufferConfigBuffer::new_checked(payload)?,
+            )?),
+            DCB_ATTR_DCB_APP_TRUST_TABLE => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(DcbAppTrustAttribute::parse(&nla?)?);
+                }
+                Self::AppTrustTable(attrs)
+            }
+            DCB_ATTR_DCB_REWR_TABLE => {
+                Self::RewriteTable(parse_app_attributes(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn parse_app_attributes(
+    payload: &[u8],
+) -> Result<Vec<DcbIeeeAppAttribute>, DecodeError> {
+    let mut attrs = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        attrs.push(DcbIeeeAppAttribute::parse(&nla?)?);
+    }
+    Ok(attrs)
+}
+
+pub(crate) fn parse_u8_array(raw: &[u8]) -> [u8; IEEE_8021QAZ_MAX_TCS] {
+    let mut ret = [0u8; IEEE_8021QAZ_MAX_TCS];
+    ret.copy_from_slice(raw);
+    ret
+}
+
+pub(crate) fn parse_u32_array(raw: &[u8]) -> [u32; IEEE_8021QAZ_MAX_TCS] {
+    let mut ret = [0u32; IEEE_8021QAZ_MAX_TCS];
+    NativeEndian::read_u32_into(raw, &mut ret);
+    ret
+}
+
+pub(crate) fn parse_u64_array(raw: &[u8]) -> [u64; IEEE_8021QAZ_MAX_TCS] {
+    let mut ret = [0u64; IEEE_8021QAZ_MAX_TCS];
+    NativeEndian::read_u64_into(raw, &mut ret);
+    ret
+}
+
+pub(crate) fn emit_u32_array(
+    values: &[u32; IEEE_8021QAZ_MAX_TCS],
+    buffer: &mut [u8],
+) {
+    NativeEndian::write_u32_into(values, buffer)
+}
+
+pub(crate) fn emit_u64_array(
+    values: &[u64; IEEE_8021QAZ_MAX_TCS],
+    buffer: &mut [u8],
+) {
+    NativeEndian::write_u64_into(values, buffer)
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::ieee::{emit_u64_array, parse_u64_array, IEEE_8021QAZ_MAX_TCS};
+
+const DCB_IEEE_MAXRATE_LEN: usize = 64;
+
+buffer!(DcbIeeeMaxRateBuffer(DCB_IEEE_MAXRATE_LEN) {
+    tc_maxrate: (slice, 0..DCB_IEEE_MAXRATE_LEN),
+});
+
+/// The `struct ieee_maxrate`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeMaxRate {
+    /// Maximum transmit rate of each traffic class in kbit/s, 0 means
+    /// unlimited.
+    pub tc_maxrate: [u64; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeMaxRateBuffer<&T>>
+    for DcbIeeeMaxRate
+{
+    fn parse(buf: &DcbIeeeMaxRateBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            tc_maxrate: parse_u64_array(buf.tc_maxrate()),
+        })
+    }
+}
+
+impl Emitable for DcbIeeeMaxRate {
+    fn buffer_len(&self) -> usize {
+        DCB_IEEE_MAXRATE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeMaxRateBuffer::new(buffer);
+        emit_u64_array(&self.tc_maxrate, buffer.tc_maxrate_mut());
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::{DcbAttribute, DcbHeader, DcbMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct DcbMessage {
+    pub header: DcbHeader,
+    pub attributes: Vec<DcbAttribute>,
+}
+
+impl Emitable for DcbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<DcbMessageBuffer<&'a T>>
+    for DcbMessage
+{
+    fn parse(buf: &DcbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = DcbHeader::parse(buf)?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(DcbAttribute::parse(&nla_buf?)?);
+        }
+        Ok(DcbMessage { header, attributes })
+    }
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+
+mod app;
+mod attribute;
+mod buffer_config;
+mod cap;
+mod cee;
+mod ets;
+mod header;
+mod ieee;
+mod maxrate;
+mod message;
+mod pfc;
+mod qcn;
+
+pub use self::app::{
+    DcbApp, DcbAppAttribute, DcbAppBuffer, DcbAppIdType, DcbAppSelector,
+    DcbAppTrustAttribute, DcbIeeeAppAttribute,
+};
+pub use self::attribute::{DcbAttribute, DcbxMode};
+pub use self::buffer_config::{DcbBufferConfig, DcbBufferConfigBuffer};
+pub use self::cap::{
+    DcbCapAttribute, DcbFeatureConfigAttribute, DcbFeatureFlags,
+    DcbNumTcsAttribute,
+};
+pub use self::cee::{DcbPfcUpAttribute, DcbPgAttribute, DcbTcAttribute};
+pub use self::ets::{DcbEtsTsa, DcbIeeeEts, DcbIeeeEtsBuffer};
+pub use self::header::{DcbCommand, DcbHeader, DcbMessageBuffer};
+pub use self::ieee::DcbIeeeAttribute;
+pub use self::maxrate::{DcbIeeeMaxRate, DcbIeeeMaxRateBuffer};
+pub use self::message::DcbMessage;
+pub use self::pfc::{DcbIeeePfc, DcbIeeePfcBuffer};
+pub use self::qcn::{
+    DcbIeeeQcn, DcbIeeeQcnBuffer, DcbIeeeQcnStats, DcbIeeeQcnStatsBuffer,
+};
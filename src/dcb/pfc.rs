@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::ieee::{emit_u64_array, parse_u64_array, IEEE_8021QAZ_MAX_TCS};
+
+const DCB_IEEE_PFC_LEN: usize = 136;
+
+buffer!(DcbIeeePfcBuffer(DCB_IEEE_PFC_LEN) {
+    pfc_cap: (u8, 0),
+    pfc_en: (u8, 1),
+    mbc: (u8, 2),
+    pad1: (u8, 3),
+    delay: (u16, 4..6),
+    pad2: (u16, 6..8),
+    requests: (slice, 8..72),
+    indications: (slice, 72..DCB_IEEE_PFC_LEN),
+});
+
+/// The `struct ieee_pfc`, arrays are indexed by user priority.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeePfc {
+    /// Number of user priorities PFC can be enabled on at the same time,
+    /// read only.
+    pub pfc_cap: u8,
+    /// Bitmap of user priorities with PFC enabled
+    pub pfc_en: u8,
+    /// MACsec bypass capability
+    pub mbc: u8,
+    /// Allowance for cable delay and interface delay in bit times
+    pub delay: u16,
+    /// Number of PFC frames sent, read only.
+    pub requests: [u64; IEEE_8021QAZ_MAX_TCS],
+    /// Number of PFC frames received, read only.
+    pub indications: [u64; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeePfcBuffer<&T>> for DcbIeeePfc {
+    fn parse(buf: &DcbIeeePfcBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            pfc_cap: buf.pfc_cap(),
+            pfc_en: buf.pfc_en(),
+            mbc: buf.mbc(),
+            delay: buf.delay(),
+            requests: parse_u64_array(buf.requests()),
+            indications: parse_u64_array(buf.indications()),
+        })
+    }
+}
+
+impl Emitable for DcbIeeePfc {
+    fn buffer_len(&self) -> usize {
+        DCB_IEEE_PFC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeePfcBuffer::new(buffer);
+        buffer.set_pfc_cap(self.pfc_cap);
+        buffer.set_pfc_en(self.pfc_en);
+        buffer.set_mbc(self.mbc);
+        buffer.set_pad1(0);
+        buffer.set_delay(self.delay);
+        buffer.set_pad2(0);
+        emit_u64_array(&self.requests, buffer.requests_mut());
+        emit_u64_array(&self.indications, buffer.indications_mut());
+    }
+}
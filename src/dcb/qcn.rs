@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::dcb::ieee::{
+    emit_u32_array, emit_u64_array, parse_u32_array, parse_u64_array,
+    parse_u8_array, IEEE_8021QAZ_MAX_TCS,
+};
+
+const DCB_IEEE_QCN_LEN: usize = 360;
+
+buffer!(DcbIeeeQcnBuffer(DCB_IEEE_QCN_LEN) {
+    rpg_enable: (slice, 0..8),
+    rppp_max_rps: (slice, 8..40),
+    rpg_time_reset: (slice, 40..72),
+    rpg_byte_reset: (slice, 72..104),
+    rpg_threshold: (slice, 104..136),
+    rpg_max_rate: (slice, 136..168),
+    rpg_ai_rate: (slice, 168..200),
+    rpg_hai_rate: (slice, 200..232),
+    rpg_gd: (slice, 232..264),
+    rpg_min_dec_fac: (slice, 264..296),
+    rpg_min_rate: (slice, 296..328),
+    cndd_state_machine: (slice, 328..DCB_IEEE_QCN_LEN),
+});
+
+/// The `struct ieee_qcn`, reaction point parameters of each user priority.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeQcn {
+    pub rpg_enable: [u8; IEEE_8021QAZ_MAX_TCS],
+    pub rppp_max_rps: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Time between rate increases in microseconds
+    pub rpg_time_reset: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Bytes transmitted between rate increases in kilobytes
+    pub rpg_byte_reset: [u32; IEEE_8021QAZ_MAX_TCS],
+    pub rpg_threshold: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Maximum rate in Mbit/s
+    pub rpg_max_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Active increase rate in Mbit/s
+    pub rpg_ai_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Hyper increase rate in Mbit/s
+    pub rpg_hai_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    pub rpg_gd: [u32; IEEE_8021QAZ_MAX_TCS],
+    pub rpg_min_dec_fac: [u32; IEEE_8021QAZ_MAX_TCS],
+    /// Minimum rate in Mbit/s
+    pub rpg_min_rate: [u32; IEEE_8021QAZ_MAX_TCS],
+    pub cndd_state_machine: [u32; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeQcnBuffer<&T>> for DcbIeeeQcn {
+    fn parse(buf: &DcbIeeeQcnBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rpg_enable: parse_u8_array(buf.rpg_enable()),
+            rppp_max_rps: parse_u32_array(buf.rppp_max_rps()),
+            rpg_time_reset: parse_u32_array(buf.rpg_time_reset()),
+            rpg_byte_reset: parse_u32_array(buf.rpg_byte_reset()),
+            rpg_threshold: parse_u32_array(buf.rpg_threshold()),
+            rpg_max_rate: parse_u32_array(buf.rpg_max_rate()),
+            rpg_ai_rate: parse_u32_array(buf.rpg_ai_rate()),
+            rpg_hai_rate: parse_u32_array(buf.rpg_hai_rate()),
+            rpg_gd: parse_u32_array(buf.rpg_gd()),
+            rpg_min_dec_fac: parse_u32_array(buf.rpg_min_dec_fac()),
+            rpg_min_rate: parse_u32_array(buf.rpg_min_rate()),
+            cndd_state_machine: parse_u32_array(buf.cndd_state_machine()),
+        })
+    }
+}
+
+impl Emitable for DcbIeeeQcn {
+    fn buffer_len(&self) -> usize {
+        DCB_IEEE_QCN_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeQcnBuffer::new(buffer);
+        buffer.rpg_enable_mut().copy_from_slice(&self.rpg_enable);
+        emit_u32_array(&self.rppp_max_rps, buffer.rppp_max_rps_mut());
+        emit_u32_array(&self.rpg_time_reset, buffer.rpg_time_reset_mut());
+        emit_u32_array(&self.rpg_byte_reset, buffer.rpg_byte_reset_mut());
+        emit_u32_array(&self.rpg_threshold, buffer.rpg_threshold_mut());
+        emit_u32_array(&self.rpg_max_rate, buffer.rpg_max_rate_mut());
+        emit_u32_array(&self.rpg_ai_rate, buffer.rpg_ai_rate_mut());
+        emit_u32_array(&self.rpg_hai_rate, buffer.rpg_hai_rate_mut());
+        emit_u32_array(&self.rpg_gd, buffer.rpg_gd_mut());
+        emit_u32_array(&self.rpg_min_dec_fac, buffer.rpg_min_dec_fac_mut());
+        emit_u32_array(&self.rpg_min_rate, buffer.rpg_min_rate_mut());
+        emit_u32_array(
+            &self.cndd_state_machine,
+            buffer.cndd_state_machine_mut(),
+        );
+    }
+}
+
+const DCB_IEEE_QCN_STATS_LEN: usize = 96;
+
+buffer!(DcbIeeeQcnStatsBuffer(DCB_IEEE_QCN_STATS_LEN) {
+    rppp_rp_centiseconds: (slice, 0..64),
+    rppp_created_rps: (slice, 64..DCB_IEEE_QCN_STATS_LEN),
+});
+
+/// The `struct ieee_qcn_stats`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DcbIeeeQcnStats {
+    pub rppp_rp_centiseconds: [u64; IEEE_8021QAZ_MAX_TCS],
+    pub rppp_created_rps: [u32; IEEE_8021QAZ_MAX_TCS],
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<DcbIeeeQcnStatsBuffer<&T>>
+    for DcbIeeeQcnStats
+{
+    fn parse(buf: &DcbIeeeQcnStatsBuffer<&T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rppp_rp_centiseconds: parse_u64_array(buf.rppp_rp_centiseconds()),
+            rppp_created_rps: parse_u32_array(buf.rppp_created_rps()),
+        })
+    }
+}
+
+impl Emitable for DcbIeeeQcnStats {
+    fn buffer_len(&self) -> usize {
+        DCB_IEEE_QCN_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = DcbIeeeQcnStatsBuffer::new(buffer);
+        emit_u64_array(
+            &self.rppp_rp_centiseconds,
+            buffer.rppp_rp_centiseconds_mut(),
+        );
+        emit_u32_array(&self.rppp_created_rps, buffer.rppp_created_rps_mut());
+    }
+}
@@ -2,9 +2,29 @@
 #![no_std]
 #![feature(ip_in_core)]
 extern crate alloc;
+#[cfg(test)]
+extern crate std;
 
 pub mod address;
+pub mod address_label;
+pub mod bridge_vlan;
+pub mod cache_report;
+pub mod dcb;
 pub mod link;
+pub mod lwtunnel;
+pub mod mdb;
+pub mod nd_user_option;
+pub mod neighbour;
+pub mod neighbour_table;
+pub mod netconf;
+pub mod nexthop;
+pub mod nsid;
+pub mod prefix;
+pub mod route;
+pub mod rule;
+pub mod stats;
+pub mod tc;
+pub mod tunnel;
 
 mod message;
 #[cfg(test)]
@@ -49,6 +69,21 @@ pub use self::message::{RouteNetlinkMessage, RouteNetlinkMessageBuffer};
 ///  * `neighbour`: Neighbour, similar to `ip neighbour` command.
 ///  * `neighbour_table`: Neighbour table, similar to `ip ntable` command.
 ///  * `nsid`: Namespace, similar to `ip netns` command.
+///  * `prefix`: IPv6 prefix, similar to `ip monitor prefix` command.
+///  * `netconf`: IP configuration, similar to `ip netconf` command.
+///  * `mdb`: Bridge multicast database, similar to `bridge mdb` command.
+///  * `address_label`: IPv6 address label, similar to `ip addrlabel` command.
+///  * `stats`: Link statistics, similar to `ip stats` command.
+///  * `nexthop`: Nexthop object, similar to `ip nexthop` command.
+///  * `nd_user_option`: Router advertisement option, similar to
+///    `ip monitor nduseropt` command.
+///  * `dcb`: Data Center Bridging, similar to `dcb` command.
+///  * `bridge_vlan`: Bridge per-VLAN option, similar to `bridge vlan` command.
+///  * `tunnel`: VXLAN VNI filter, similar to `bridge vni` command.
+///  * `cache_report`: Multicast routing cache report, similar to `ip mroute`
+///    command.
+///  * `lwtunnel`: Lightweight tunnel encapsulation, similar to `encap` of
+///    `ip route` command.
 ///
 /// At the top level of this crate, we also provide:
 ///  * [AddressFamily]
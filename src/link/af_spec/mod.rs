@@ -8,6 +8,7 @@ mod inet6_devconf;
 mod inet6_icmp;
 mod inet6_iface_flag;
 mod inet6_stats;
+mod mpls;
 mod unspec;
 
 pub use self::bridge::{AfSpecBridge, BridgeVlanInfo};
@@ -18,6 +19,7 @@ pub use self::inet6_devconf::{Inet6DevConf, Inet6DevConfBuffer};
 pub use self::inet6_icmp::{Icmp6Stats, Icmp6StatsBuffer};
 pub use self::inet6_iface_flag::Inet6IfaceFlags;
 pub use self::inet6_stats::{Inet6Stats, Inet6StatsBuffer};
+pub use self::mpls::{AfSpecMpls, MplsLinkStats, MplsLinkStatsBuffer};
 pub use self::unspec::AfSpecUnspec;
 
 #[cfg(any(target_os = "linux", target_os = "fuchsia"))]
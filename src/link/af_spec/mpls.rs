@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const MPLS_STATS_LINK: u16 = 1;
+
+/// MPLS per-link attributes of `IFLA_AF_SPEC` and
+/// [crate::stats::StatsAfSpec::Mpls].
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum AfSpecMpls {
+    Stats(MplsLinkStats),
+    Other(DefaultNla),
+}
+
+impl Nla for AfSpecMpls {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Stats(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Stats(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Stats(_) => MPLS_STATS_LINK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecMpls {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MPLS_STATS_LINK => Self::Stats(MplsLinkStats::parse(
+                &MplsLinkStatsBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const MPLS_LINK_STATS_LEN: usize = 72;
+
+buffer!(MplsLinkStatsBuffer(MPLS_LINK_STATS_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    rx_noroute: (u64, 64..72),
+});
+
+/// The `struct mpls_link_stats`.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+#[non_exhaustive]
+pub struct MplsLinkStats {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    /// Packets dropped due to missing route of the label
+    pub rx_noroute: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<MplsLinkStatsBuffer<T>> for MplsLinkStats {
+    fn parse(buf: &MplsLinkStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            rx_noroute: buf.rx_noroute(),
+        })
+    }
+}
+
+impl Emitable for MplsLinkStats {
+    fn buffer_len(&self) -> usize {
+        MPLS_LINK_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MplsLinkStatsBuffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_rx_noroute(self.rx_noroute);
+    }
+}
@@ -10,7 +10,7 @@ use netlink_packet_utils::{
 
 use crate::link::{
     af_spec::{VecAfSpecInet, VecAfSpecInet6},
-    AfSpecInet, AfSpecInet6,
+    AfSpecInet, AfSpecInet6, AfSpecMpls,
 };
 use crate::AddressFamily;
 
@@ -41,6 +41,7 @@ use crate::AddressFamily;
 pub enum AfSpecUnspec {
     Inet(Vec<AfSpecInet>),
     Inet6(Vec<AfSpecInet6>),
+    Mpls(Vec<AfSpecMpls>),
     Other(DefaultNla),
 }
 
@@ -69,6 +70,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
                             .0,
                     )
                 }
+                k if k == u8::from(AddressFamily::Mpls) as u16 => {
+                    let mut attrs = vec![];
+                    for nla in NlasIterator::new(nla.value()) {
+                        attrs.push(AfSpecMpls::parse(&nla?)?);
+                    }
+                    AfSpecUnspec::Mpls(attrs)
+                }
                 kind => AfSpecUnspec::Other(DefaultNla::parse(&nla)?),
             })
         }
@@ -81,6 +89,7 @@ impl Nla for AfSpecUnspec {
         match *self {
             Self::Inet(ref nlas) => nlas.as_slice().buffer_len(),
             Self::Inet6(ref nlas) => nlas.as_slice().buffer_len(),
+            Self::Mpls(ref nlas) => nlas.as_slice().buffer_len(),
             Self::Other(ref nla) => nla.value_len(),
         }
     }
@@ -89,6 +98,7 @@ impl Nla for AfSpecUnspec {
         match *self {
             Self::Inet(ref nlas) => nlas.as_slice().emit(buffer),
             Self::Inet6(ref nlas) => nlas.as_slice().emit(buffer),
+            Self::Mpls(ref nlas) => nlas.as_slice().emit(buffer),
             Self::Other(ref nla) => nla.emit_value(buffer),
         }
     }
@@ -97,6 +107,7 @@ impl Nla for AfSpecUnspec {
         match *self {
             Self::Inet(_) => u8::from(AddressFamily::Inet) as u16,
             Self::Inet6(_) => u8::from(AddressFamily::Inet6) as u16,
+            Self::Mpls(_) => u8::from(AddressFamily::Mpls) as u16,
             Self::Other(ref nla) => nla.kind(),
         }
     }
@@ -85,7 +85,7 @@ const IFLA_CARRIER_DOWN_COUNT: u16 = 48;
 const IFLA_NEW_IFINDEX: u16 = 49;
 const IFLA_MIN_MTU: u16 = 50;
 const IFLA_MAX_MTU: u16 = 51;
-const IFLA_PROP_LIST: u16 = 52;
+pub(crate) const IFLA_PROP_LIST: u16 = 52;
 const IFLA_PERM_ADDRESS: u16 = 54;
 const IFLA_PROTO_DOWN_REASON: u16 = 55;
 
@@ -1,31 +1,168 @@
 // SPDX-License-Identifier: MIT
 
+use core::net::Ipv4Addr;
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
     DecodeError, Parseable,
 };
 
+use crate::ip::{parse_ipv4_addr, IPV4_ADDR_LEN};
+
+const IFLA_GRE_LINK: u16 = 1;
+const IFLA_GRE_IFLAGS: u16 = 2;
+const IFLA_GRE_OFLAGS: u16 = 3;
+const IFLA_GRE_IKEY: u16 = 4;
+const IFLA_GRE_OKEY: u16 = 5;
+const IFLA_GRE_LOCAL: u16 = 6;
+const IFLA_GRE_REMOTE: u16 = 7;
+const IFLA_GRE_TTL: u16 = 8;
+const IFLA_GRE_TOS: u16 = 9;
+const IFLA_GRE_PMTUDISC: u16 = 10;
+// const IFLA_GRE_ENCAP_LIMIT: u16 = 11; // ip6gre only
+// const IFLA_GRE_FLOWINFO: u16 = 12; // ip6gre only
+// const IFLA_GRE_FLAGS: u16 = 13; // ip6gre only
+const IFLA_GRE_ENCAP_TYPE: u16 = 14;
+const IFLA_GRE_ENCAP_FLAGS: u16 = 15;
+const IFLA_GRE_ENCAP_SPORT: u16 = 16;
+const IFLA_GRE_ENCAP_DPORT: u16 = 17;
+const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+const IFLA_GRE_IGNORE_DF: u16 = 19;
+const IFLA_GRE_FWMARK: u16 = 20;
+const IFLA_GRE_ERSPAN_INDEX: u16 = 21;
+const IFLA_GRE_ERSPAN_VER: u16 = 22;
+const IFLA_GRE_ERSPAN_DIR: u16 = 23;
+const IFLA_GRE_ERSPAN_HWID: u16 = 24;
+
+/// Attributes of `gre`, `gretap` and `erspan` links, which share the
+/// `IFLA_GRE_*` attribute set.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum InfoGreTun {
+    /// Interface index of the underlying device
+    Link(u32),
+    IFlags(GreIoFlags),
+    OFlags(GreIoFlags),
+    IKey(u32),
+    OKey(u32),
+    Local(Ipv4Addr),
+    Remote(Ipv4Addr),
+    /// 0 means inheriting from the inner packet
+    Ttl(u8),
+    /// 1 means inheriting from the inner packet
+    Tos(u8),
+    PathMtuDiscovery(bool),
+    EncapType(GreEncapType),
+    EncapFlags(GreEncapFlags),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
+    /// Tunnel parameters are taken from the metadata of each packet
+    CollectMetadata,
+    IgnoreDf(bool),
+    FwMark(u32),
+    ErspanIndex(u32),
+    /// ERSPAN version, 1 for type II and 2 for type III
+    ErspanVersion(u8),
+    ErspanDirection(GreErspanDirection),
+    ErspanHardwareId(u16),
     Other(DefaultNla),
 }
 
+pub type InfoGreTap = InfoGreTun;
+
 impl Nla for InfoGreTun {
     fn value_len(&self) -> usize {
         match self {
+            Self::CollectMetadata => 0,
+            Self::Ttl(_)
+            | Self::Tos(_)
+            | Self::PathMtuDiscovery(_)
+            | Self::IgnoreDf(_)
+            | Self::ErspanVersion(_)
+            | Self::ErspanDirection(_) => 1,
+            Self::IFlags(_)
+            | Self::OFlags(_)
+            | Self::EncapType(_)
+            | Self::EncapFlags(_)
+            | Self::EncapSourcePort(_)
+            | Self::EncapDestinationPort(_)
+            | Self::ErspanHardwareId(_) => 2,
+            Self::Link(_)
+            | Self::IKey(_)
+            | Self::OKey(_)
+            | Self::FwMark(_)
+            | Self::ErspanIndex(_) => 4,
+            Self::Local(_) | Self::Remote(_) => IPV4_ADDR_LEN,
             Self::Other(nla) => nla.value_len(),
         }
     }
 
     fn emit_value(&self, buffer: &mut [u8]) {
         match self {
+            Self::CollectMetadata => (),
+            Self::Ttl(value)
+            | Self::Tos(value)
+            | Self::ErspanVersion(value) => buffer[0] = *value,
+            Self::PathMtuDiscovery(value) | Self::IgnoreDf(value) => {
+                buffer[0] = *value as u8
+            }
+            Self::ErspanDirection(value) => buffer[0] = (*value).into(),
+            Self::IFlags(value) | Self::OFlags(value) => {
+                BigEndian::write_u16(buffer, value.bits())
+            }
+            Self::EncapType(value) => {
+                NativeEndian::write_u16(buffer, (*value).into())
+            }
+            Self::EncapFlags(value) => {
+                NativeEndian::write_u16(buffer, value.bits())
+            }
+            Self::EncapSourcePort(value)
+            | Self::EncapDestinationPort(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
+            Self::ErspanHardwareId(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            Self::IKey(value) | Self::OKey(value) => {
+                BigEndian::write_u32(buffer, *value)
+            }
+            Self::Link(value)
+            | Self::FwMark(value)
+            | Self::ErspanIndex(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Local(value) | Self::Remote(value) => {
+                buffer.copy_from_slice(&value.octets())
+            }
             Self::Other(nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
         match self {
+            Self::Link(_) => IFLA_GRE_LINK,
+            Self::IFlags(_) => IFLA_GRE_IFLAGS,
+            Self::OFlags(_) => IFLA_GRE_OFLAGS,
+            Self::IKey(_) => IFLA_GRE_IKEY,
+            Self::OKey(_) => IFLA_GRE_OKEY,
+            Self::Local(_) => IFLA_GRE_LOCAL,
+            Self::Remote(_) => IFLA_GRE_REMOTE,
+            Self::Ttl(_) => IFLA_GRE_TTL,
+            Self::Tos(_) => IFLA_GRE_TOS,
+            Self::PathMtuDiscovery(_) => IFLA_GRE_PMTUDISC,
+            Self::EncapType(_) => IFLA_GRE_ENCAP_TYPE,
+            Self::EncapFlags(_) => IFLA_GRE_ENCAP_FLAGS,
+            Self::EncapSourcePort(_) => IFLA_GRE_ENCAP_SPORT,
+            Self::EncapDestinationPort(_) => IFLA_GRE_ENCAP_DPORT,
+            Self::CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            Self::IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+            Self::FwMark(_) => IFLA_GRE_FWMARK,
+            Self::ErspanIndex(_) => IFLA_GRE_ERSPAN_INDEX,
+            Self::ErspanVersion(_) => IFLA_GRE_ERSPAN_VER,
+            Self::ErspanDirection(_) => IFLA_GRE_ERSPAN_DIR,
+            Self::ErspanHardwareId(_) => IFLA_GRE_ERSPAN_HWID,
             Self::Other(nla) => nla.kind(),
         }
     }
@@ -33,11 +170,167 @@ impl Nla for InfoGreTun {
 
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGreTun {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        #[allow(clippy::match_single_binding)]
+        let payload = buf.value();
         Ok(match buf.kind() {
-            kind => Self::Other(
-                DefaultNla::parse(buf)?,
+            IFLA_GRE_LINK => Self::Link(parse_u32(payload)?),
+            IFLA_GRE_IFLAGS => Self::IFlags(GreIoFlags::from_bits_retain(
+                parse_u16_be(payload)?,
+            )),
+            IFLA_GRE_OFLAGS => Self::OFlags(GreIoFlags::from_bits_retain(
+                parse_u16_be(payload)?,
+            )),
+            IFLA_GRE_IKEY => Self::IKey(parse_u32_be(payload)?),
+            IFLA_GRE_OKEY => Self::OKey(parse_u32_be(payload)?),
+            IFLA_GRE_LOCAL => Self::Local(parse_ipv4_addr(payload)?),
+            IFLA_GRE_REMOTE => Self::Remote(parse_ipv4_addr(payload)?),
+            IFLA_GRE_TTL => Self::Ttl(parse_u8(payload)?),
+            IFLA_GRE_TOS => Self::Tos(parse_u8(payload)?),
+            IFLA_GRE_PMTUDISC => Self::PathMtuDiscovery(parse_u8(payload)? > 0),
+            IFLA_GRE_ENCAP_TYPE => Self::EncapType(parse_u16(payload)?.into()),
+            IFLA_GRE_ENCAP_FLAGS => Self::EncapFlags(
+                GreEncapFlags::from_bits_retain(parse_u16(payload)?),
             ),
+            IFLA_GRE_ENCAP_SPORT => {
+                Self::EncapSourcePort(parse_u16_be(payload)?)
+            }
+            IFLA_GRE_ENCAP_DPORT => {
+                Self::EncapDestinationPort(parse_u16_be(payload)?)
+            }
+            IFLA_GRE_COLLECT_METADATA => Self::CollectMetadata,
+            IFLA_GRE_IGNORE_DF => Self::IgnoreDf(parse_u8(payload)? > 0),
+            IFLA_GRE_FWMARK => Self::FwMark(parse_u32(payload)?),
+            IFLA_GRE_ERSPAN_INDEX => Self::ErspanIndex(parse_u32(payload)?),
+            IFLA_GRE_ERSPAN_VER => Self::ErspanVersion(parse_u8(payload)?),
+            IFLA_GRE_ERSPAN_DIR => {
+                Self::ErspanDirection(parse_u8(payload)?.into())
+            }
+            IFLA_GRE_ERSPAN_HWID => Self::ErspanHardwareId(parse_u16(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
         })
     }
 }
+
+const GRE_CSUM: u16 = 0x8000;
+const GRE_ROUTING: u16 = 0x4000;
+const GRE_KEY: u16 = 0x2000;
+const GRE_SEQ: u16 = 0x1000;
+const GRE_STRICT: u16 = 0x0800;
+const GRE_ACK: u16 = 0x0080;
+
+bitflags! {
+    /// Flags of GRE header used by `IFLA_GRE_IFLAGS` and `IFLA_GRE_OFLAGS`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct GreIoFlags: u16 {
+        const Csum = GRE_CSUM;
+        const Routing = GRE_ROUTING;
+        const Key = GRE_KEY;
+        const Seq = GRE_SEQ;
+        const Strict = GRE_STRICT;
+        const Ack = GRE_ACK;
+        const _ = !0;
+    }
+}
+
+impl Default for GreIoFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const TUNNEL_ENCAP_NONE: u16 = 0;
+const TUNNEL_ENCAP_FOU: u16 = 1;
+const TUNNEL_ENCAP_GUE: u16 = 2;
+const TUNNEL_ENCAP_MPLS: u16 = 3;
+
+/// UDP encapsulation of the tunnel, carried by `IFLA_GRE_ENCAP_TYPE`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum GreEncapType {
+    #[default]
+    None,
+    /// Foo over UDP
+    Fou,
+    /// Generic UDP Encapsulation
+    Gue,
+    Mpls,
+    Other(u16),
+}
+
+impl From<u16> for GreEncapType {
+    fn from(d: u16) -> Self {
+        match d {
+            TUNNEL_ENCAP_NONE => Self::None,
+            TUNNEL_ENCAP_FOU => Self::Fou,
+            TUNNEL_ENCAP_GUE => Self::Gue,
+            TUNNEL_ENCAP_MPLS => Self::Mpls,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<GreEncapType> for u16 {
+    fn from(v: GreEncapType) -> u16 {
+        match v {
+            GreEncapType::None => TUNNEL_ENCAP_NONE,
+            GreEncapType::Fou => TUNNEL_ENCAP_FOU,
+            GreEncapType::Gue => TUNNEL_ENCAP_GUE,
+            GreEncapType::Mpls => TUNNEL_ENCAP_MPLS,
+            GreEncapType::Other(d) => d,
+        }
+    }
+}
+
+const TUNNEL_ENCAP_FLAG_CSUM: u16 = 1 << 0;
+const TUNNEL_ENCAP_FLAG_CSUM6: u16 = 1 << 1;
+const TUNNEL_ENCAP_FLAG_REMCSUM: u16 = 1 << 2;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct GreEncapFlags: u16 {
+        const Csum = TUNNEL_ENCAP_FLAG_CSUM;
+        const Csum6 = TUNNEL_ENCAP_FLAG_CSUM6;
+        const RemCsum = TUNNEL_ENCAP_FLAG_REMCSUM;
+        const _ = !0;
+    }
+}
+
+impl Default for GreEncapFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const ERSPAN_DIR_INGRESS: u8 = 0;
+const ERSPAN_DIR_EGRESS: u8 = 1;
+
+/// Direction of the mirrored traffic, ERSPAN version 2 only.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum GreErspanDirection {
+    #[default]
+    Ingress,
+    Egress,
+    Other(u8),
+}
+
+impl From<u8> for GreErspanDirection {
+    fn from(d: u8) -> Self {
+        match d {
+            ERSPAN_DIR_INGRESS => Self::Ingress,
+            ERSPAN_DIR_EGRESS => Self::Egress,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<GreErspanDirection> for u8 {
+    fn from(v: GreErspanDirection) -> u8 {
+        match v {
+            GreErspanDirection::Ingress => ERSPAN_DIR_INGRESS,
+            GreErspanDirection::Egress => ERSPAN_DIR_EGRESS,
+            GreErspanDirection::Other(d) => d,
+        }
+    }
+}
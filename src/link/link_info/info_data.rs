@@ -31,6 +31,7 @@ pub enum InfoData {
     MacVtap(Vec<InfoMacVtap>),
     GreTap(Vec<InfoGreTap>),
     GreTap6(Vec<InfoGreTap6>),
+    Erspan(Vec<InfoGreTap>),
     SitTun(Vec<InfoSitTun>),
     GreTun(Vec<InfoGreTun>),
     GreTun6(Vec<InfoGreTun6>),
@@ -64,6 +65,7 @@ impl Nla for InfoData {
             Self::Tun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTap(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTap6(nlas) => nlas.as_slice().buffer_len(),
+            Self::Erspan(nlas) => nlas.as_slice().buffer_len(),
             Self::SitTun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTun(nlas) => nlas.as_slice().buffer_len(),
             Self::GreTun6(nlas) => nlas.as_slice().buffer_len(),
@@ -92,6 +94,7 @@ impl Nla for InfoData {
             Self::Tun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTap(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTap6(nlas) => nlas.as_slice().emit(buffer),
+            Self::Erspan(nlas) => nlas.as_slice().emit(buffer),
             Self::SitTun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTun(nlas) => nlas.as_slice().emit(buffer),
             Self::GreTun6(nlas) => nlas.as_slice().emit(buffer),
@@ -207,6 +210,15 @@ impl InfoData {
                 }
                 InfoData::GreTap(v)
             }
+            InfoKind::Erspan => {
+                let mut v = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla?;
+                    let parsed = InfoGreTap::parse(nla)?;
+                    v.push(parsed);
+                }
+                InfoData::Erspan(v)
+            }
             InfoKind::GreTap6 => {
                 let mut v = Vec::new();
                 for nla in NlasIterator::new(payload) {
@@ -30,6 +30,7 @@ const IPVTAP: &str = "ipvtap";
 const MACVLAN: &str = "macvlan";
 const MACVTAP: &str = "macvtap";
 const GRETAP: &str = "gretap";
+const ERSPAN: &str = "erspan";
 const IP6GRETAP: &str = "ip6gretap";
 const IPIP: &str = "ipip";
 const SIT: &str = "sit";
@@ -180,6 +181,7 @@ pub enum InfoKind {
     MacVtap,
     GreTap,
     GreTap6,
+    Erspan,
     IpTun,
     SitTun,
     GreTun,
@@ -216,6 +218,7 @@ impl core::fmt::Display for InfoKind {
                 Self::MacVtap => MACVTAP,
                 Self::GreTap => GRETAP,
                 Self::GreTap6 => IP6GRETAP,
+                Self::Erspan => ERSPAN,
                 Self::IpTun => IPIP,
                 Self::SitTun => SIT,
                 Self::GreTun => GRE,
@@ -252,6 +255,7 @@ impl Nla for InfoKind {
             Self::MacVtap => MACVTAP.len(),
             Self::GreTap => GRETAP.len(),
             Self::GreTap6 => IP6GRETAP.len(),
+            Self::Erspan => ERSPAN.len(),
             Self::IpTun => IPIP.len(),
             Self::SitTun => SIT.len(),
             Self::GreTun => GRE.len(),
@@ -304,6 +308,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             MACVTAP => Self::MacVtap,
             GRETAP => Self::GreTap,
             IP6GRETAP => Self::GreTap6,
+            ERSPAN => Self::Erspan,
             IPIP => Self::IpTun,
             SIT => Self::SitTun,
             GRE => Self::GreTun,
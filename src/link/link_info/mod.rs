@@ -6,7 +6,6 @@ mod bridge;
 mod bridge_port;
 mod gre;
 mod gre6;
-mod gre_tap;
 mod gre_tap6;
 mod gtp;
 mod hsr;
@@ -35,9 +34,11 @@ pub use self::bridge::{
 pub use self::bridge_port::{
     BridgePortMulticastRouter, BridgePortState, InfoBridgePort,
 };
-pub use self::gre::InfoGreTun;
+pub use self::gre::{
+    GreEncapFlags, GreEncapType, GreErspanDirection, GreIoFlags, InfoGreTap,
+    InfoGreTun,
+};
 pub use self::gre6::InfoGreTun6;
-pub use self::gre_tap::InfoGreTap;
 pub use self::gre_tap6::InfoGreTap6;
 pub use self::gtp::InfoGtp;
 pub use self::hsr::{HsrProtocol, InfoHsr};
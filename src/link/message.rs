@@ -2,12 +2,16 @@
 
 use alloc::vec::Vec;
 use alloc::vec;
+use axerrno::AxError;
 use netlink_packet_utils::{
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
-use crate::link::{LinkAttribute, LinkHeader, LinkMessageBuffer};
+use crate::link::{
+    attribute::IFLA_PROP_LIST, LinkAttribute, LinkHeader, LinkMessageBuffer,
+    Prop,
+};
 use crate::AddressFamily;
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
@@ -44,6 +48,30 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<LinkMessageBuffer<&'a T>>
     }
 }
 
+impl LinkMessage {
+    // Kernel only accepts nested `IFLA_PROP_LIST` holding `IFLA_ALT_IFNAME`
+    // in `RTM_NEWLINKPROP` and `RTM_DELLINKPROP` requests.
+    pub(crate) fn parse_link_prop<'a, T: AsRef<[u8]> + 'a>(
+        buf: &LinkMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        for nla in buf.attributes() {
+            let nla = nla?;
+            if nla.kind() == IFLA_PROP_LIST && !nla.nested_flag() {
+                return Err(AxError::InvalidInput);
+            }
+        }
+        let msg = Self::parse(buf)?;
+        for attr in msg.attributes.iter() {
+            if let LinkAttribute::PropList(props) = attr {
+                if props.iter().any(|p| !matches!(p, Prop::AltIfName(_))) {
+                    return Err(AxError::InvalidInput);
+                }
+            }
+        }
+        Ok(msg)
+    }
+}
+
 impl<'a, T: AsRef<[u8]> + 'a>
     ParseableParametrized<LinkMessageBuffer<&'a T>, AddressFamily>
     for Vec<LinkAttribute>
@@ -2,7 +2,7 @@
 
 mod af_spec;
 mod attribute;
-mod buffer_tool;
+pub(crate) mod buffer_tool;
 mod down_reason;
 mod event;
 pub(crate) mod ext_mask;
@@ -18,16 +18,17 @@ mod prop_list;
 mod proto_info;
 pub(crate) mod sriov;
 mod stats;
-mod stats64;
+pub(crate) mod stats64;
 mod vlan_protocol;
 mod wireless;
 mod xdp;
 
 pub use self::af_spec::{
-    AfSpecBridge, AfSpecInet, AfSpecInet6, AfSpecUnspec, BridgeVlanInfo,
-    Icmp6Stats, Icmp6StatsBuffer, Inet6CacheInfo, Inet6CacheInfoBuffer,
-    Inet6DevConf, Inet6DevConfBuffer, Inet6IfaceFlags, Inet6Stats,
-    Inet6StatsBuffer, InetDevConf,
+    AfSpecBridge, AfSpecInet, AfSpecInet6, AfSpecMpls, AfSpecUnspec,
+    BridgeVlanInfo, Icmp6Stats, Icmp6StatsBuffer, Inet6CacheInfo,
+    Inet6CacheInfoBuffer, Inet6DevConf, Inet6DevConfBuffer, Inet6IfaceFlags,
+    Inet6Stats, Inet6StatsBuffer, InetDevConf, MplsLinkStats,
+    MplsLinkStatsBuffer,
 };
 pub use self::attribute::LinkAttribute;
 pub use self::down_reason::LinkProtocolDownReason;
@@ -38,13 +39,14 @@ pub use self::link_flag::LinkFlags;
 pub use self::link_info::{
     BondAdInfo, BondMode, BondPortState, BridgeId, BridgeIdBuffer,
     BridgePortMulticastRouter, BridgePortState, BridgeQuerierState,
-    HsrProtocol, InfoBond, InfoBondPort, InfoBridge, InfoBridgePort, InfoData,
-    InfoGreTap, InfoGreTap6, InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr,
-    InfoIpVlan, InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan,
-    InfoMacVtap, InfoPortData, InfoPortKind, InfoSitTun, InfoTun, InfoVeth,
-    InfoVlan, InfoVrf, InfoVti, InfoVxlan, InfoXfrm, IpVlanMode, IpVtapMode,
-    LinkInfo, LinkXstats, MacSecCipherId, MacSecOffload, MacSecValidate,
-    MacVlanMode, MacVtapMode, MiiStatus, VlanQosMapping,
+    GreEncapFlags, GreEncapType, GreErspanDirection, GreIoFlags, HsrProtocol,
+    InfoBond, InfoBondPort, InfoBridge, InfoBridgePort, InfoData, InfoGreTap,
+    InfoGreTap6, InfoGreTun, InfoGreTun6, InfoGtp, InfoHsr, InfoIpVlan,
+    InfoIpVtap, InfoIpoib, InfoKind, InfoMacSec, InfoMacVlan, InfoMacVtap,
+    InfoPortData, InfoPortKind, InfoSitTun, InfoTun, InfoVeth, InfoVlan,
+    InfoVrf, InfoVti, InfoVxlan, InfoXfrm, IpVlanMode, IpVtapMode, LinkInfo,
+    LinkXstats, MacSecCipherId, MacSecOffload, MacSecValidate, MacVlanMode,
+    MacVtapMode, MiiStatus, VlanQosMapping,
 };
 pub use self::link_layer_type::LinkLayerType;
 pub use self::link_state::State;
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 use alloc::string::String;
+use axerrno::AxError;
 use netlink_packet_utils::{
     nla::{DefaultNla, Nla, NlaBuffer},
     parsers::parse_string,
@@ -9,10 +10,13 @@ use netlink_packet_utils::{
 };
 
 const IFLA_ALT_IFNAME: u16 = 53;
+// Including the trailing NUL
+const ALTIFNAMSIZ: usize = 128;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum Prop {
+    /// Alternative interface name, at most 127 bytes.
     AltIfName(String),
     Other(DefaultNla),
 }
@@ -52,11 +56,14 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Prop {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_ALT_IFNAME => Prop::AltIfName(
-                parse_string(payload)
-                    ?,
-            ),
-            kind => Prop::Other(
+            IFLA_ALT_IFNAME => {
+                let name = parse_string(payload)?;
+                if name.len() >= ALTIFNAMSIZ {
+                    return Err(AxError::InvalidInput);
+                }
+                Prop::AltIfName(name)
+            }
+            _ => Prop::Other(
                 DefaultNla::parse(buf)?,
             ),
         })
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const LWT_BPF_IN: u16 = 1;
+const LWT_BPF_OUT: u16 = 2;
+const LWT_BPF_XMIT: u16 = 3;
+const LWT_BPF_XMIT_HEADROOM: u16 = 4;
+
+/// Attribute of `LWTUNNEL_ENCAP_BPF` encapsulation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum LwtunnelBpfAttribute {
+    /// Program for packets received by the route
+    In(Vec<LwtunnelBpfProgAttribute>),
+    /// Program for packets generated locally
+    Out(Vec<LwtunnelBpfProgAttribute>),
+    /// Program for packets about to be transmitted
+    Xmit(Vec<LwtunnelBpfProgAttribute>),
+    /// Headroom reserved for the encapsulation done by [Self::Xmit].
+    XmitHeadroom(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for LwtunnelBpfAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::In(attrs) | Self::Out(attrs) | Self::Xmit(attrs) => {
+                attrs.as_slice().buffer_len()
+            }
+            Self::XmitHeadroom(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::In(attrs) | Self::Out(attrs) | Self::Xmit(attrs) => {
+                attrs.as_slice().emit(buffer)
+            }
+            Self::XmitHeadroom(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::In(_) => LWT_BPF_IN,
+            Self::Out(_) => LWT_BPF_OUT,
+            Self::Xmit(_) => LWT_BPF_XMIT,
+            Self::XmitHeadroom(_) => LWT_BPF_XMIT_HEADROOM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for LwtunnelBpfAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_IN => Self::In(parse_bpf_prog(payload)?),
+            LWT_BPF_OUT => Self::Out(parse_bpf_prog(payload)?),
+            LWT_BPF_XMIT => Self::Xmit(parse_bpf_prog(payload)?),
+            LWT_BPF_XMIT_HEADROOM => Self::XmitHeadroom(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+pub(crate) fn parse_bpf_prog(
+    payload: &[u8],
+) -> Result<Vec<LwtunnelBpfProgAttribute>, DecodeError> {
+    let mut attrs = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        attrs.push(LwtunnelBpfProgAttribute::parse(&nla?)?);
+    }
+    Ok(attrs)
+}
+
+const LWT_BPF_PROG_FD: u16 = 1;
+const LWT_BPF_PROG_NAME: u16 = 2;
+
+/// BPF program of [LwtunnelBpfAttribute] and
+/// [crate::lwtunnel::Seg6LocalAttribute::Bpf]. Kernel only provides the
+/// name.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum LwtunnelBpfProgAttribute {
+    Fd(u32),
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl Nla for LwtunnelBpfProgAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Fd(_) => 4,
+            Self::Name(s) => s.len() + 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Fd(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Name(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Fd(_) => LWT_BPF_PROG_FD,
+            Self::Name(_) => LWT_BPF_PROG_NAME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for LwtunnelBpfProgAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_PROG_FD => Self::Fd(parse_u32(payload)?),
+            LWT_BPF_PROG_NAME => Self::Name(parse_string(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
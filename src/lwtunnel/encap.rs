@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::lwtunnel::{
+    Ioam6IptunnelAttribute, LwtunnelBpfAttribute, LwtunnelIp6Attribute,
+    LwtunnelIpAttribute, MplsIptunnelAttribute, Seg6IptunnelAttribute,
+    Seg6LocalAttribute,
+};
+
+const LWTUNNEL_ENCAP_NONE: u16 = 0;
+const LWTUNNEL_ENCAP_MPLS: u16 = 1;
+const LWTUNNEL_ENCAP_IP: u16 = 2;
+const LWTUNNEL_ENCAP_ILA: u16 = 3;
+const LWTUNNEL_ENCAP_IP6: u16 = 4;
+const LWTUNNEL_ENCAP_SEG6: u16 = 5;
+const LWTUNNEL_ENCAP_BPF: u16 = 6;
+const LWTUNNEL_ENCAP_SEG6_LOCAL: u16 = 7;
+const LWTUNNEL_ENCAP_RPL: u16 = 8;
+const LWTUNNEL_ENCAP_IOAM6: u16 = 9;
+const LWTUNNEL_ENCAP_XFRM: u16 = 10;
+
+/// Lightweight tunnel encapsulation type(`LWTUNNEL_ENCAP_*`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum LwtunnelEncapType {
+    #[default]
+    None,
+    Mpls,
+    Ip,
+    Ila,
+    Ip6,
+    Seg6,
+    Bpf,
+    Seg6Local,
+    Rpl,
+    Ioam6,
+    Xfrm,
+    Other(u16),
+}
+
+impl From<u16> for LwtunnelEncapType {
+    fn from(d: u16) -> Self {
+        match d {
+            LWTUNNEL_ENCAP_NONE => Self::None,
+            LWTUNNEL_ENCAP_MPLS => Self::Mpls,
+            LWTUNNEL_ENCAP_IP => Self::Ip,
+            LWTUNNEL_ENCAP_ILA => Self::Ila,
+            LWTUNNEL_ENCAP_IP6 => Self::Ip6,
+            LWTUNNEL_ENCAP_SEG6 => Self::Seg6,
+            LWTUNNEL_ENCAP_BPF => Self::Bpf,
+            LWTUNNEL_ENCAP_SEG6_LOCAL => Self::Seg6Local,
+            LWTUNNEL_ENCAP_RPL => Self::Rpl,
+            LWTUNNEL_ENCAP_IOAM6 => Self::Ioam6,
+            LWTUNNEL_ENCAP_XFRM => Self::Xfrm,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<LwtunnelEncapType> for u16 {
+    fn from(v: LwtunnelEncapType) -> u16 {
+        match v {
+            LwtunnelEncapType::None => LWTUNNEL_ENCAP_NONE,
+            LwtunnelEncapType::Mpls => LWTUNNEL_ENCAP_MPLS,
+            LwtunnelEncapType::Ip => LWTUNNEL_ENCAP_IP,
+            LwtunnelEncapType::Ila => LWTUNNEL_ENCAP_ILA,
+            LwtunnelEncapType::Ip6 => LWTUNNEL_ENCAP_IP6,
+            LwtunnelEncapType::Seg6 => LWTUNNEL_ENCAP_SEG6,
+            LwtunnelEncapType::Bpf => LWTUNNEL_ENCAP_BPF,
+            LwtunnelEncapType::Seg6Local => LWTUNNEL_ENCAP_SEG6_LOCAL,
+            LwtunnelEncapType::Rpl => LWTUNNEL_ENCAP_RPL,
+            LwtunnelEncapType::Ioam6 => LWTUNNEL_ENCAP_IOAM6,
+            LwtunnelEncapType::Xfrm => LWTUNNEL_ENCAP_XFRM,
+            LwtunnelEncapType::Other(d) => d,
+        }
+    }
+}
+
+/// Nested attributes of lightweight tunnel encapsulation, the layout
+/// depends on [LwtunnelEncapType] provided along with it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum LwtunnelEncap {
+    Mpls(Vec<MplsIptunnelAttribute>),
+    Ip(Vec<LwtunnelIpAttribute>),
+    Ip6(Vec<LwtunnelIp6Attribute>),
+    Seg6(Vec<Seg6IptunnelAttribute>),
+    Seg6Local(Vec<Seg6LocalAttribute>),
+    Bpf(Vec<LwtunnelBpfAttribute>),
+    Ioam6(Vec<Ioam6IptunnelAttribute>),
+    Other(Vec<DefaultNla>),
+}
+
+impl Emitable for LwtunnelEncap {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Mpls(attrs) => attrs.as_slice().buffer_len(),
+            Self::Ip(attrs) => attrs.as_slice().buffer_len(),
+            Self::Ip6(attrs) => attrs.as_slice().buffer_len(),
+            Self::Seg6(attrs) => attrs.as_slice().buffer_len(),
+            Self::Seg6Local(attrs) => attrs.as_slice().buffer_len(),
+            Self::Bpf(attrs) => attrs.as_slice().buffer_len(),
+            Self::Ioam6(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attrs) => attrs.as_slice().buffer_len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mpls(attrs) => attrs.as_slice().emit(buffer),
+            Self::Ip(attrs) => attrs.as_slice().emit(buffer),
+            Self::Ip6(attrs) => attrs.as_slice().emit(buffer),
+            Self::Seg6(attrs) => attrs.as_slice().emit(buffer),
+            Self::Seg6Local(attrs) => attrs.as_slice().emit(buffer),
+            Self::Bpf(attrs) => attrs.as_slice().emit(buffer),
+            Self::Ioam6(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attrs) => attrs.as_slice().emit(buffer),
+        }
+    }
+}
+
+impl ParseableParametrized<[u8], LwtunnelEncapType> for LwtunnelEncap {
+    fn parse_with_param(
+        payload: &[u8],
+        encap_type: LwtunnelEncapType,
+    ) -> Result<Self, DecodeError> {
+        Ok(match encap_type {
+            LwtunnelEncapType::Mpls => Self::Mpls(parse_attributes(payload)?),
+            LwtunnelEncapType::Ip => Self::Ip(parse_attributes(payload)?),
+            LwtunnelEncapType::Ip6 => Self::Ip6(parse_attributes(payload)?),
+            LwtunnelEncapType::Seg6 => Self::Seg6(parse_attributes(payload)?),
+            LwtunnelEncapType::Seg6Local => {
+                Self::Seg6Local(parse_attributes(payload)?)
+            }
+            LwtunnelEncapType::Bpf => Self::Bpf(parse_attributes(payload)?),
+            LwtunnelEncapType::Ioam6 => Self::Ioam6(parse_attributes(payload)?),
+            _ => Self::Other(parse_attributes(payload)?),
+        })
+    }
+}
+
+fn parse_attributes<'a, A: Parseable<NlaBuffer<&'a [u8]>>>(
+    payload: &'a [u8],
+) -> Result<Vec<A>, DecodeError> {
+    let mut attrs = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        attrs.push(A::parse(&nla?)?);
+    }
+    Ok(attrs)
+}
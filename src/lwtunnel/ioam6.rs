@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::Ipv6Addr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const IOAM6_IPTUNNEL_FREQ_K: u16 = 1;
+const IOAM6_IPTUNNEL_FREQ_N: u16 = 2;
+const IOAM6_IPTUNNEL_MODE: u16 = 3;
+const IOAM6_IPTUNNEL_DST: u16 = 4;
+const IOAM6_IPTUNNEL_TRACE: u16 = 5;
+const IOAM6_IPTUNNEL_SRC: u16 = 6;
+
+/// Attribute of `LWTUNNEL_ENCAP_IOAM6` encapsulation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Ioam6IptunnelAttribute {
+    /// Insert IOAM data into `k` packets out of every [Self::FreqN]
+    /// packets.
+    FreqK(u32),
+    FreqN(u32),
+    Mode(Ioam6IptunnelMode),
+    /// Tunnel destination of [Ioam6IptunnelMode::Encap] and
+    /// [Ioam6IptunnelMode::Auto].
+    Destination(Ipv6Addr),
+    /// Pre-allocated trace option header
+    Trace(Ioam6Trace),
+    /// Tunnel source of [Ioam6IptunnelMode::Encap] and
+    /// [Ioam6IptunnelMode::Auto].
+    Source(Ipv6Addr),
+    Other(DefaultNla),
+}
+
+impl Nla for Ioam6IptunnelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::FreqK(_) | Self::FreqN(_) => 4,
+            Self::Mode(_) => 1,
+            Self::Destination(_) | Self::Source(_) => IPV6_ADDR_LEN,
+            Self::Trace(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::FreqK(v) | Self::FreqN(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Mode(v) => buffer[0] = (*v).into(),
+            Self::Destination(v) | Self::Source(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::Trace(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::FreqK(_) => IOAM6_IPTUNNEL_FREQ_K,
+            Self::FreqN(_) => IOAM6_IPTUNNEL_FREQ_N,
+            Self::Mode(_) => IOAM6_IPTUNNEL_MODE,
+            Self::Destination(_) => IOAM6_IPTUNNEL_DST,
+            Self::Trace(_) => IOAM6_IPTUNNEL_TRACE,
+            Self::Source(_) => IOAM6_IPTUNNEL_SRC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Ioam6IptunnelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IOAM6_IPTUNNEL_FREQ_K => Self::FreqK(parse_u32(payload)?),
+            IOAM6_IPTUNNEL_FREQ_N => Self::FreqN(parse_u32(payload)?),
+            IOAM6_IPTUNNEL_MODE => Self::Mode(parse_u8(payload)?.into()),
+            IOAM6_IPTUNNEL_DST => Self::Destination(parse_ipv6_addr(payload)?),
+            IOAM6_IPTUNNEL_TRACE => Self::Trace(Ioam6Trace::parse(
+                &Ioam6TraceBuffer::new_checked(payload)?,
+            )?),
+            IOAM6_IPTUNNEL_SRC => Self::Source(parse_ipv6_addr(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const IOAM6_IPTUNNEL_MODE_INLINE: u8 = 1;
+const IOAM6_IPTUNNEL_MODE_ENCAP: u8 = 2;
+const IOAM6_IPTUNNEL_MODE_AUTO: u8 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Ioam6IptunnelMode {
+    /// Insert the IOAM option into the packet
+    #[default]
+    Inline,
+    /// Encapsulate the packet into outer IPv6 header with the IOAM option
+    Encap,
+    /// [Self::Inline] for locally generated packets, [Self::Encap] for
+    /// forwarded packets.
+    Auto,
+    Other(u8),
+}
+
+impl From<u8> for Ioam6IptunnelMode {
+    fn from(d: u8) -> Self {
+        match d {
+            IOAM6_IPTUNNEL_MODE_INLINE => Self::Inline,
+            IOAM6_IPTUNNEL_MODE_ENCAP => Self::Encap,
+            IOAM6_IPTUNNEL_MODE_AUTO => Self::Auto,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Ioam6IptunnelMode> for u8 {
+    fn from(v: Ioam6IptunnelMode) -> u8 {
+        match v {
+            Ioam6IptunnelMode::Inline => IOAM6_IPTUNNEL_MODE_INLINE,
+            Ioam6IptunnelMode::Encap => IOAM6_IPTUNNEL_MODE_ENCAP,
+            Ioam6IptunnelMode::Auto => IOAM6_IPTUNNEL_MODE_AUTO,
+            Ioam6IptunnelMode::Other(d) => d,
+        }
+    }
+}
+
+const IOAM6_TRACE_LEN: usize = 8;
+
+const IOAM6_TRACE_OVERFLOW: u8 = 1 << 2;
+const IOAM6_TRACE_NODE_LEN_SHIFT: u8 = 3;
+const IOAM6_TRACE_REM_LEN_MASK: u8 = 0x7F;
+// Trace type takes the upper 24 bits, followed by reserved 8 bits
+const IOAM6_TRACE_TYPE_SHIFT: u32 = 8;
+
+buffer!(Ioam6TraceBuffer(IOAM6_TRACE_LEN) {
+    // Stored in network byte order
+    namespace_id: (u16, 0..2),
+    node_len_and_flags: (u8, 2),
+    remaining_len: (u8, 3),
+    // Stored in network byte order
+    trace_type: (u32, 4..IOAM6_TRACE_LEN),
+});
+
+/// IOAM pre-allocated trace option header(`struct ioam6_trace_hdr`)
+/// defined in RFC 9197.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct Ioam6Trace {
+    pub namespace_id: u16,
+    /// Length of data added by each node in 4-octet units, calculated by
+    /// kernel from [Self::trace_type].
+    pub node_len: u8,
+    pub overflow: bool,
+    /// Remaining length for data in 4-octet units
+    pub remaining_len: u8,
+    /// Bitmap of 24 bits, the most significant bit is bit 0 of RFC 9197.
+    pub trace_type: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<Ioam6TraceBuffer<T>> for Ioam6Trace {
+    fn parse(buf: &Ioam6TraceBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            namespace_id: u16::from_be(buf.namespace_id()),
+            node_len: buf.node_len_and_flags() >> IOAM6_TRACE_NODE_LEN_SHIFT,
+            overflow: buf.node_len_and_flags() & IOAM6_TRACE_OVERFLOW > 0,
+            remaining_len: buf.remaining_len() & IOAM6_TRACE_REM_LEN_MASK,
+            trace_type: u32::from_be(buf.trace_type())
+                >> IOAM6_TRACE_TYPE_SHIFT,
+        })
+    }
+}
+
+impl Emitable for Ioam6Trace {
+    fn buffer_len(&self) -> usize {
+        IOAM6_TRACE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = Ioam6TraceBuffer::new(buffer);
+        buffer.set_namespace_id(self.namespace_id.to_be());
+        buffer.set_node_len_and_flags(
+            (self.node_len << IOAM6_TRACE_NODE_LEN_SHIFT)
+                | if self.overflow {
+                    IOAM6_TRACE_OVERFLOW
+                } else {
+                    0
+                },
+        );
+        buffer.set_remaining_len(self.remaining_len & IOAM6_TRACE_REM_LEN_MASK);
+        buffer.set_trace_type(
+            (self.trace_type << IOAM6_TRACE_TYPE_SHIFT).to_be(),
+        );
+    }
+}
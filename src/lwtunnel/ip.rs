@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16_be, parse_u64, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+use crate::ip::{
+    parse_ipv4_addr, parse_ipv6_addr, IPV4_ADDR_LEN, IPV6_ADDR_LEN,
+};
+
+const LWTUNNEL_IP_ID: u16 = 1;
+const LWTUNNEL_IP_DST: u16 = 2;
+const LWTUNNEL_IP_SRC: u16 = 3;
+const LWTUNNEL_IP_TTL: u16 = 4;
+const LWTUNNEL_IP_TOS: u16 = 5;
+const LWTUNNEL_IP_FLAGS: u16 = 6;
+// const LWTUNNEL_IP_PAD: u16 = 7;
+const LWTUNNEL_IP_OPTS: u16 = 8;
+
+const LWTUNNEL_IP6_ID: u16 = 1;
+const LWTUNNEL_IP6_DST: u16 = 2;
+const LWTUNNEL_IP6_SRC: u16 = 3;
+const LWTUNNEL_IP6_HOPLIMIT: u16 = 4;
+const LWTUNNEL_IP6_TC: u16 = 5;
+const LWTUNNEL_IP6_FLAGS: u16 = 6;
+// const LWTUNNEL_IP6_PAD: u16 = 7;
+const LWTUNNEL_IP6_OPTS: u16 = 8;
+
+/// Tunnel key of `LWTUNNEL_ENCAP_IP` encapsulation, used by collect
+/// metadata tunnel devices.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum LwtunnelIpAttribute {
+    /// Tunnel ID, e.g. VNI of VXLAN or key of GRE.
+    Id(u64),
+    Destination(Ipv4Addr),
+    Source(Ipv4Addr),
+    Ttl(u8),
+    Tos(u8),
+    Flags(IpTunnelFlags),
+    /// Tunnel options of Geneve, VXLAN or ERSPAN.
+    Options(DefaultNla),
+    Other(DefaultNla),
+}
+
+impl Nla for LwtunnelIpAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) => 8,
+            Self::Destination(_) | Self::Source(_) => IPV4_ADDR_LEN,
+            Self::Ttl(_) | Self::Tos(_) => 1,
+            Self::Flags(_) => 2,
+            Self::Options(attr) | Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) => BigEndian::write_u64(buffer, *v),
+            Self::Destination(v) | Self::Source(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::Ttl(v) | Self::Tos(v) => buffer[0] = *v,
+            Self::Flags(v) => BigEndian::write_u16(buffer, v.bits()),
+            Self::Options(attr) | Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => LWTUNNEL_IP_ID,
+            Self::Destination(_) => LWTUNNEL_IP_DST,
+            Self::Source(_) => LWTUNNEL_IP_SRC,
+            Self::Ttl(_) => LWTUNNEL_IP_TTL,
+            Self::Tos(_) => LWTUNNEL_IP_TOS,
+            Self::Flags(_) => LWTUNNEL_IP_FLAGS,
+            Self::Options(attr) | Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for LwtunnelIpAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_ID => Self::Id(u64::from_be(parse_u64(payload)?)),
+            LWTUNNEL_IP_DST => Self::Destination(parse_ipv4_addr(payload)?),
+            LWTUNNEL_IP_SRC => Self::Source(parse_ipv4_addr(payload)?),
+            LWTUNNEL_IP_TTL => Self::Ttl(parse_u8(payload)?),
+            LWTUNNEL_IP_TOS => Self::Tos(parse_u8(payload)?),
+            LWTUNNEL_IP_FLAGS => Self::Flags(IpTunnelFlags::from_bits_retain(
+                parse_u16_be(payload)?,
+            )),
+            LWTUNNEL_IP_OPTS => Self::Options(DefaultNla::parse(buf)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// Tunnel key of `LWTUNNEL_ENCAP_IP6` encapsulation, used by collect
+/// metadata tunnel devices.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum LwtunnelIp6Attribute {
+    /// Tunnel ID, e.g. VNI of VXLAN or key of GRE.
+    Id(u64),
+    Destination(Ipv6Addr),
+    Source(Ipv6Addr),
+    HopLimit(u8),
+    TrafficClass(u8),
+    Flags(IpTunnelFlags),
+    /// Tunnel options of Geneve, VXLAN or ERSPAN.
+    Options(DefaultNla),
+    Other(DefaultNla),
+}
+
+impl Nla for LwtunnelIp6Attribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Id(_) => 8,
+            Self::Destination(_) | Self::Source(_) => IPV6_ADDR_LEN,
+            Self::HopLimit(_) | Self::TrafficClass(_) => 1,
+            Self::Flags(_) => 2,
+            Self::Options(attr) | Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Id(v) => BigEndian::write_u64(buffer, *v),
+            Self::Destination(v) | Self::Source(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::HopLimit(v) | Self::TrafficClass(v) => buffer[0] = *v,
+            Self::Flags(v) => BigEndian::write_u16(buffer, v.bits()),
+            Self::Options(attr) | Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => LWTUNNEL_IP6_ID,
+            Self::Destination(_) => LWTUNNEL_IP6_DST,
+            Self::Source(_) => LWTUNNEL_IP6_SRC,
+            Self::HopLimit(_) => LWTUNNEL_IP6_HOPLIMIT,
+            Self::TrafficClass(_) => LWTUNNEL_IP6_TC,
+            Self::Flags(_) => LWTUNNEL_IP6_FLAGS,
+            Self::Options(attr) | Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for LwtunnelIp6Attribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP6_ID => Self::Id(u64::from_be(parse_u64(payload)?)),
+            LWTUNNEL_IP6_DST => Self::Destination(parse_ipv6_addr(payload)?),
+            LWTUNNEL_IP6_SRC => Self::Source(parse_ipv6_addr(payload)?),
+            LWTUNNEL_IP6_HOPLIMIT => Self::HopLimit(parse_u8(payload)?),
+            LWTUNNEL_IP6_TC => Self::TrafficClass(parse_u8(payload)?),
+            LWTUNNEL_IP6_FLAGS => Self::Flags(IpTunnelFlags::from_bits_retain(
+                parse_u16_be(payload)?,
+            )),
+            LWTUNNEL_IP6_OPTS => Self::Options(DefaultNla::parse(buf)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TUNNEL_CSUM: u16 = 1 << 0;
+const TUNNEL_KEY: u16 = 1 << 2;
+const TUNNEL_SEQ: u16 = 1 << 3;
+const TUNNEL_DONT_FRAGMENT: u16 = 1 << 8;
+const TUNNEL_OAM: u16 = 1 << 9;
+const TUNNEL_CRIT_OPT: u16 = 1 << 10;
+
+bitflags! {
+    /// The `TUNNEL_*` flags of tunnel key, stored in network byte order.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct IpTunnelFlags: u16 {
+        const Csum = TUNNEL_CSUM;
+        const Key = TUNNEL_KEY;
+        const Seq = TUNNEL_SEQ;
+        const DontFragment = TUNNEL_DONT_FRAGMENT;
+        const Oam = TUNNEL_OAM;
+        const CritOpt = TUNNEL_CRIT_OPT;
+        const _ = !0;
+    }
+}
+
+impl Default for IpTunnelFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
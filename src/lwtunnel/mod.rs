@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+
+mod bpf;
+mod encap;
+mod ioam6;
+mod ip;
+mod mpls;
+mod seg6;
+mod seg6_local;
+mod srh;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::bpf::{LwtunnelBpfAttribute, LwtunnelBpfProgAttribute};
+pub use self::encap::{LwtunnelEncap, LwtunnelEncapType};
+pub use self::ioam6::{
+    Ioam6IptunnelAttribute, Ioam6IptunnelMode, Ioam6Trace, Ioam6TraceBuffer,
+};
+pub use self::ip::{IpTunnelFlags, LwtunnelIp6Attribute, LwtunnelIpAttribute};
+pub(crate) use self::mpls::{
+    emit_mpls_labels, mpls_labels_len, parse_mpls_labels, MPLS_LABEL_LEN,
+};
+pub use self::mpls::{MplsIptunnelAttribute, MplsLabel};
+pub use self::seg6::{
+    Seg6IptunnelAttribute, Seg6IptunnelEncap, Seg6IptunnelEncapBuffer,
+    Seg6IptunnelMode,
+};
+pub use self::seg6_local::{
+    Seg6LocalAction, Seg6LocalAttribute, Seg6LocalCounterAttribute,
+    Seg6LocalFlavorAttribute, Seg6LocalFlavors,
+};
+pub use self::srh::{
+    Seg6Header, Seg6HeaderBuffer, Seg6HeaderFlags, Seg6Tlv, Seg6TlvHmac,
+};
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use axerrno::AxError;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u8,
+    traits::Parseable,
+    DecodeError,
+};
+
+const MPLS_IPTUNNEL_DST: u16 = 1;
+const MPLS_IPTUNNEL_TTL: u16 = 2;
+
+pub(crate) const MPLS_LABEL_LEN: usize = 4;
+
+const MPLS_LS_LABEL_MASK: u32 = 0xFFFFF000;
+const MPLS_LS_LABEL_SHIFT: u32 = 12;
+const MPLS_LS_TC_MASK: u32 = 0x00000E00;
+const MPLS_LS_TC_SHIFT: u32 = 9;
+const MPLS_LS_S_MASK: u32 = 0x00000100;
+const MPLS_LS_TTL_MASK: u32 = 0x000000FF;
+
+/// MPLS label stack entry(`struct mpls_label`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct MplsLabel {
+    /// Label value, 20 bits.
+    pub label: u32,
+    /// Traffic class, 3 bits.
+    pub traffic_class: u8,
+    /// Bottom of stack, should only be set on the last entry.
+    pub bottom_of_stack: bool,
+    pub ttl: u8,
+}
+
+impl MplsLabel {
+    pub fn new(label: u32) -> Self {
+        Self {
+            label,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<u32> for MplsLabel {
+    fn from(d: u32) -> Self {
+        Self {
+            label: (d & MPLS_LS_LABEL_MASK) >> MPLS_LS_LABEL_SHIFT,
+            traffic_class: ((d & MPLS_LS_TC_MASK) >> MPLS_LS_TC_SHIFT) as u8,
+            bottom_of_stack: (d & MPLS_LS_S_MASK) > 0,
+            ttl: (d & MPLS_LS_TTL_MASK) as u8,
+        }
+    }
+}
+
+impl From<MplsLabel> for u32 {
+    fn from(v: MplsLabel) -> u32 {
+        ((v.label << MPLS_LS_LABEL_SHIFT) & MPLS_LS_LABEL_MASK)
+            | (((v.traffic_class as u32) << MPLS_LS_TC_SHIFT) & MPLS_LS_TC_MASK)
+            | if v.bottom_of_stack { MPLS_LS_S_MASK } else { 0 }
+            | v.ttl as u32
+    }
+}
+
+/// Parse label stack stored in network byte order.
+pub(crate) fn parse_mpls_labels(
+    payload: &[u8],
+) -> Result<Vec<MplsLabel>, DecodeError> {
+    if !payload.len().is_multiple_of(MPLS_LABEL_LEN) {
+        return Err(AxError::InvalidInput);
+    }
+    Ok(payload
+        .chunks(MPLS_LABEL_LEN)
+        .map(|chunk| BigEndian::read_u32(chunk).into())
+        .collect())
+}
+
+pub(crate) fn mpls_labels_len(labels: &[MplsLabel]) -> usize {
+    labels.len() * MPLS_LABEL_LEN
+}
+
+pub(crate) fn emit_mpls_labels(labels: &[MplsLabel], buffer: &mut [u8]) {
+    for (label, chunk) in labels.iter().zip(buffer.chunks_mut(MPLS_LABEL_LEN)) {
+        BigEndian::write_u32(chunk, (*label).into());
+    }
+}
+
+/// Attribute of `LWTUNNEL_ENCAP_MPLS` encapsulation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MplsIptunnelAttribute {
+    /// Label stack to push
+    Destination(Vec<MplsLabel>),
+    /// TTL of the pushed labels, 0 means propagating from IP header.
+    Ttl(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MplsIptunnelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Destination(labels) => mpls_labels_len(labels),
+            Self::Ttl(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Destination(labels) => emit_mpls_labels(labels, buffer),
+            Self::Ttl(v) => buffer[0] = *v,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Destination(_) => MPLS_IPTUNNEL_DST,
+            Self::Ttl(_) => MPLS_IPTUNNEL_TTL,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MplsIptunnelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MPLS_IPTUNNEL_DST => Self::Destination(parse_mpls_labels(payload)?),
+            MPLS_IPTUNNEL_TTL => Self::Ttl(parse_u8(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
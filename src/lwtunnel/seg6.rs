@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::lwtunnel::{Seg6Header, Seg6HeaderBuffer};
+
+const SEG6_IPTUNNEL_SRH: u16 = 1;
+
+const SEG6_IPTUNNEL_ENCAP_HEADER_LEN: usize = 4;
+
+buffer!(Seg6IptunnelEncapBuffer(SEG6_IPTUNNEL_ENCAP_HEADER_LEN) {
+    mode: (u32, 0..SEG6_IPTUNNEL_ENCAP_HEADER_LEN),
+    srh: (slice, SEG6_IPTUNNEL_ENCAP_HEADER_LEN..),
+});
+
+/// Attribute of `LWTUNNEL_ENCAP_SEG6` encapsulation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6IptunnelAttribute {
+    Srh(Seg6IptunnelEncap),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6IptunnelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Srh(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Srh(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Srh(_) => SEG6_IPTUNNEL_SRH,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Seg6IptunnelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_IPTUNNEL_SRH => Self::Srh(Seg6IptunnelEncap::parse(
+                &Seg6IptunnelEncapBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// The `struct seg6_iptunnel_encap`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Seg6IptunnelEncap {
+    pub mode: Seg6IptunnelMode,
+    pub header: Seg6Header,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Seg6IptunnelEncapBuffer<&'a T>>
+    for Seg6IptunnelEncap
+{
+    fn parse(
+        buf: &Seg6IptunnelEncapBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mode: buf.mode().into(),
+            header: Seg6Header::parse(&Seg6HeaderBuffer::new_checked(
+                buf.srh(),
+            )?)?,
+        })
+    }
+}
+
+impl Emitable for Seg6IptunnelEncap {
+    fn buffer_len(&self) -> usize {
+        SEG6_IPTUNNEL_ENCAP_HEADER_LEN + self.header.buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = Seg6IptunnelEncapBuffer::new(buffer);
+        buffer.set_mode(self.mode.into());
+        self.header.emit(buffer.srh_mut());
+    }
+}
+
+const SEG6_IPTUN_MODE_INLINE: u32 = 0;
+const SEG6_IPTUN_MODE_ENCAP: u32 = 1;
+const SEG6_IPTUN_MODE_L2ENCAP: u32 = 2;
+const SEG6_IPTUN_MODE_ENCAP_RED: u32 = 3;
+const SEG6_IPTUN_MODE_L2ENCAP_RED: u32 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Seg6IptunnelMode {
+    /// Insert the SRH into the packet
+    #[default]
+    Inline,
+    /// Encapsulate the packet into outer IPv6 header with the SRH
+    Encap,
+    /// Encapsulate the layer 2 frame into outer IPv6 header with the SRH
+    L2Encap,
+    /// Same as [Self::Encap] with reduced SRH, the first segment is only
+    /// carried by the outer IPv6 destination address.
+    EncapReduced,
+    /// Same as [Self::L2Encap] with reduced SRH
+    L2EncapReduced,
+    Other(u32),
+}
+
+impl From<u32> for Seg6IptunnelMode {
+    fn from(d: u32) -> Self {
+        match d {
+            SEG6_IPTUN_MODE_INLINE => Self::Inline,
+            SEG6_IPTUN_MODE_ENCAP => Self::Encap,
+            SEG6_IPTUN_MODE_L2ENCAP => Self::L2Encap,
+            SEG6_IPTUN_MODE_ENCAP_RED => Self::EncapReduced,
+            SEG6_IPTUN_MODE_L2ENCAP_RED => Self::L2EncapReduced,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Seg6IptunnelMode> for u32 {
+    fn from(v: Seg6IptunnelMode) -> u32 {
+        match v {
+            Seg6IptunnelMode::Inline => SEG6_IPTUN_MODE_INLINE,
+            Seg6IptunnelMode::Encap => SEG6_IPTUN_MODE_ENCAP,
+            Seg6IptunnelMode::L2Encap => SEG6_IPTUN_MODE_L2ENCAP,
+            Seg6IptunnelMode::EncapReduced => SEG6_IPTUN_MODE_ENCAP_RED,
+            Seg6IptunnelMode::L2EncapReduced => SEG6_IPTUN_MODE_L2ENCAP_RED,
+            Seg6IptunnelMode::Other(d) => d,
+        }
+    }
+}
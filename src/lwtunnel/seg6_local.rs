@@ -0,0 +1,408 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    ip::{parse_ipv4_addr, parse_ipv6_addr, IPV4_ADDR_LEN, IPV6_ADDR_LEN},
+    lwtunnel::{
+        bpf::parse_bpf_prog, LwtunnelBpfProgAttribute, Seg6Header,
+        Seg6HeaderBuffer,
+    },
+};
+
+const SEG6_LOCAL_ACTION: u16 = 1;
+const SEG6_LOCAL_SRH: u16 = 2;
+const SEG6_LOCAL_TABLE: u16 = 3;
+const SEG6_LOCAL_NH4: u16 = 4;
+const SEG6_LOCAL_NH6: u16 = 5;
+const SEG6_LOCAL_IIF: u16 = 6;
+const SEG6_LOCAL_OIF: u16 = 7;
+const SEG6_LOCAL_BPF: u16 = 8;
+const SEG6_LOCAL_VRFTABLE: u16 = 9;
+const SEG6_LOCAL_COUNTERS: u16 = 10;
+const SEG6_LOCAL_FLAVORS: u16 = 11;
+
+/// Attribute of `LWTUNNEL_ENCAP_SEG6_LOCAL` encapsulation, the required
+/// attributes depend on [Self::Action].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6LocalAttribute {
+    Action(Seg6LocalAction),
+    /// SRH to push, used by [Seg6LocalAction::EndB6] and
+    /// [Seg6LocalAction::EndB6Encap].
+    Srh(Seg6Header),
+    /// Routing table to lookup, used by [Seg6LocalAction::EndT] and
+    /// [Seg6LocalAction::EndDt6].
+    Table(u32),
+    /// IPv4 next hop of [Seg6LocalAction::EndDx4]
+    Nh4(Ipv4Addr),
+    /// IPv6 next hop of [Seg6LocalAction::EndX] and
+    /// [Seg6LocalAction::EndDx6]
+    Nh6(Ipv6Addr),
+    Iif(u32),
+    /// Output interface of [Seg6LocalAction::EndDx2]
+    Oif(u32),
+    /// Program of [Seg6LocalAction::EndBpf]
+    Bpf(Vec<LwtunnelBpfProgAttribute>),
+    /// VRF table to lookup, used by [Seg6LocalAction::EndDt4],
+    /// [Seg6LocalAction::EndDt6] and [Seg6LocalAction::EndDt46].
+    VrfTable(u32),
+    Counters(Vec<Seg6LocalCounterAttribute>),
+    Flavors(Vec<Seg6LocalFlavorAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6LocalAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Action(_)
+            | Self::Table(_)
+            | Self::Iif(_)
+            | Self::Oif(_)
+            | Self::VrfTable(_) => 4,
+            Self::Srh(v) => v.buffer_len(),
+            Self::Nh4(_) => IPV4_ADDR_LEN,
+            Self::Nh6(_) => IPV6_ADDR_LEN,
+            Self::Bpf(attrs) => attrs.as_slice().buffer_len(),
+            Self::Counters(attrs) => attrs.as_slice().buffer_len(),
+            Self::Flavors(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Action(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Table(v)
+            | Self::Iif(v)
+            | Self::Oif(v)
+            | Self::VrfTable(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Srh(v) => v.emit(buffer),
+            Self::Nh4(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Nh6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Bpf(attrs) => attrs.as_slice().emit(buffer),
+            Self::Counters(attrs) => attrs.as_slice().emit(buffer),
+            Self::Flavors(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Action(_) => SEG6_LOCAL_ACTION,
+            Self::Srh(_) => SEG6_LOCAL_SRH,
+            Self::Table(_) => SEG6_LOCAL_TABLE,
+            Self::Nh4(_) => SEG6_LOCAL_NH4,
+            Self::Nh6(_) => SEG6_LOCAL_NH6,
+            Self::Iif(_) => SEG6_LOCAL_IIF,
+            Self::Oif(_) => SEG6_LOCAL_OIF,
+            Self::Bpf(_) => SEG6_LOCAL_BPF,
+            Self::VrfTable(_) => SEG6_LOCAL_VRFTABLE,
+            Self::Counters(_) => SEG6_LOCAL_COUNTERS | NLA_F_NESTED,
+            Self::Flavors(_) => SEG6_LOCAL_FLAVORS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Seg6LocalAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_ACTION => Self::Action(parse_u32(payload)?.into()),
+            SEG6_LOCAL_SRH => Self::Srh(Seg6Header::parse(
+                &Seg6HeaderBuffer::new_checked(payload)?,
+            )?),
+            SEG6_LOCAL_TABLE => Self::Table(parse_u32(payload)?),
+            SEG6_LOCAL_NH4 => Self::Nh4(parse_ipv4_addr(payload)?),
+            SEG6_LOCAL_NH6 => Self::Nh6(parse_ipv6_addr(payload)?),
+            SEG6_LOCAL_IIF => Self::Iif(parse_u32(payload)?),
+            SEG6_LOCAL_OIF => Self::Oif(parse_u32(payload)?),
+            SEG6_LOCAL_BPF => Self::Bpf(parse_bpf_prog(payload)?),
+            SEG6_LOCAL_VRFTABLE => Self::VrfTable(parse_u32(payload)?),
+            SEG6_LOCAL_COUNTERS => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(Seg6LocalCounterAttribute::parse(&nla?)?);
+                }
+                Self::Counters(attrs)
+            }
+            SEG6_LOCAL_FLAVORS => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(Seg6LocalFlavorAttribute::parse(&nla?)?);
+                }
+                Self::Flavors(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const SEG6_LOCAL_ACTION_UNSPEC: u32 = 0;
+const SEG6_LOCAL_ACTION_END: u32 = 1;
+const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+const SEG6_LOCAL_ACTION_END_T: u32 = 3;
+const SEG6_LOCAL_ACTION_END_DX2: u32 = 4;
+const SEG6_LOCAL_ACTION_END_DX6: u32 = 5;
+const SEG6_LOCAL_ACTION_END_DX4: u32 = 6;
+const SEG6_LOCAL_ACTION_END_DT6: u32 = 7;
+const SEG6_LOCAL_ACTION_END_DT4: u32 = 8;
+const SEG6_LOCAL_ACTION_END_B6: u32 = 9;
+const SEG6_LOCAL_ACTION_END_B6_ENCAP: u32 = 10;
+const SEG6_LOCAL_ACTION_END_BM: u32 = 11;
+const SEG6_LOCAL_ACTION_END_S: u32 = 12;
+const SEG6_LOCAL_ACTION_END_AS: u32 = 13;
+const SEG6_LOCAL_ACTION_END_AM: u32 = 14;
+const SEG6_LOCAL_ACTION_END_BPF: u32 = 15;
+const SEG6_LOCAL_ACTION_END_DT46: u32 = 16;
+
+/// SRv6 local segment behavior defined in RFC 8986.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Seg6LocalAction {
+    #[default]
+    Unspec,
+    /// Endpoint
+    End,
+    /// Endpoint with layer 3 cross-connect
+    EndX,
+    /// Endpoint with specific IPv6 table lookup
+    EndT,
+    /// Decapsulation and layer 2 cross-connect
+    EndDx2,
+    /// Decapsulation and IPv6 cross-connect
+    EndDx6,
+    /// Decapsulation and IPv4 cross-connect
+    EndDx4,
+    /// Decapsulation and specific IPv6 table lookup
+    EndDt6,
+    /// Decapsulation and specific IPv4 table lookup
+    EndDt4,
+    /// Endpoint bound to an SRv6 policy
+    EndB6,
+    /// Endpoint bound to an SRv6 encapsulation policy
+    EndB6Encap,
+    /// Endpoint bound to an SR-MPLS policy
+    EndBm,
+    /// Endpoint in search of a target in table
+    EndS,
+    /// Static proxy
+    EndAs,
+    /// Masquerading proxy
+    EndAm,
+    /// Custom behavior implemented in BPF
+    EndBpf,
+    /// Decapsulation and specific IP table lookup
+    EndDt46,
+    Other(u32),
+}
+
+impl From<u32> for Seg6LocalAction {
+    fn from(d: u32) -> Self {
+        match d {
+            SEG6_LOCAL_ACTION_UNSPEC => Self::Unspec,
+            SEG6_LOCAL_ACTION_END => Self::End,
+            SEG6_LOCAL_ACTION_END_X => Self::EndX,
+            SEG6_LOCAL_ACTION_END_T => Self::EndT,
+            SEG6_LOCAL_ACTION_END_DX2 => Self::EndDx2,
+            SEG6_LOCAL_ACTION_END_DX6 => Self::EndDx6,
+            SEG6_LOCAL_ACTION_END_DX4 => Self::EndDx4,
+            SEG6_LOCAL_ACTION_END_DT6 => Self::EndDt6,
+            SEG6_LOCAL_ACTION_END_DT4 => Self::EndDt4,
+            SEG6_LOCAL_ACTION_END_B6 => Self::EndB6,
+            SEG6_LOCAL_ACTION_END_B6_ENCAP => Self::EndB6Encap,
+            SEG6_LOCAL_ACTION_END_BM => Self::EndBm,
+            SEG6_LOCAL_ACTION_END_S => Self::EndS,
+            SEG6_LOCAL_ACTION_END_AS => Self::EndAs,
+            SEG6_LOCAL_ACTION_END_AM => Self::EndAm,
+            SEG6_LOCAL_ACTION_END_BPF => Self::EndBpf,
+            SEG6_LOCAL_ACTION_END_DT46 => Self::EndDt46,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<Seg6LocalAction> for u32 {
+    fn from(v: Seg6LocalAction) -> u32 {
+        match v {
+            Seg6LocalAction::Unspec => SEG6_LOCAL_ACTION_UNSPEC,
+            Seg6LocalAction::End => SEG6_LOCAL_ACTION_END,
+            Seg6LocalAction::EndX => SEG6_LOCAL_ACTION_END_X,
+            Seg6LocalAction::EndT => SEG6_LOCAL_ACTION_END_T,
+            Seg6LocalAction::EndDx2 => SEG6_LOCAL_ACTION_END_DX2,
+            Seg6LocalAction::EndDx6 => SEG6_LOCAL_ACTION_END_DX6,
+            Seg6LocalAction::EndDx4 => SEG6_LOCAL_ACTION_END_DX4,
+            Seg6LocalAction::EndDt6 => SEG6_LOCAL_ACTION_END_DT6,
+            Seg6LocalAction::EndDt4 => SEG6_LOCAL_ACTION_END_DT4,
+            Seg6LocalAction::EndB6 => SEG6_LOCAL_ACTION_END_B6,
+            Seg6LocalAction::EndB6Encap => SEG6_LOCAL_ACTION_END_B6_ENCAP,
+            Seg6LocalAction::EndBm => SEG6_LOCAL_ACTION_END_BM,
+            Seg6LocalAction::EndS => SEG6_LOCAL_ACTION_END_S,
+            Seg6LocalAction::EndAs => SEG6_LOCAL_ACTION_END_AS,
+            Seg6LocalAction::EndAm => SEG6_LOCAL_ACTION_END_AM,
+            Seg6LocalAction::EndBpf => SEG6_LOCAL_ACTION_END_BPF,
+            Seg6LocalAction::EndDt46 => SEG6_LOCAL_ACTION_END_DT46,
+            Seg6LocalAction::Other(d) => d,
+        }
+    }
+}
+
+// const SEG6_LOCAL_CNT_PAD: u16 = 1;
+const SEG6_LOCAL_CNT_PACKETS: u16 = 2;
+const SEG6_LOCAL_CNT_BYTES: u16 = 3;
+const SEG6_LOCAL_CNT_ERRORS: u16 = 4;
+
+/// Per-route counters of seg6local behavior. An empty
+/// [Seg6LocalAttribute::Counters] enables the counters on creation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6LocalCounterAttribute {
+    Packets(u64),
+    Bytes(u64),
+    Errors(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6LocalCounterAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Packets(_) | Self::Bytes(_) | Self::Errors(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Packets(v) | Self::Bytes(v) | Self::Errors(v) => {
+                NativeEndian::write_u64(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Packets(_) => SEG6_LOCAL_CNT_PACKETS,
+            Self::Bytes(_) => SEG6_LOCAL_CNT_BYTES,
+            Self::Errors(_) => SEG6_LOCAL_CNT_ERRORS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Seg6LocalCounterAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_CNT_PACKETS => Self::Packets(parse_u64(payload)?),
+            SEG6_LOCAL_CNT_BYTES => Self::Bytes(parse_u64(payload)?),
+            SEG6_LOCAL_CNT_ERRORS => Self::Errors(parse_u64(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const SEG6_LOCAL_FLV_OPERATION: u16 = 1;
+const SEG6_LOCAL_FLV_LCBLOCK_BITS: u16 = 2;
+const SEG6_LOCAL_FLV_LCNODE_FN_BITS: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6LocalFlavorAttribute {
+    Operation(Seg6LocalFlavors),
+    /// Locator block length in bits of compressed SID(NEXT-C-SID)
+    LocatorBlockBits(u8),
+    /// Locator node and function length in bits of compressed SID
+    LocatorNodeFunctionBits(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6LocalFlavorAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Operation(_) => 4,
+            Self::LocatorBlockBits(_) | Self::LocatorNodeFunctionBits(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Operation(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::LocatorBlockBits(v) | Self::LocatorNodeFunctionBits(v) => {
+                buffer[0] = *v
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Operation(_) => SEG6_LOCAL_FLV_OPERATION,
+            Self::LocatorBlockBits(_) => SEG6_LOCAL_FLV_LCBLOCK_BITS,
+            Self::LocatorNodeFunctionBits(_) => SEG6_LOCAL_FLV_LCNODE_FN_BITS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for Seg6LocalFlavorAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_FLV_OPERATION => Self::Operation(
+                Seg6LocalFlavors::from_bits_retain(parse_u32(payload)?),
+            ),
+            SEG6_LOCAL_FLV_LCBLOCK_BITS => {
+                Self::LocatorBlockBits(parse_u8(payload)?)
+            }
+            SEG6_LOCAL_FLV_LCNODE_FN_BITS => {
+                Self::LocatorNodeFunctionBits(parse_u8(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const SEG6_LOCAL_FLV_OP_PSP: u32 = 1 << 1;
+const SEG6_LOCAL_FLV_OP_USP: u32 = 1 << 2;
+const SEG6_LOCAL_FLV_OP_USD: u32 = 1 << 3;
+const SEG6_LOCAL_FLV_OP_NEXT_CSID: u32 = 1 << 4;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct Seg6LocalFlavors: u32 {
+        /// Penultimate Segment Pop
+        const Psp = SEG6_LOCAL_FLV_OP_PSP;
+        /// Ultimate Segment Pop
+        const Usp = SEG6_LOCAL_FLV_OP_USP;
+        /// Ultimate Segment Decapsulation
+        const Usd = SEG6_LOCAL_FLV_OP_USD;
+        /// Compressed SID of NEXT-C-SID flavor
+        const NextCsid = SEG6_LOCAL_FLV_OP_NEXT_CSID;
+        const _ = !0;
+    }
+}
+
+impl Default for Seg6LocalFlavors {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::Ipv6Addr;
+
+use axerrno::AxError;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+const SEG6_HEADER_LEN: usize = 8;
+// Header extension length is in units of 8 bytes, excluding the first 8
+// bytes
+const SEG6_HEADER_LEN_UNIT: usize = 8;
+const IPV6_SRCRT_TYPE_4: u8 = 4;
+
+buffer!(Seg6HeaderBuffer(SEG6_HEADER_LEN) {
+    next_header: (u8, 0),
+    header_len: (u8, 1),
+    routing_type: (u8, 2),
+    segments_left: (u8, 3),
+    last_entry: (u8, 4),
+    flags: (u8, 5),
+    // Stored in network byte order
+    tag: (u16, 6..SEG6_HEADER_LEN),
+    payload: (slice, SEG6_HEADER_LEN..),
+});
+
+/// IPv6 Segment Routing Header(`struct ipv6_sr_hdr`) defined in RFC 8754.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Seg6Header {
+    /// Ignored by kernel for encapsulation, which sets it by itself.
+    pub next_header: u8,
+    pub segments_left: u8,
+    pub flags: Seg6HeaderFlags,
+    pub tag: u16,
+    /// Segment list in reverse order, the first entry is the last segment
+    /// of the path.
+    pub segments: Vec<Ipv6Addr>,
+    /// TLVs without padding, which is generated on emit.
+    pub tlvs: Vec<Seg6Tlv>,
+}
+
+impl Seg6Header {
+    fn tlvs_len(&self) -> usize {
+        self.tlvs.iter().map(|tlv| tlv.buffer_len()).sum()
+    }
+
+    // Padded with a single Pad1 byte or a PadN, the segments are always
+    // multiple of 8 bytes
+    fn padding_len(&self) -> usize {
+        match self.tlvs_len() % SEG6_HEADER_LEN_UNIT {
+            0 => 0,
+            len => SEG6_HEADER_LEN_UNIT - len,
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<Seg6HeaderBuffer<&'a T>>
+    for Seg6Header
+{
+    fn parse(buf: &Seg6HeaderBuffer<&'a T>) -> Result<Self, DecodeError> {
+        if buf.routing_type() != IPV6_SRCRT_TYPE_4 {
+            return Err(AxError::InvalidInput);
+        }
+        let len = buf.header_len() as usize * SEG6_HEADER_LEN_UNIT;
+        let segments_len = (buf.last_entry() as usize + 1) * IPV6_ADDR_LEN;
+        let payload = buf.payload();
+        if payload.len() < len || len < segments_len {
+            return Err(AxError::InvalidInput);
+        }
+        let mut segments = Vec::new();
+        for chunk in payload[..segments_len].chunks(IPV6_ADDR_LEN) {
+            segments.push(parse_ipv6_addr(chunk)?);
+        }
+        Ok(Self {
+            next_header: buf.next_header(),
+            segments_left: buf.segments_left(),
+            flags: Seg6HeaderFlags::from_bits_retain(buf.flags()),
+            tag: u16::from_be(buf.tag()),
+            segments,
+            tlvs: parse_seg6_tlvs(&payload[segments_len..len])?,
+        })
+    }
+}
+
+impl Emitable for Seg6Header {
+    fn buffer_len(&self) -> usize {
+        SEG6_HEADER_LEN
+            + self.segments.len() * IPV6_ADDR_LEN
+            + self.tlvs_len()
+            + self.padding_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        let mut buffer = Seg6HeaderBuffer::new(&mut buffer[..len]);
+        buffer.set_next_header(self.next_header);
+        buffer.set_header_len(
+            ((len - SEG6_HEADER_LEN) / SEG6_HEADER_LEN_UNIT) as u8,
+        );
+        buffer.set_routing_type(IPV6_SRCRT_TYPE_4);
+        buffer.set_segments_left(self.segments_left);
+        buffer.set_last_entry(self.segments.len().saturating_sub(1) as u8);
+        buffer.set_flags(self.flags.bits());
+        buffer.set_tag(self.tag.to_be());
+        let payload = buffer.payload_mut();
+        let mut offset = 0;
+        for segment in self.segments.iter() {
+            payload[offset..offset + IPV6_ADDR_LEN]
+                .copy_from_slice(&segment.octets());
+            offset += IPV6_ADDR_LEN;
+        }
+        for tlv in self.tlvs.iter() {
+            tlv.emit(&mut payload[offset..]);
+            offset += tlv.buffer_len();
+        }
+        let padding = &mut payload[offset..];
+        padding.fill(0);
+        match padding.len() {
+            0 => (),
+            1 => padding[0] = SR6_TLV_PAD1,
+            len => {
+                padding[0] = SR6_TLV_PADDING;
+                padding[1] = (len - SR6_TLV_HEADER_LEN) as u8;
+            }
+        }
+    }
+}
+
+const SR6_FLAG1_PROTECTED: u8 = 1 << 6;
+const SR6_FLAG1_OAM: u8 = 1 << 5;
+const SR6_FLAG1_ALERT: u8 = 1 << 4;
+const SR6_FLAG1_HMAC: u8 = 1 << 3;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct Seg6HeaderFlags: u8 {
+        const Protected = SR6_FLAG1_PROTECTED;
+        const Oam = SR6_FLAG1_OAM;
+        const Alert = SR6_FLAG1_ALERT;
+        /// [Seg6Tlv::Hmac] is present
+        const Hmac = SR6_FLAG1_HMAC;
+        const _ = !0;
+    }
+}
+
+impl Default for Seg6HeaderFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const SR6_TLV_HEADER_LEN: usize = 2;
+
+const SR6_TLV_PAD1: u8 = 0;
+const SR6_TLV_OPAQUE: u8 = 3;
+const SR6_TLV_PADDING: u8 = 4;
+const SR6_TLV_HMAC: u8 = 5;
+
+// Reserved and HMAC key ID
+const SR6_TLV_HMAC_HEADER_LEN: usize = 6;
+
+/// TLV of [Seg6Header]. Pad1 and PadN are omitted.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum Seg6Tlv {
+    Opaque(Vec<u8>),
+    Hmac(Seg6TlvHmac),
+    Other { kind: u8, payload: Vec<u8> },
+}
+
+/// HMAC TLV(`struct sr6_tlv_hmac`) defined in RFC 8754.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct Seg6TlvHmac {
+    pub key_id: u32,
+    /// Kernel uses HMAC of 32 bytes
+    pub hmac: Vec<u8>,
+}
+
+impl Seg6Tlv {
+    fn kind(&self) -> u8 {
+        match self {
+            Self::Opaque(_) => SR6_TLV_OPAQUE,
+            Self::Hmac(_) => SR6_TLV_HMAC,
+            Self::Other { kind, .. } => *kind,
+        }
+    }
+
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Opaque(v) | Self::Other { payload: v, .. } => v.len(),
+            Self::Hmac(v) => SR6_TLV_HMAC_HEADER_LEN + v.hmac.len(),
+        }
+    }
+}
+
+impl Emitable for Seg6Tlv {
+    fn buffer_len(&self) -> usize {
+        SR6_TLV_HEADER_LEN + self.value_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        buffer[0] = self.kind();
+        buffer[1] = self.value_len() as u8;
+        let buffer = &mut buffer[SR6_TLV_HEADER_LEN..self.buffer_len()];
+        match self {
+            Self::Opaque(v) | Self::Other { payload: v, .. } => {
+                buffer.copy_from_slice(v)
+            }
+            Self::Hmac(v) => {
+                buffer[..2].fill(0);
+                BigEndian::write_u32(&mut buffer[2..6], v.key_id);
+                buffer[SR6_TLV_HMAC_HEADER_LEN..].copy_from_slice(&v.hmac);
+            }
+        }
+    }
+}
+
+fn parse_seg6_tlvs(raw: &[u8]) -> Result<Vec<Seg6Tlv>, DecodeError> {
+    let mut tlvs = Vec::new();
+    let mut raw = raw;
+    while !raw.is_empty() {
+        if raw[0] == SR6_TLV_PAD1 {
+            raw = &raw[1..];
+            continue;
+        }
+        if raw.len() < SR6_TLV_HEADER_LEN {
+            return Err(AxError::InvalidInput);
+        }
+        let len = SR6_TLV_HEADER_LEN + raw[1] as usize;
+        let payload = raw
+            .get(SR6_TLV_HEADER_LEN..len)
+            .ok_or(AxError::InvalidInput)?;
+        match raw[0] {
+            SR6_TLV_PADDING => (),
+            SR6_TLV_OPAQUE => tlvs.push(Seg6Tlv::Opaque(payload.to_vec())),
+            SR6_TLV_HMAC => {
+                if payload.len() < SR6_TLV_HMAC_HEADER_LEN {
+                    return Err(AxError::InvalidInput);
+                }
+                tlvs.push(Seg6Tlv::Hmac(Seg6TlvHmac {
+                    key_id: BigEndian::read_u32(&payload[2..6]),
+                    hmac: payload[SR6_TLV_HMAC_HEADER_LEN..].to_vec(),
+                }))
+            }
+            kind => tlvs.push(Seg6Tlv::Other {
+                kind,
+                payload: payload.to_vec(),
+            }),
+        }
+        raw = &raw[len..];
+    }
+    Ok(tlvs)
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec;
+use core::net::Ipv6Addr;
+
+use netlink_packet_utils::{Emitable, Parseable};
+
+use crate::lwtunnel::{Seg6Header, Seg6HeaderBuffer, Seg6Tlv};
+
+const SR6_TLV_PAD1: u8 = 0;
+const SR6_TLV_PADDING: u8 = 4;
+
+fn header_with_opaque(len: usize) -> Seg6Header {
+    Seg6Header {
+        segments: vec![Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 1)],
+        tlvs: vec![Seg6Tlv::Opaque(vec![0xab; len])],
+        ..Default::default()
+    }
+}
+
+// TLV of 7 bytes leaves a single byte of padding, which is Pad1
+#[test]
+fn test_srh_pad1() {
+    let header = header_with_opaque(5);
+    assert_eq!(header.buffer_len(), 8 + 16 + 7 + 1);
+
+    let mut buf = vec![0u8; header.buffer_len()];
+    header.emit(&mut buf);
+    assert_eq!(buf[1], 3);
+    assert_eq!(buf[buf.len() - 1], SR6_TLV_PAD1);
+    assert_eq!(
+        Seg6Header::parse(&Seg6HeaderBuffer::new(&buf)).unwrap(),
+        header
+    );
+}
+
+// TLVs leaving 2 to 7 bytes of padding use PadN of length 0 to 5
+#[test]
+fn test_srh_padn() {
+    for (opaque_len, padding_len) in
+        [(0, 6), (1, 5), (2, 4), (3, 3), (4, 2), (7, 7), (8, 6)]
+    {
+        let header = header_with_opaque(opaque_len);
+        let tlvs_len = 2 + opaque_len;
+        assert_eq!(header.buffer_len(), 8 + 16 + tlvs_len + padding_len);
+
+        let mut buf = vec![0xffu8; header.buffer_len()];
+        header.emit(&mut buf);
+        let padding = &buf[8 + 16 + tlvs_len..];
+        assert_eq!(padding[0], SR6_TLV_PADDING);
+        assert_eq!(padding[1] as usize, padding_len - 2);
+        assert!(padding[2..].iter().all(|b| *b == 0));
+        assert_eq!(
+            Seg6Header::parse(&Seg6HeaderBuffer::new(&buf)).unwrap(),
+            header
+        );
+    }
+}
+
+#[test]
+fn test_srh_no_padding() {
+    let header = header_with_opaque(6);
+    assert_eq!(header.buffer_len(), 8 + 16 + 8);
+
+    let mut buf = vec![0u8; header.buffer_len()];
+    header.emit(&mut buf);
+    assert_eq!(buf[1], 3);
+    assert_eq!(
+        Seg6Header::parse(&Seg6HeaderBuffer::new(&buf)).unwrap(),
+        header
+    );
+}
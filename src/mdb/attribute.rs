@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::mdb::{
+    entry::MDB_ENTRY_LEN, MdbDbAttribute, MdbEntry, MdbEntryBuffer,
+    MdbRouterAttribute, MdbSetEntryAttribute,
+};
+
+const MDBA_MDB: u16 = 1;
+const MDBA_ROUTER: u16 = 2;
+
+const MDBA_SET_ENTRY: u16 = 1;
+const MDBA_SET_ENTRY_ATTRS: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbAttribute {
+    /// Multicast group entries, used by dumps and notifications.
+    Mdb(Vec<MdbDbAttribute>),
+    /// Multicast router ports, used by dumps and notifications.
+    Router(Vec<MdbRouterAttribute>),
+    /// Entry to add, delete or get, used by requests. Kernel constant
+    /// name is `MDBA_SET_ENTRY` or `MDBA_GET_ENTRY`.
+    SetEntry(MdbEntry),
+    /// Extra properties of [Self::SetEntry]. Kernel constant name is
+    /// `MDBA_SET_ENTRY_ATTRS` or `MDBA_GET_ENTRY_ATTRS`.
+    SetEntryAttributes(Vec<MdbSetEntryAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mdb(attrs) => attrs.as_slice().buffer_len(),
+            Self::Router(attrs) => attrs.as_slice().buffer_len(),
+            Self::SetEntry(entry) => entry.buffer_len(),
+            Self::SetEntryAttributes(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mdb(attrs) => attrs.as_slice().emit(buffer),
+            Self::Router(attrs) => attrs.as_slice().emit(buffer),
+            Self::SetEntry(entry) => entry.emit(buffer),
+            Self::SetEntryAttributes(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mdb(_) => MDBA_MDB,
+            Self::Router(_) => MDBA_ROUTER,
+            Self::SetEntry(_) => MDBA_SET_ENTRY,
+            Self::SetEntryAttributes(_) => MDBA_SET_ENTRY_ATTRS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl MdbAttribute {
+    pub(crate) fn is_set_entry<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+    ) -> bool {
+        buf.kind() == MDBA_SET_ENTRY && buf.value().len() == MDB_ENTRY_LEN
+    }
+
+    pub(crate) fn parse_with_param<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        is_request: bool,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match (buf.kind(), is_request) {
+            (MDBA_SET_ENTRY, true) => Self::SetEntry(MdbEntry::parse(
+                &MdbEntryBuffer::new_checked(payload)?,
+            )?),
+            (MDBA_SET_ENTRY_ATTRS, true) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(MdbSetEntryAttribute::parse(&nla?)?);
+                }
+                Self::SetEntryAttributes(attrs)
+            }
+            (MDBA_MDB, false) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(MdbDbAttribute::parse(&nla?)?);
+                }
+                Self::Mdb(attrs)
+            }
+            (MDBA_ROUTER, false) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(MdbRouterAttribute::parse(&nla?)?);
+                }
+                Self::Router(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,265 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::IpAddr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    ip::{emit_ip_addr, ip_addr_len, parse_ip_addr},
+    mdb::{
+        entry::MDB_ENTRY_LEN, MdbEntry, MdbEntryBuffer, MdbGroupMode,
+        MdbSourceListAttribute,
+    },
+    route::RouteProtocol,
+};
+
+const MDBA_MDB_ENTRY: u16 = 1;
+
+/// Member of `MDBA_MDB`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbDbAttribute {
+    /// All the entries of a single multicast group.
+    Entry(Vec<MdbEntryAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbDbAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => MDBA_MDB_ENTRY,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbDbAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(MdbEntryAttribute::parse(&nla?)?);
+                }
+                Self::Entry(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const MDBA_MDB_ENTRY_INFO: u16 = 1;
+
+/// Member of `MDBA_MDB_ENTRY`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbEntryAttribute {
+    Info(MdbEntryInfo),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Info(info) => info.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Info(info) => info.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Info(_) => MDBA_MDB_ENTRY_INFO,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbEntryAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY_INFO => Self::Info(MdbEntryInfo::parse(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// The `MDBA_MDB_ENTRY_INFO` holds a `struct br_mdb_entry` followed by
+/// `MDBA_MDB_EATTR_*` attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct MdbEntryInfo {
+    pub entry: MdbEntry,
+    pub attributes: Vec<MdbEntryInfoAttribute>,
+}
+
+impl MdbEntryInfo {
+    fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let entry = MdbEntry::parse(&MdbEntryBuffer::new_checked(payload)?)?;
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(&payload[MDB_ENTRY_LEN..]) {
+            attributes.push(MdbEntryInfoAttribute::parse(&nla?)?);
+        }
+        Ok(Self { entry, attributes })
+    }
+}
+
+impl Emitable for MdbEntryInfo {
+    fn buffer_len(&self) -> usize {
+        MDB_ENTRY_LEN + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.entry.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[MDB_ENTRY_LEN..]);
+    }
+}
+
+const MDBA_MDB_EATTR_TIMER: u16 = 1;
+const MDBA_MDB_EATTR_SRC_LIST: u16 = 2;
+const MDBA_MDB_EATTR_GROUP_MODE: u16 = 3;
+const MDBA_MDB_EATTR_SOURCE: u16 = 4;
+const MDBA_MDB_EATTR_RTPROT: u16 = 5;
+const MDBA_MDB_EATTR_DST: u16 = 6;
+const MDBA_MDB_EATTR_DST_PORT: u16 = 7;
+const MDBA_MDB_EATTR_VNI: u16 = 8;
+const MDBA_MDB_EATTR_IFINDEX: u16 = 9;
+const MDBA_MDB_EATTR_SRC_VNI: u16 = 10;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbEntryInfoAttribute {
+    /// Remaining time of the entry in centiseconds.
+    Timer(u32),
+    SourceList(Vec<MdbSourceListAttribute>),
+    GroupMode(MdbGroupMode),
+    /// Source address of (S, G) entry.
+    Source(IpAddr),
+    Protocol(RouteProtocol),
+    /// Remote VTEP address, VXLAN MDB only.
+    Destination(IpAddr),
+    /// Remote VTEP UDP port, VXLAN MDB only.
+    DestinationPort(u16),
+    /// Remote VXLAN network identifier, VXLAN MDB only.
+    Vni(u32),
+    /// Outgoing interface index of the remote VTEP, VXLAN MDB only.
+    Ifindex(u32),
+    /// Source VXLAN network identifier, VXLAN MDB only.
+    SourceVni(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryInfoAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SourceList(attrs) => attrs.as_slice().buffer_len(),
+            Self::GroupMode(_) | Self::Protocol(_) => 1,
+            Self::DestinationPort(_) => 2,
+            Self::Timer(_)
+            | Self::Vni(_)
+            | Self::Ifindex(_)
+            | Self::SourceVni(_) => 4,
+            Self::Source(addr) | Self::Destination(addr) => ip_addr_len(addr),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::SourceList(attrs) => attrs.as_slice().emit(buffer),
+            Self::GroupMode(v) => buffer[0] = (*v).into(),
+            Self::Protocol(v) => buffer[0] = (*v).into(),
+            Self::DestinationPort(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Timer(v)
+            | Self::Vni(v)
+            | Self::Ifindex(v)
+            | Self::SourceVni(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Source(addr) | Self::Destination(addr) => {
+                emit_ip_addr(addr, buffer)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Timer(_) => MDBA_MDB_EATTR_TIMER,
+            Self::SourceList(_) => MDBA_MDB_EATTR_SRC_LIST | NLA_F_NESTED,
+            Self::GroupMode(_) => MDBA_MDB_EATTR_GROUP_MODE,
+            Self::Source(_) => MDBA_MDB_EATTR_SOURCE,
+            Self::Protocol(_) => MDBA_MDB_EATTR_RTPROT,
+            Self::Destination(_) => MDBA_MDB_EATTR_DST,
+            Self::DestinationPort(_) => MDBA_MDB_EATTR_DST_PORT,
+            Self::Vni(_) => MDBA_MDB_EATTR_VNI,
+            Self::Ifindex(_) => MDBA_MDB_EATTR_IFINDEX,
+            Self::SourceVni(_) => MDBA_MDB_EATTR_SRC_VNI,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbEntryInfoAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_EATTR_TIMER => Self::Timer(parse_u32(payload)?),
+            MDBA_MDB_EATTR_SRC_LIST => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(MdbSourceListAttribute::parse(&nla?)?);
+                }
+                Self::SourceList(attrs)
+            }
+            MDBA_MDB_EATTR_GROUP_MODE => {
+                Self::GroupMode(parse_u8(payload)?.into())
+            }
+            MDBA_MDB_EATTR_SOURCE => Self::Source(parse_ip_addr(payload)?),
+            MDBA_MDB_EATTR_RTPROT => Self::Protocol(parse_u8(payload)?.into()),
+            MDBA_MDB_EATTR_DST => Self::Destination(parse_ip_addr(payload)?),
+            MDBA_MDB_EATTR_DST_PORT => {
+                Self::DestinationPort(parse_u16(payload)?)
+            }
+            MDBA_MDB_EATTR_VNI => Self::Vni(parse_u32(payload)?),
+            MDBA_MDB_EATTR_IFINDEX => Self::Ifindex(parse_u32(payload)?),
+            MDBA_MDB_EATTR_SRC_VNI => Self::SourceVni(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
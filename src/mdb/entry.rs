@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use axerrno::AxError;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+
+pub(crate) const MDB_ENTRY_LEN: usize = 28;
+
+const ETH_P_IP: u16 = 0x0800;
+const ETH_P_IPV6: u16 = 0x86DD;
+const ETH_ALEN: usize = 6;
+
+buffer!(MdbEntryBuffer(MDB_ENTRY_LEN) {
+    ifindex: (u32, 0..4),
+    state: (u8, 4),
+    flags: (u8, 5),
+    vid: (u16, 6..8),
+    address: (slice, 8..24),
+    // Stored in network byte order
+    protocol: (u16, 24..26),
+    pad: (u16, 26..MDB_ENTRY_LEN),
+});
+
+/// Multicast group address of [MdbEntry].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum MdbGroupAddress {
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    /// Layer 2 multicast MAC address
+    L2([u8; ETH_ALEN]),
+    Other {
+        protocol: u16,
+        address: [u8; 16],
+    },
+}
+
+impl Default for MdbGroupAddress {
+    fn default() -> Self {
+        Self::Ipv4(Ipv4Addr::UNSPECIFIED)
+    }
+}
+
+impl MdbGroupAddress {
+    fn protocol(&self) -> u16 {
+        match self {
+            Self::Ipv4(_) => ETH_P_IP,
+            Self::Ipv6(_) => ETH_P_IPV6,
+            Self::L2(_) => 0,
+            Self::Other { protocol, .. } => *protocol,
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ipv4(ip) => buffer[..4].copy_from_slice(&ip.octets()),
+            Self::Ipv6(ip) => buffer.copy_from_slice(&ip.octets()),
+            Self::L2(mac) => buffer[..ETH_ALEN].copy_from_slice(mac),
+            Self::Other { address, .. } => buffer.copy_from_slice(address),
+        }
+    }
+}
+
+const MDB_TEMPORARY: u8 = 0;
+const MDB_PERMANENT: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum MdbEntryState {
+    #[default]
+    Temporary,
+    Permanent,
+    Other(u8),
+}
+
+impl From<u8> for MdbEntryState {
+    fn from(d: u8) -> Self {
+        match d {
+            MDB_TEMPORARY => Self::Temporary,
+            MDB_PERMANENT => Self::Permanent,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbEntryState> for u8 {
+    fn from(v: MdbEntryState) -> u8 {
+        match v {
+            MdbEntryState::Temporary => MDB_TEMPORARY,
+            MdbEntryState::Permanent => MDB_PERMANENT,
+            MdbEntryState::Other(d) => d,
+        }
+    }
+}
+
+const MDB_FLAGS_OFFLOAD: u8 = 1 << 0;
+const MDB_FLAGS_FAST_LEAVE: u8 = 1 << 1;
+const MDB_FLAGS_STAR_EXCL: u8 = 1 << 2;
+const MDB_FLAGS_BLOCKED: u8 = 1 << 3;
+const MDB_FLAGS_OFFLOAD_FAILED: u8 = 1 << 4;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct MdbEntryFlags: u8 {
+        const Offload = MDB_FLAGS_OFFLOAD;
+        const FastLeave = MDB_FLAGS_FAST_LEAVE;
+        const StarExcl = MDB_FLAGS_STAR_EXCL;
+        const Blocked = MDB_FLAGS_BLOCKED;
+        const OffloadFailed = MDB_FLAGS_OFFLOAD_FAILED;
+        const _ = !0;
+    }
+}
+
+impl Default for MdbEntryFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// The `struct br_mdb_entry` used by `MDBA_MDB_ENTRY_INFO`,
+/// `MDBA_SET_ENTRY` and `MDBA_GET_ENTRY`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct MdbEntry {
+    /// Interface index of the bridge port, or of the bridge itself for
+    /// host joined groups.
+    pub ifindex: u32,
+    pub state: MdbEntryState,
+    pub flags: MdbEntryFlags,
+    /// VLAN ID, 0 if not VLAN aware.
+    pub vid: u16,
+    pub group: MdbGroupAddress,
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryBuffer<&T>> for MdbEntry {
+    fn parse(buf: &MdbEntryBuffer<&T>) -> Result<Self, DecodeError> {
+        let address = buf.address();
+        let group = match u16::from_be(buf.protocol()) {
+            ETH_P_IP => MdbGroupAddress::Ipv4(parse_ipv4_addr(&address[..4])?),
+            ETH_P_IPV6 => MdbGroupAddress::Ipv6(parse_ipv6_addr(address)?),
+            0 => {
+                let mut mac = [0u8; ETH_ALEN];
+                mac.copy_from_slice(&address[..ETH_ALEN]);
+                MdbGroupAddress::L2(mac)
+            }
+            protocol => MdbGroupAddress::Other {
+                protocol,
+                address: address
+                    .try_into()
+                    .map_err(|_| AxError::InvalidInput)?,
+            },
+        };
+        Ok(Self {
+            ifindex: buf.ifindex(),
+            state: buf.state().into(),
+            flags: MdbEntryFlags::from_bits_retain(buf.flags()),
+            vid: buf.vid(),
+            group,
+        })
+    }
+}
+
+impl Emitable for MdbEntry {
+    fn buffer_len(&self) -> usize {
+        MDB_ENTRY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MdbEntryBuffer::new(buffer);
+        buffer.set_ifindex(self.ifindex);
+        buffer.set_state(self.state.into());
+        buffer.set_flags(self.flags.bits());
+        buffer.set_vid(self.vid);
+        buffer.address_mut().fill(0);
+        self.group.emit(buffer.address_mut());
+        buffer.set_protocol(self.group.protocol().to_be());
+        buffer.set_pad(0);
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const MDB_HEADER_LEN: usize = 8;
+
+buffer!(MdbMessageBuffer(MDB_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    ifindex: (u32, 4..MDB_HEADER_LEN),
+    payload: (slice, MDB_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> MdbMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_GETMDB`, `RTM_NEWMDB` and
+/// `RTM_DELMDB` messages headers(`struct br_port_msg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct MdbHeader {
+    /// Address family, normally `AF_BRIDGE`.
+    pub family: AddressFamily,
+    /// Interface index of the bridge.
+    pub ifindex: u32,
+}
+
+impl Emitable for MdbHeader {
+    fn buffer_len(&self) -> usize {
+        MDB_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = MdbMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<MdbMessageBuffer<T>> for MdbHeader {
+    fn parse(buf: &MdbMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(MdbHeader {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
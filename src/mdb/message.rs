@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::mdb::{MdbAttribute, MdbHeader, MdbMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct MdbMessage {
+    pub header: MdbHeader,
+    pub attributes: Vec<MdbAttribute>,
+}
+
+impl Emitable for MdbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>>
+    for MdbMessage
+{
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = MdbHeader::parse(buf)?;
+        // The kernel reuses the attribute numbers: `MDBA_MDB` and
+        // `MDBA_ROUTER` in dumps and notifications, `MDBA_SET_ENTRY` and
+        // `MDBA_SET_ENTRY_ATTRS` in requests. Requests are identified by
+        // the fixed size `struct br_mdb_entry` payload.
+        let mut is_request = false;
+        for nla_buf in buf.attributes() {
+            if MdbAttribute::is_set_entry(&nla_buf?) {
+                is_request = true;
+                break;
+            }
+        }
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes
+                .push(MdbAttribute::parse_with_param(&nla_buf?, is_request)?);
+        }
+        Ok(MdbMessage { header, attributes })
+    }
+}
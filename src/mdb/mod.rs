@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod db;
+mod entry;
+mod header;
+mod message;
+mod router;
+mod set_entry;
+mod source;
+
+pub use self::attribute::MdbAttribute;
+pub use self::db::{
+    MdbDbAttribute, MdbEntryAttribute, MdbEntryInfo, MdbEntryInfoAttribute,
+};
+pub use self::entry::{
+    MdbEntry, MdbEntryBuffer, MdbEntryFlags, MdbEntryState, MdbGroupAddress,
+};
+pub use self::header::{MdbHeader, MdbMessageBuffer};
+pub use self::message::MdbMessage;
+pub use self::router::{
+    MdbRouterAttribute, MdbRouterPort, MdbRouterPortAttribute, MdbRouterType,
+};
+pub use self::set_entry::MdbSetEntryAttribute;
+pub use self::source::{
+    MdbGroupMode, MdbSourceAttribute, MdbSourceListAttribute,
+};
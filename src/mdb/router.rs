@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use axerrno::AxError;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const MDBA_ROUTER_PORT: u16 = 1;
+
+/// Member of `MDBA_ROUTER`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbRouterAttribute {
+    Port(MdbRouterPort),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbRouterAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Port(port) => port.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Port(port) => port.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Port(_) => MDBA_ROUTER_PORT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbRouterAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PORT => Self::Port(MdbRouterPort::parse(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// The `MDBA_ROUTER_PORT` holds the interface index of the multicast
+/// router port followed by `MDBA_ROUTER_PATTR_*` attributes. Old kernels
+/// only provide the interface index.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct MdbRouterPort {
+    pub ifindex: u32,
+    pub attributes: Vec<MdbRouterPortAttribute>,
+}
+
+impl MdbRouterPort {
+    fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < 4 {
+            return Err(AxError::InvalidInput);
+        }
+        let ifindex = parse_u32(&payload[..4])?;
+        let mut attributes = Vec::new();
+        for nla in NlasIterator::new(&payload[4..]) {
+            attributes.push(MdbRouterPortAttribute::parse(&nla?)?);
+        }
+        Ok(Self {
+            ifindex,
+            attributes,
+        })
+    }
+}
+
+impl Emitable for MdbRouterPort {
+    fn buffer_len(&self) -> usize {
+        4 + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(buffer, self.ifindex);
+        self.attributes.as_slice().emit(&mut buffer[4..]);
+    }
+}
+
+const MDBA_ROUTER_PATTR_TIMER: u16 = 1;
+const MDBA_ROUTER_PATTR_TYPE: u16 = 2;
+const MDBA_ROUTER_PATTR_INET_TIMER: u16 = 3;
+const MDBA_ROUTER_PATTR_INET6_TIMER: u16 = 4;
+const MDBA_ROUTER_PATTR_VID: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbRouterPortAttribute {
+    /// Remaining time in centiseconds
+    Timer(u32),
+    Type(MdbRouterType),
+    /// Remaining time of IPv4 multicast router in centiseconds
+    InetTimer(u32),
+    /// Remaining time of IPv6 multicast router in centiseconds
+    Inet6Timer(u32),
+    Vid(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbRouterPortAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Type(_) => 1,
+            Self::Vid(_) => 2,
+            Self::Timer(_) | Self::InetTimer(_) | Self::Inet6Timer(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Type(v) => buffer[0] = (*v).into(),
+            Self::Vid(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Timer(v) | Self::InetTimer(v) | Self::Inet6Timer(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Timer(_) => MDBA_ROUTER_PATTR_TIMER,
+            Self::Type(_) => MDBA_ROUTER_PATTR_TYPE,
+            Self::InetTimer(_) => MDBA_ROUTER_PATTR_INET_TIMER,
+            Self::Inet6Timer(_) => MDBA_ROUTER_PATTR_INET6_TIMER,
+            Self::Vid(_) => MDBA_ROUTER_PATTR_VID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbRouterPortAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PATTR_TIMER => Self::Timer(parse_u32(payload)?),
+            MDBA_ROUTER_PATTR_TYPE => Self::Type(parse_u8(payload)?.into()),
+            MDBA_ROUTER_PATTR_INET_TIMER => {
+                Self::InetTimer(parse_u32(payload)?)
+            }
+            MDBA_ROUTER_PATTR_INET6_TIMER => {
+                Self::Inet6Timer(parse_u32(payload)?)
+            }
+            MDBA_ROUTER_PATTR_VID => Self::Vid(parse_u16(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const MDB_RTR_TYPE_DISABLED: u8 = 0;
+const MDB_RTR_TYPE_TEMP_QUERY: u8 = 1;
+const MDB_RTR_TYPE_PERM: u8 = 2;
+const MDB_RTR_TYPE_TEMP: u8 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum MdbRouterType {
+    #[default]
+    Disabled,
+    TempQuery,
+    Perm,
+    Temp,
+    Other(u8),
+}
+
+impl From<u8> for MdbRouterType {
+    fn from(d: u8) -> Self {
+        match d {
+            MDB_RTR_TYPE_DISABLED => Self::Disabled,
+            MDB_RTR_TYPE_TEMP_QUERY => Self::TempQuery,
+            MDB_RTR_TYPE_PERM => Self::Perm,
+            MDB_RTR_TYPE_TEMP => Self::Temp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbRouterType> for u8 {
+    fn from(v: MdbRouterType) -> u8 {
+        match v {
+            MdbRouterType::Disabled => MDB_RTR_TYPE_DISABLED,
+            MdbRouterType::TempQuery => MDB_RTR_TYPE_TEMP_QUERY,
+            MdbRouterType::Perm => MDB_RTR_TYPE_PERM,
+            MdbRouterType::Temp => MDB_RTR_TYPE_TEMP,
+            MdbRouterType::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::IpAddr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    ip::{emit_ip_addr, ip_addr_len, parse_ip_addr},
+    mdb::{MdbGroupMode, MdbSourceListAttribute},
+    route::RouteProtocol,
+};
+
+const MDBE_ATTR_SOURCE: u16 = 1;
+const MDBE_ATTR_SRC_LIST: u16 = 2;
+const MDBE_ATTR_GROUP_MODE: u16 = 3;
+const MDBE_ATTR_RTPROT: u16 = 4;
+const MDBE_ATTR_DST: u16 = 5;
+const MDBE_ATTR_DST_PORT: u16 = 6;
+const MDBE_ATTR_VNI: u16 = 7;
+const MDBE_ATTR_IFINDEX: u16 = 8;
+const MDBE_ATTR_SRC_VNI: u16 = 9;
+const MDBE_ATTR_STATE_MASK: u16 = 10;
+
+/// Member of `MDBA_SET_ENTRY_ATTRS` and `MDBA_GET_ENTRY_ATTRS`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbSetEntryAttribute {
+    /// Source address of (S, G) entry.
+    Source(IpAddr),
+    SourceList(Vec<MdbSourceListAttribute>),
+    GroupMode(MdbGroupMode),
+    Protocol(RouteProtocol),
+    /// Remote VTEP address, VXLAN MDB only.
+    Destination(IpAddr),
+    /// Remote VTEP UDP port, VXLAN MDB only.
+    DestinationPort(u16),
+    /// Remote VXLAN network identifier, VXLAN MDB only.
+    Vni(u32),
+    /// Outgoing interface index of the remote VTEP, VXLAN MDB only.
+    Ifindex(u32),
+    /// Source VXLAN network identifier, VXLAN MDB only.
+    SourceVni(u32),
+    /// Used by bulk deletion to match [crate::mdb::MdbEntryState].
+    StateMask(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSetEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::SourceList(attrs) => attrs.as_slice().buffer_len(),
+            Self::GroupMode(_) | Self::Protocol(_) | Self::StateMask(_) => 1,
+            Self::DestinationPort(_) => 2,
+            Self::Vni(_) | Self::Ifindex(_) | Self::SourceVni(_) => 4,
+            Self::Source(addr) | Self::Destination(addr) => ip_addr_len(addr),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::SourceList(attrs) => attrs.as_slice().emit(buffer),
+            Self::GroupMode(v) => buffer[0] = (*v).into(),
+            Self::Protocol(v) => buffer[0] = (*v).into(),
+            Self::StateMask(v) => buffer[0] = *v,
+            Self::DestinationPort(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Vni(v) | Self::Ifindex(v) | Self::SourceVni(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Source(addr) | Self::Destination(addr) => {
+                emit_ip_addr(addr, buffer)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Source(_) => MDBE_ATTR_SOURCE,
+            Self::SourceList(_) => MDBE_ATTR_SRC_LIST | NLA_F_NESTED,
+            Self::GroupMode(_) => MDBE_ATTR_GROUP_MODE,
+            Self::Protocol(_) => MDBE_ATTR_RTPROT,
+            Self::Destination(_) => MDBE_ATTR_DST,
+            Self::DestinationPort(_) => MDBE_ATTR_DST_PORT,
+            Self::Vni(_) => MDBE_ATTR_VNI,
+            Self::Ifindex(_) => MDBE_ATTR_IFINDEX,
+            Self::SourceVni(_) => MDBE_ATTR_SRC_VNI,
+            Self::StateMask(_) => MDBE_ATTR_STATE_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSetEntryAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBE_ATTR_SOURCE => Self::Source(parse_ip_addr(payload)?),
+            MDBE_ATTR_SRC_LIST => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(MdbSourceListAttribute::parse(&nla?)?);
+                }
+                Self::SourceList(attrs)
+            }
+            MDBE_ATTR_GROUP_MODE => Self::GroupMode(parse_u8(payload)?.into()),
+            MDBE_ATTR_RTPROT => Self::Protocol(parse_u8(payload)?.into()),
+            MDBE_ATTR_DST => Self::Destination(parse_ip_addr(payload)?),
+            MDBE_ATTR_DST_PORT => Self::DestinationPort(parse_u16(payload)?),
+            MDBE_ATTR_VNI => Self::Vni(parse_u32(payload)?),
+            MDBE_ATTR_IFINDEX => Self::Ifindex(parse_u32(payload)?),
+            MDBE_ATTR_SRC_VNI => Self::SourceVni(parse_u32(payload)?),
+            MDBE_ATTR_STATE_MASK => Self::StateMask(parse_u8(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::IpAddr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{emit_ip_addr, ip_addr_len, parse_ip_addr};
+
+// MDBA_MDB_SRCLIST_ENTRY and MDBE_SRC_LIST_ENTRY
+const SRC_LIST_ENTRY: u16 = 1;
+
+// MDBA_MDB_SRCATTR_ADDRESS and MDBE_SRCATTR_ADDRESS
+const SRCATTR_ADDRESS: u16 = 1;
+const MDBA_MDB_SRCATTR_TIMER: u16 = 2;
+
+/// Member of `MDBA_MDB_EATTR_SRC_LIST` and `MDBE_ATTR_SRC_LIST`, both
+/// share the same layout.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbSourceListAttribute {
+    Entry(Vec<MdbSourceAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSourceListAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Entry(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Entry(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Entry(_) => SRC_LIST_ENTRY | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSourceListAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SRC_LIST_ENTRY => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(MdbSourceAttribute::parse(&nla?)?);
+                }
+                Self::Entry(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum MdbSourceAttribute {
+    Address(IpAddr),
+    /// Remaining time of the source entry in centiseconds, not used in
+    /// requests.
+    Timer(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSourceAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Address(addr) => ip_addr_len(addr),
+            Self::Timer(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Address(addr) => emit_ip_addr(addr, buffer),
+            Self::Timer(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Address(_) => SRCATTR_ADDRESS,
+            Self::Timer(_) => MDBA_MDB_SRCATTR_TIMER,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for MdbSourceAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SRCATTR_ADDRESS => Self::Address(parse_ip_addr(payload)?),
+            MDBA_MDB_SRCATTR_TIMER => Self::Timer(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const MCAST_EXCLUDE: u8 = 0;
+const MCAST_INCLUDE: u8 = 1;
+
+/// IGMPv3/MLDv2 source filter mode of the group.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum MdbGroupMode {
+    #[default]
+    Exclude,
+    Include,
+    Other(u8),
+}
+
+impl From<u8> for MdbGroupMode {
+    fn from(d: u8) -> Self {
+        match d {
+            MCAST_EXCLUDE => Self::Exclude,
+            MCAST_INCLUDE => Self::Include,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<MdbGroupMode> for u8 {
+    fn from(v: MdbGroupMode) -> u8 {
+        match v {
+            MdbGroupMode::Exclude => MCAST_EXCLUDE,
+            MdbGroupMode::Include => MCAST_INCLUDE,
+            MdbGroupMode::Other(d) => d,
+        }
+    }
+}
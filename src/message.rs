@@ -12,7 +12,26 @@ use netlink_packet_core::{
 
 use crate::{
     address::{AddressHeader, AddressMessage, AddressMessageBuffer},
+    address_label::{AddressLabelMessage, AddressLabelMessageBuffer},
+    bridge_vlan::{BridgeVlanMessage, BridgeVlanMessageBuffer},
+    cache_report::{CacheReportMessage, CacheReportMessageBuffer},
+    dcb::{DcbMessage, DcbMessageBuffer},
     link::{LinkMessage, LinkMessageBuffer},
+    mdb::{MdbMessage, MdbMessageBuffer},
+    nd_user_option::{NdUserOptionMessage, NdUserOptionMessageBuffer},
+    neighbour::{NeighbourMessage, NeighbourMessageBuffer},
+    neighbour_table::{NeighbourTableMessage, NeighbourTableMessageBuffer},
+    netconf::{NetconfMessage, NetconfMessageBuffer},
+    nexthop::{NexthopMessage, NexthopMessageBuffer},
+    nsid::{NsidMessage, NsidMessageBuffer},
+    prefix::{PrefixMessage, PrefixMessageBuffer},
+    route::{RouteMessage, RouteMessageBuffer},
+    rule::{RuleMessage, RuleMessageBuffer},
+    stats::{StatsMessage, StatsMessageBuffer},
+    tc::{
+        TcActionMessage, TcActionMessageBuffer, TcMessage, TcMessageBuffer,
+    },
+    tunnel::{TunnelMessage, TunnelMessageBuffer},
 };
 
 const RTM_NEWLINK: u16 = 16;
@@ -40,38 +59,55 @@ const RTM_GETTCLASS: u16 = 42;
 const RTM_NEWTFILTER: u16 = 44;
 const RTM_DELTFILTER: u16 = 45;
 const RTM_GETTFILTER: u16 = 46;
-// const RTM_NEWACTION: u16 = 48;
-// const RTM_DELACTION: u16 = 49;
-// const RTM_GETACTION: u16 = 50;
+const RTM_NEWACTION: u16 = 48;
+const RTM_DELACTION: u16 = 49;
+const RTM_GETACTION: u16 = 50;
 const RTM_NEWPREFIX: u16 = 52;
-// const RTM_GETMULTICAST: u16 = 58;
-// const RTM_GETANYCAST: u16 = 62;
+const RTM_NEWMULTICAST: u16 = 56;
+const RTM_DELMULTICAST: u16 = 57;
+const RTM_GETMULTICAST: u16 = 58;
+const RTM_NEWANYCAST: u16 = 60;
+const RTM_DELANYCAST: u16 = 61;
+const RTM_GETANYCAST: u16 = 62;
 const RTM_NEWNEIGHTBL: u16 = 64;
 const RTM_GETNEIGHTBL: u16 = 66;
 const RTM_SETNEIGHTBL: u16 = 67;
-// const RTM_NEWNDUSEROPT: u16 = 68;
-// const RTM_NEWADDRLABEL: u16 = 72;
-// const RTM_DELADDRLABEL: u16 = 73;
-// const RTM_GETADDRLABEL: u16 = 74;
-// const RTM_GETDCB: u16 = 78;
-// const RTM_SETDCB: u16 = 79;
-// const RTM_NEWNETCONF: u16 = 80;
-// const RTM_DELNETCONF: u16 = 81;
-// const RTM_GETNETCONF: u16 = 82;
-// const RTM_NEWMDB: u16 = 84;
-// const RTM_DELMDB: u16 = 85;
-// const RTM_GETMDB: u16 = 86;
+const RTM_NEWNDUSEROPT: u16 = 68;
+const RTM_NEWADDRLABEL: u16 = 72;
+const RTM_DELADDRLABEL: u16 = 73;
+const RTM_GETADDRLABEL: u16 = 74;
+const RTM_GETDCB: u16 = 78;
+const RTM_SETDCB: u16 = 79;
+const RTM_NEWNETCONF: u16 = 80;
+const RTM_DELNETCONF: u16 = 81;
+const RTM_GETNETCONF: u16 = 82;
+const RTM_NEWMDB: u16 = 84;
+const RTM_DELMDB: u16 = 85;
+const RTM_GETMDB: u16 = 86;
 const RTM_NEWNSID: u16 = 88;
 const RTM_DELNSID: u16 = 89;
 const RTM_GETNSID: u16 = 90;
-// const RTM_NEWSTATS: u16 = 92;
-// const RTM_GETSTATS: u16 = 94;
-// const RTM_NEWCACHEREPORT: u16 = 96;
+const RTM_NEWSTATS: u16 = 92;
+const RTM_GETSTATS: u16 = 94;
+const RTM_SETSTATS: u16 = 95;
+const RTM_NEWCACHEREPORT: u16 = 96;
 const RTM_NEWCHAIN: u16 = 100;
 const RTM_DELCHAIN: u16 = 101;
 const RTM_GETCHAIN: u16 = 102;
+const RTM_NEWNEXTHOP: u16 = 104;
+const RTM_DELNEXTHOP: u16 = 105;
+const RTM_GETNEXTHOP: u16 = 106;
 const RTM_NEWLINKPROP: u16 = 108;
 const RTM_DELLINKPROP: u16 = 109;
+const RTM_NEWVLAN: u16 = 112;
+const RTM_DELVLAN: u16 = 113;
+const RTM_GETVLAN: u16 = 114;
+const RTM_NEWNEXTHOPBUCKET: u16 = 116;
+const RTM_DELNEXTHOPBUCKET: u16 = 117;
+const RTM_GETNEXTHOPBUCKET: u16 = 118;
+const RTM_NEWTUNNEL: u16 = 120;
+const RTM_DELTUNNEL: u16 = 121;
+const RTM_GETTUNNEL: u16 = 122;
 
 buffer!(RouteNetlinkMessageBuffer);
 
@@ -111,9 +147,24 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                     _ => unreachable!(),
                 }
             }
+            // Link property messages
+            RTM_NEWLINKPROP | RTM_DELLINKPROP => {
+                let buf_inner = buf.inner();
+                let msg = LinkMessage::parse_link_prop(
+                    &LinkMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                match message_type {
+                    RTM_NEWLINKPROP => RouteNetlinkMessage::NewLinkProp(msg),
+                    RTM_DELLINKPROP => RouteNetlinkMessage::DelLinkProp(msg),
+                    _ => unreachable!(),
+                }
+            }
 
-            // Address messages
-            RTM_NEWADDR | RTM_GETADDR | RTM_DELADDR => {
+            // Address messages, multicast and anycast addresses are IPv6
+            // only and dumped separately from unicast addresses.
+            RTM_NEWADDR | RTM_GETADDR | RTM_DELADDR | RTM_NEWMULTICAST
+            | RTM_DELMULTICAST | RTM_GETMULTICAST | RTM_NEWANYCAST
+            | RTM_DELANYCAST | RTM_GETANYCAST => {
                 let msg = match AddressMessageBuffer::new_checked(&buf.inner())
                 {
                     Ok(buf) => AddressMessage::parse(&buf)
@@ -122,7 +173,10 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                     // the header is limited to the
                     // interface family (1 byte) and 3 bytes of padding.
                     Err(e) => {
-                        if buf.inner().len() == 4 && message_type == RTM_GETADDR
+                        if buf.inner().len() == 4
+                            && (message_type == RTM_GETADDR
+                                || message_type == RTM_GETMULTICAST
+                                || message_type == RTM_GETANYCAST)
                         {
                             let mut msg = AddressMessage {
                                 header: AddressHeader::default(),
@@ -139,9 +193,363 @@ impl<'a, T: AsRef<[u8]> + ?Sized>
                     RTM_NEWADDR => RouteNetlinkMessage::NewAddress(msg),
                     RTM_GETADDR => RouteNetlinkMessage::GetAddress(msg),
                     RTM_DELADDR => RouteNetlinkMessage::DelAddress(msg),
+                    RTM_NEWMULTICAST => {
+                        RouteNetlinkMessage::NewMulticastAddress(msg)
+                    }
+                    RTM_DELMULTICAST => {
+                        RouteNetlinkMessage::DelMulticastAddress(msg)
+                    }
+                    RTM_GETMULTICAST => {
+                        RouteNetlinkMessage::GetMulticastAddress(msg)
+                    }
+                    RTM_NEWANYCAST => {
+                        RouteNetlinkMessage::NewAnycastAddress(msg)
+                    }
+                    RTM_DELANYCAST => {
+                        RouteNetlinkMessage::DelAnycastAddress(msg)
+                    }
+                    RTM_GETANYCAST => {
+                        RouteNetlinkMessage::GetAnycastAddress(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // Route messages
+            RTM_NEWROUTE | RTM_GETROUTE | RTM_DELROUTE => {
+                let msg = match RouteMessageBuffer::new_checked(&buf.inner()) {
+                    Ok(buf) => RouteMessage::parse(&buf)?,
+                    // HACK: iproute2 sends invalid RTM_GETROUTE message, where
+                    // the header is limited to the interface family
+                    // (1 byte) and 3 bytes of padding.
+                    Err(e) => {
+                        // Not only does iproute2 sends invalid messages, it's
+                        // also inconsistent in doing so: for link and address
+                        // messages, the length advertised in the
+                        // netlink header includes the 3 bytes of
+                        // padding but it does not seem to be the case
+                        // for the route message, hence the buf.length() == 1
+                        // check.
+                        if (buf.inner().len() == 4 || buf.inner().len() == 1)
+                            && message_type == RTM_GETROUTE
+                        {
+                            let mut msg = RouteMessage::default();
+                            msg.header.address_family = buf.inner()[0].into();
+                            msg
+                        } else {
+                            return Err(e);
+                        }
+                    }
+                };
+                match message_type {
+                    RTM_NEWROUTE => RouteNetlinkMessage::NewRoute(msg),
+                    RTM_GETROUTE => RouteNetlinkMessage::GetRoute(msg),
+                    RTM_DELROUTE => RouteNetlinkMessage::DelRoute(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Neighbour messages
+            RTM_NEWNEIGH | RTM_GETNEIGH | RTM_DELNEIGH => {
+                let buf_inner = buf.inner();
+                let msg = NeighbourMessage::parse(
+                    &NeighbourMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                match message_type {
+                    RTM_GETNEIGH => RouteNetlinkMessage::GetNeighbour(msg),
+                    RTM_NEWNEIGH => RouteNetlinkMessage::NewNeighbour(msg),
+                    RTM_DELNEIGH => RouteNetlinkMessage::DelNeighbour(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Neighbour table messages
+            RTM_NEWNEIGHTBL | RTM_GETNEIGHTBL | RTM_SETNEIGHTBL => {
+                let buf_inner = buf.inner();
+                let msg = NeighbourTableMessage::parse(
+                    &NeighbourTableMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                match message_type {
+                    RTM_GETNEIGHTBL => {
+                        RouteNetlinkMessage::GetNeighbourTable(msg)
+                    }
+                    RTM_NEWNEIGHTBL => {
+                        RouteNetlinkMessage::NewNeighbourTable(msg)
+                    }
+                    RTM_SETNEIGHTBL => {
+                        RouteNetlinkMessage::SetNeighbourTable(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // Rule messages
+            RTM_NEWRULE | RTM_GETRULE | RTM_DELRULE => {
+                let buf_inner = buf.inner();
+                let msg = RuleMessage::parse(&RuleMessageBuffer::new_checked(
+                    &buf_inner,
+                )?)?;
+                match message_type {
+                    RTM_NEWRULE => RouteNetlinkMessage::NewRule(msg),
+                    RTM_DELRULE => RouteNetlinkMessage::DelRule(msg),
+                    RTM_GETRULE => RouteNetlinkMessage::GetRule(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Queue discipline messages
+            RTM_NEWQDISC | RTM_DELQDISC | RTM_GETQDISC => {
+                let buf_inner = buf.inner();
+                let msg = TcMessage::parse(&TcMessageBuffer::new_checked(
+                    &buf_inner,
+                )?)?;
+                match message_type {
+                    RTM_NEWQDISC => {
+                        RouteNetlinkMessage::NewQueueDiscipline(msg)
+                    }
+                    RTM_DELQDISC => {
+                        RouteNetlinkMessage::DelQueueDiscipline(msg)
+                    }
+                    RTM_GETQDISC => {
+                        RouteNetlinkMessage::GetQueueDiscipline(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // Traffic class messages
+            RTM_NEWTCLASS | RTM_DELTCLASS | RTM_GETTCLASS => {
+                let buf_inner = buf.inner();
+                let msg = TcMessage::parse_with_param(
+                    &TcMessageBuffer::new_checked(&buf_inner)?,
+                    message_type,
+                )?;
+                match message_type {
+                    RTM_NEWTCLASS => RouteNetlinkMessage::NewTrafficClass(msg),
+                    RTM_DELTCLASS => RouteNetlinkMessage::DelTrafficClass(msg),
+                    RTM_GETTCLASS => RouteNetlinkMessage::GetTrafficClass(msg),
                     _ => unreachable!(),
                 }
             }
+
+            // Traffic filter messages
+            RTM_NEWTFILTER | RTM_DELTFILTER | RTM_GETTFILTER => {
+                let buf_inner = buf.inner();
+                let msg = TcMessage::parse(&TcMessageBuffer::new_checked(
+                    &buf_inner,
+                )?)?;
+                match message_type {
+                    RTM_NEWTFILTER => {
+                        RouteNetlinkMessage::NewTrafficFilter(msg)
+                    }
+                    RTM_DELTFILTER => {
+                        RouteNetlinkMessage::DelTrafficFilter(msg)
+                    }
+                    RTM_GETTFILTER => {
+                        RouteNetlinkMessage::GetTrafficFilter(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+
+            // Traffic chain messages
+            RTM_NEWCHAIN | RTM_DELCHAIN | RTM_GETCHAIN => {
+                let buf_inner = buf.inner();
+                let msg = TcMessage::parse(&TcMessageBuffer::new_checked(
+                    &buf_inner,
+                )?)?;
+                match message_type {
+                    RTM_NEWCHAIN => RouteNetlinkMessage::NewTrafficChain(msg),
+                    RTM_DELCHAIN => RouteNetlinkMessage::DelTrafficChain(msg),
+                    RTM_GETCHAIN => RouteNetlinkMessage::GetTrafficChain(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Traffic action messages
+            RTM_NEWACTION | RTM_DELACTION | RTM_GETACTION => {
+                let buf_inner = buf.inner();
+                let msg = TcActionMessage::parse(
+                    &TcActionMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                match message_type {
+                    RTM_NEWACTION => RouteNetlinkMessage::NewTrafficAction(msg),
+                    RTM_DELACTION => RouteNetlinkMessage::DelTrafficAction(msg),
+                    RTM_GETACTION => RouteNetlinkMessage::GetTrafficAction(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Network namespace ID messages
+            RTM_NEWNSID | RTM_DELNSID | RTM_GETNSID => {
+                let buf_inner = buf.inner();
+                let msg = NsidMessage::parse(&NsidMessageBuffer::new_checked(
+                    &buf_inner,
+                )?)?;
+                match message_type {
+                    RTM_NEWNSID => RouteNetlinkMessage::NewNsId(msg),
+                    RTM_DELNSID => RouteNetlinkMessage::DelNsId(msg),
+                    RTM_GETNSID => RouteNetlinkMessage::GetNsId(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // IPv6 prefix messages
+            RTM_NEWPREFIX => {
+                let buf_inner = buf.inner();
+                RouteNetlinkMessage::NewPrefix(PrefixMessage::parse(
+                    &PrefixMessageBuffer::new_checked(&buf_inner)?,
+                )?)
+            }
+
+            // Per-interface IP configuration messages
+            RTM_NEWNETCONF | RTM_DELNETCONF | RTM_GETNETCONF => {
+                let buf_inner = buf.inner();
+                let msg = match NetconfMessageBuffer::new_checked(&buf_inner) {
+                    Ok(buf) => NetconfMessage::parse(&buf)?,
+                    // HACK: iproute2 sends RTM_GETNETCONF dump request
+                    // without padding the 1 byte `struct netconfmsg`.
+                    Err(e) => {
+                        if buf_inner.len() == 1
+                            && message_type == RTM_GETNETCONF
+                        {
+                            let mut msg = NetconfMessage::default();
+                            msg.header.family = buf_inner[0].into();
+                            msg
+                        } else {
+                            return Err(e);
+                        }
+                    }
+                };
+                match message_type {
+                    RTM_NEWNETCONF => RouteNetlinkMessage::NewNetconf(msg),
+                    RTM_DELNETCONF => RouteNetlinkMessage::DelNetconf(msg),
+                    RTM_GETNETCONF => RouteNetlinkMessage::GetNetconf(msg),
+                    _ => unreachable!(),
+                }
+            }
+            // Bridge multicast database messages
+            RTM_NEWMDB | RTM_DELMDB | RTM_GETMDB => {
+                let msg = MdbMessage::parse(&MdbMessageBuffer::new_checked(
+                    &buf.inner(),
+                )?)?;
+                match message_type {
+                    RTM_NEWMDB => RouteNetlinkMessage::NewMdb(msg),
+                    RTM_DELMDB => RouteNetlinkMessage::DelMdb(msg),
+                    RTM_GETMDB => RouteNetlinkMessage::GetMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+            // IPv6 address label messages
+            RTM_NEWADDRLABEL | RTM_DELADDRLABEL | RTM_GETADDRLABEL => {
+                let buf_inner = buf.inner();
+                let msg = AddressLabelMessage::parse(
+                    &AddressLabelMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                match message_type {
+                    RTM_NEWADDRLABEL => {
+                        RouteNetlinkMessage::NewAddressLabel(msg)
+                    }
+                    RTM_DELADDRLABEL => {
+                        RouteNetlinkMessage::DelAddressLabel(msg)
+                    }
+                    RTM_GETADDRLABEL => {
+                        RouteNetlinkMessage::GetAddressLabel(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            // Link statistics messages
+            RTM_NEWSTATS | RTM_GETSTATS | RTM_SETSTATS => {
+                let msg = StatsMessage::parse_with_param(
+                    &StatsMessageBuffer::new_checked(&buf.inner())?,
+                    message_type,
+                )?;
+                match message_type {
+                    RTM_NEWSTATS => RouteNetlinkMessage::NewStats(msg),
+                    RTM_GETSTATS => RouteNetlinkMessage::GetStats(msg),
+                    RTM_SETSTATS => RouteNetlinkMessage::SetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+            // Nexthop object messages
+            RTM_NEWNEXTHOP | RTM_DELNEXTHOP | RTM_GETNEXTHOP
+            | RTM_NEWNEXTHOPBUCKET | RTM_DELNEXTHOPBUCKET
+            | RTM_GETNEXTHOPBUCKET => {
+                let buf_inner = buf.inner();
+                let msg = NexthopMessage::parse(
+                    &NexthopMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                match message_type {
+                    RTM_NEWNEXTHOP => RouteNetlinkMessage::NewNexthop(msg),
+                    RTM_DELNEXTHOP => RouteNetlinkMessage::DelNexthop(msg),
+                    RTM_GETNEXTHOP => RouteNetlinkMessage::GetNexthop(msg),
+                    RTM_NEWNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::NewNexthopBucket(msg)
+                    }
+                    RTM_DELNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::DelNexthopBucket(msg)
+                    }
+                    RTM_GETNEXTHOPBUCKET => {
+                        RouteNetlinkMessage::GetNexthopBucket(msg)
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            // Neighbour discovery user options
+            RTM_NEWNDUSEROPT => {
+                let buf_inner = buf.inner();
+                let msg = NdUserOptionMessage::parse(
+                    &NdUserOptionMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                RouteNetlinkMessage::NewNdUserOption(msg)
+            }
+            // Data center bridging messages
+            RTM_GETDCB | RTM_SETDCB => {
+                let buf_inner = buf.inner();
+                let msg = DcbMessage::parse(&DcbMessageBuffer::new_checked(
+                    &buf_inner,
+                )?)?;
+                match message_type {
+                    RTM_GETDCB => RouteNetlinkMessage::GetDcb(msg),
+                    RTM_SETDCB => RouteNetlinkMessage::SetDcb(msg),
+                    _ => unreachable!(),
+                }
+            }
+            // Bridge VLAN database messages
+            RTM_NEWVLAN | RTM_DELVLAN | RTM_GETVLAN => {
+                let msg = BridgeVlanMessage::parse_with_param(
+                    &BridgeVlanMessageBuffer::new_checked(&buf.inner())?,
+                    message_type,
+                )?;
+                match message_type {
+                    RTM_NEWVLAN => RouteNetlinkMessage::NewBridgeVlan(msg),
+                    RTM_DELVLAN => RouteNetlinkMessage::DelBridgeVlan(msg),
+                    RTM_GETVLAN => RouteNetlinkMessage::GetBridgeVlan(msg),
+                    _ => unreachable!(),
+                }
+            }
+            // Tunnel messages
+            RTM_NEWTUNNEL | RTM_DELTUNNEL | RTM_GETTUNNEL => {
+                let buf_inner = buf.inner();
+                let msg = TunnelMessage::parse(
+                    &TunnelMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                match message_type {
+                    RTM_NEWTUNNEL => RouteNetlinkMessage::NewTunnel(msg),
+                    RTM_DELTUNNEL => RouteNetlinkMessage::DelTunnel(msg),
+                    RTM_GETTUNNEL => RouteNetlinkMessage::GetTunnel(msg),
+                    _ => unreachable!(),
+                }
+            }
+            // Multicast routing cache reports
+            RTM_NEWCACHEREPORT => {
+                let buf_inner = buf.inner();
+                let msg = CacheReportMessage::parse(
+                    &CacheReportMessageBuffer::new_checked(&buf_inner)?,
+                )?;
+                RouteNetlinkMessage::NewCacheReport(msg)
+            }
             _ => {
                 return Err(
                     AxError::InvalidInput
@@ -164,6 +572,81 @@ pub enum RouteNetlinkMessage {
     NewAddress(AddressMessage),
     DelAddress(AddressMessage),
     GetAddress(AddressMessage),
+    /// Notification of joining an IPv6 multicast group, the group address
+    /// is stored in [crate::address::AddressAttribute::Multicast].
+    NewMulticastAddress(AddressMessage),
+    /// Notification of leaving an IPv6 multicast group.
+    DelMulticastAddress(AddressMessage),
+    /// Dump request of IPv6 multicast group addresses. The kernel also uses
+    /// `RTM_GETMULTICAST` as the message type of the dump replies.
+    GetMulticastAddress(AddressMessage),
+    /// Notification of adding an IPv6 anycast address, the address is
+    /// stored in [crate::address::AddressAttribute::Anycast].
+    NewAnycastAddress(AddressMessage),
+    /// Notification of removing an IPv6 anycast address.
+    DelAnycastAddress(AddressMessage),
+    /// Dump request of IPv6 anycast addresses. The kernel also uses
+    /// `RTM_GETANYCAST` as the message type of the dump replies.
+    GetAnycastAddress(AddressMessage),
+    NewRoute(RouteMessage),
+    DelRoute(RouteMessage),
+    GetRoute(RouteMessage),
+    NewNeighbour(NeighbourMessage),
+    GetNeighbour(NeighbourMessage),
+    DelNeighbour(NeighbourMessage),
+    NewNeighbourTable(NeighbourTableMessage),
+    GetNeighbourTable(NeighbourTableMessage),
+    SetNeighbourTable(NeighbourTableMessage),
+    NewRule(RuleMessage),
+    DelRule(RuleMessage),
+    GetRule(RuleMessage),
+    NewQueueDiscipline(TcMessage),
+    DelQueueDiscipline(TcMessage),
+    GetQueueDiscipline(TcMessage),
+    NewTrafficClass(TcMessage),
+    DelTrafficClass(TcMessage),
+    GetTrafficClass(TcMessage),
+    NewTrafficFilter(TcMessage),
+    DelTrafficFilter(TcMessage),
+    GetTrafficFilter(TcMessage),
+    NewTrafficChain(TcMessage),
+    DelTrafficChain(TcMessage),
+    GetTrafficChain(TcMessage),
+    NewTrafficAction(TcActionMessage),
+    DelTrafficAction(TcActionMessage),
+    GetTrafficAction(TcActionMessage),
+    NewNsId(NsidMessage),
+    DelNsId(NsidMessage),
+    GetNsId(NsidMessage),
+    NewPrefix(PrefixMessage),
+    NewNetconf(NetconfMessage),
+    DelNetconf(NetconfMessage),
+    GetNetconf(NetconfMessage),
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    NewAddressLabel(AddressLabelMessage),
+    DelAddressLabel(AddressLabelMessage),
+    GetAddressLabel(AddressLabelMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
+    SetStats(StatsMessage),
+    NewNexthop(NexthopMessage),
+    DelNexthop(NexthopMessage),
+    GetNexthop(NexthopMessage),
+    NewNexthopBucket(NexthopMessage),
+    DelNexthopBucket(NexthopMessage),
+    GetNexthopBucket(NexthopMessage),
+    NewNdUserOption(NdUserOptionMessage),
+    GetDcb(DcbMessage),
+    SetDcb(DcbMessage),
+    NewBridgeVlan(BridgeVlanMessage),
+    DelBridgeVlan(BridgeVlanMessage),
+    GetBridgeVlan(BridgeVlanMessage),
+    NewTunnel(TunnelMessage),
+    DelTunnel(TunnelMessage),
+    GetTunnel(TunnelMessage),
+    NewCacheReport(CacheReportMessage),
 }
 
 impl RouteNetlinkMessage {
@@ -195,6 +678,266 @@ impl RouteNetlinkMessage {
         matches!(self, RouteNetlinkMessage::GetAddress(_))
     }
 
+    pub fn is_new_multicast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewMulticastAddress(_))
+    }
+
+    pub fn is_del_multicast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelMulticastAddress(_))
+    }
+
+    pub fn is_get_multicast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetMulticastAddress(_))
+    }
+
+    pub fn is_new_anycast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewAnycastAddress(_))
+    }
+
+    pub fn is_del_anycast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelAnycastAddress(_))
+    }
+
+    pub fn is_get_anycast_address(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetAnycastAddress(_))
+    }
+
+    pub fn is_new_route(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewRoute(_))
+    }
+
+    pub fn is_del_route(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelRoute(_))
+    }
+
+    pub fn is_get_route(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetRoute(_))
+    }
+
+    pub fn is_new_neighbour(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNeighbour(_))
+    }
+
+    pub fn is_get_neighbour(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNeighbour(_))
+    }
+
+    pub fn is_del_neighbour(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNeighbour(_))
+    }
+
+    pub fn is_new_neighbour_table(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNeighbourTable(_))
+    }
+
+    pub fn is_get_neighbour_table(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNeighbourTable(_))
+    }
+
+    pub fn is_set_neighbour_table(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::SetNeighbourTable(_))
+    }
+
+    pub fn is_new_rule(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewRule(_))
+    }
+
+    pub fn is_del_rule(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelRule(_))
+    }
+
+    pub fn is_get_rule(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetRule(_))
+    }
+
+    pub fn is_new_qdisc(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewQueueDiscipline(_))
+    }
+
+    pub fn is_del_qdisc(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelQueueDiscipline(_))
+    }
+
+    pub fn is_get_qdisc(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetQueueDiscipline(_))
+    }
+
+    pub fn is_new_traffic_class(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTrafficClass(_))
+    }
+
+    pub fn is_del_traffic_class(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTrafficClass(_))
+    }
+
+    pub fn is_get_traffic_class(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTrafficClass(_))
+    }
+
+    pub fn is_new_filter(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTrafficFilter(_))
+    }
+
+    pub fn is_del_filter(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTrafficFilter(_))
+    }
+
+    pub fn is_get_filter(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTrafficFilter(_))
+    }
+
+    pub fn is_new_chain(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTrafficChain(_))
+    }
+
+    pub fn is_del_chain(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTrafficChain(_))
+    }
+
+    pub fn is_get_chain(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTrafficChain(_))
+    }
+
+    pub fn is_new_action(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTrafficAction(_))
+    }
+
+    pub fn is_del_action(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTrafficAction(_))
+    }
+
+    pub fn is_get_action(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTrafficAction(_))
+    }
+
+    pub fn is_new_nsid(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNsId(_))
+    }
+
+    pub fn is_del_nsid(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNsId(_))
+    }
+
+    pub fn is_get_nsid(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNsId(_))
+    }
+
+    pub fn is_new_prefix(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewPrefix(_))
+    }
+
+    pub fn is_new_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNetconf(_))
+    }
+
+    pub fn is_del_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNetconf(_))
+    }
+
+    pub fn is_get_netconf(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNetconf(_))
+    }
+
+    pub fn is_new_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewMdb(_))
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelMdb(_))
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetMdb(_))
+    }
+
+    pub fn is_new_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewAddressLabel(_))
+    }
+
+    pub fn is_del_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelAddressLabel(_))
+    }
+
+    pub fn is_get_address_label(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetAddressLabel(_))
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetStats(_))
+    }
+
+    pub fn is_set_stats(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::SetStats(_))
+    }
+
+    pub fn is_new_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthop(_))
+    }
+
+    pub fn is_del_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthop(_))
+    }
+
+    pub fn is_get_nexthop(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthop(_))
+    }
+
+    pub fn is_new_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNexthopBucket(_))
+    }
+
+    pub fn is_del_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelNexthopBucket(_))
+    }
+
+    pub fn is_get_nexthop_bucket(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetNexthopBucket(_))
+    }
+
+    pub fn is_new_nd_user_option(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewNdUserOption(_))
+    }
+
+    pub fn is_get_dcb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetDcb(_))
+    }
+
+    pub fn is_set_dcb(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::SetDcb(_))
+    }
+
+    pub fn is_new_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewBridgeVlan(_))
+    }
+
+    pub fn is_del_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelBridgeVlan(_))
+    }
+
+    pub fn is_get_bridge_vlan(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetBridgeVlan(_))
+    }
+
+    pub fn is_new_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewTunnel(_))
+    }
+
+    pub fn is_del_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::DelTunnel(_))
+    }
+
+    pub fn is_get_tunnel(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::GetTunnel(_))
+    }
+
+    pub fn is_new_cache_report(&self) -> bool {
+        matches!(self, RouteNetlinkMessage::NewCacheReport(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RouteNetlinkMessage::*;
 
@@ -208,6 +951,71 @@ impl RouteNetlinkMessage {
             NewAddress(_) => RTM_NEWADDR,
             DelAddress(_) => RTM_DELADDR,
             GetAddress(_) => RTM_GETADDR,
+            NewMulticastAddress(_) => RTM_NEWMULTICAST,
+            DelMulticastAddress(_) => RTM_DELMULTICAST,
+            GetMulticastAddress(_) => RTM_GETMULTICAST,
+            NewAnycastAddress(_) => RTM_NEWANYCAST,
+            DelAnycastAddress(_) => RTM_DELANYCAST,
+            GetAnycastAddress(_) => RTM_GETANYCAST,
+            NewRoute(_) => RTM_NEWROUTE,
+            DelRoute(_) => RTM_DELROUTE,
+            GetRoute(_) => RTM_GETROUTE,
+            NewNeighbour(_) => RTM_NEWNEIGH,
+            DelNeighbour(_) => RTM_DELNEIGH,
+            GetNeighbour(_) => RTM_GETNEIGH,
+            NewNeighbourTable(_) => RTM_NEWNEIGHTBL,
+            GetNeighbourTable(_) => RTM_GETNEIGHTBL,
+            SetNeighbourTable(_) => RTM_SETNEIGHTBL,
+            NewRule(_) => RTM_NEWRULE,
+            DelRule(_) => RTM_DELRULE,
+            GetRule(_) => RTM_GETRULE,
+            NewQueueDiscipline(_) => RTM_NEWQDISC,
+            DelQueueDiscipline(_) => RTM_DELQDISC,
+            GetQueueDiscipline(_) => RTM_GETQDISC,
+            NewTrafficClass(_) => RTM_NEWTCLASS,
+            DelTrafficClass(_) => RTM_DELTCLASS,
+            GetTrafficClass(_) => RTM_GETTCLASS,
+            NewTrafficFilter(_) => RTM_NEWTFILTER,
+            DelTrafficFilter(_) => RTM_DELTFILTER,
+            GetTrafficFilter(_) => RTM_GETTFILTER,
+            NewTrafficChain(_) => RTM_NEWCHAIN,
+            DelTrafficChain(_) => RTM_DELCHAIN,
+            GetTrafficChain(_) => RTM_GETCHAIN,
+            NewTrafficAction(_) => RTM_NEWACTION,
+            DelTrafficAction(_) => RTM_DELACTION,
+            GetTrafficAction(_) => RTM_GETACTION,
+            NewNsId(_) => RTM_NEWNSID,
+            DelNsId(_) => RTM_DELNSID,
+            GetNsId(_) => RTM_GETNSID,
+            NewPrefix(_) => RTM_NEWPREFIX,
+            NewNetconf(_) => RTM_NEWNETCONF,
+            DelNetconf(_) => RTM_DELNETCONF,
+            GetNetconf(_) => RTM_GETNETCONF,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
+            NewAddressLabel(_) => RTM_NEWADDRLABEL,
+            DelAddressLabel(_) => RTM_DELADDRLABEL,
+            GetAddressLabel(_) => RTM_GETADDRLABEL,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
+            SetStats(_) => RTM_SETSTATS,
+            NewNexthop(_) => RTM_NEWNEXTHOP,
+            DelNexthop(_) => RTM_DELNEXTHOP,
+            GetNexthop(_) => RTM_GETNEXTHOP,
+            NewNexthopBucket(_) => RTM_NEWNEXTHOPBUCKET,
+            DelNexthopBucket(_) => RTM_DELNEXTHOPBUCKET,
+            GetNexthopBucket(_) => RTM_GETNEXTHOPBUCKET,
+            NewNdUserOption(_) => RTM_NEWNDUSEROPT,
+            GetDcb(_) => RTM_GETDCB,
+            SetDcb(_) => RTM_SETDCB,
+            NewBridgeVlan(_) => RTM_NEWVLAN,
+            DelBridgeVlan(_) => RTM_DELVLAN,
+            GetBridgeVlan(_) => RTM_GETVLAN,
+            NewTunnel(_) => RTM_NEWTUNNEL,
+            DelTunnel(_) => RTM_DELTUNNEL,
+            GetTunnel(_) => RTM_GETTUNNEL,
+            NewCacheReport(_) => RTM_NEWCACHEREPORT,
         }
     }
 }
@@ -228,6 +1036,113 @@ impl Emitable for RouteNetlinkMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            | NewMulticastAddress(ref msg)
+            | DelMulticastAddress(ref msg)
+            | GetMulticastAddress(ref msg)
+            | NewAnycastAddress(ref msg)
+            | DelAnycastAddress(ref msg)
+            | GetAnycastAddress(ref msg)
+            => msg.buffer_len(),
+
+            | NewRoute(ref msg)
+            | DelRoute(ref msg)
+            | GetRoute(ref msg)
+            => msg.buffer_len(),
+
+            | NewNeighbour(ref msg)
+            | GetNeighbour(ref msg)
+            | DelNeighbour(ref msg)
+            => msg.buffer_len(),
+
+            | NewNeighbourTable(ref msg)
+            | GetNeighbourTable(ref msg)
+            | SetNeighbourTable(ref msg)
+            => msg.buffer_len(),
+
+            | NewRule(ref msg)
+            | DelRule(ref msg)
+            | GetRule(ref msg)
+            => msg.buffer_len(),
+
+            | NewQueueDiscipline(ref msg)
+            | DelQueueDiscipline(ref msg)
+            | GetQueueDiscipline(ref msg)
+            => msg.buffer_len(),
+
+            | NewTrafficClass(ref msg)
+            | DelTrafficClass(ref msg)
+            | GetTrafficClass(ref msg)
+            => msg.buffer_len(),
+
+            | NewTrafficFilter(ref msg)
+            | DelTrafficFilter(ref msg)
+            | GetTrafficFilter(ref msg)
+            => msg.buffer_len(),
+
+            | NewTrafficChain(ref msg)
+            | DelTrafficChain(ref msg)
+            | GetTrafficChain(ref msg)
+            => msg.buffer_len(),
+
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.buffer_len(),
+
+            | NewNsId(ref msg)
+            | DelNsId(ref msg)
+            | GetNsId(ref msg)
+            => msg.buffer_len(),
+
+            | NewPrefix(ref msg)
+            => msg.buffer_len(),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            | SetStats(ref msg)
+            => msg.buffer_len(),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.buffer_len(),
+
+            | NewNdUserOption(ref msg)
+            => msg.buffer_len(),
+
+            | GetDcb(ref msg)
+            | SetDcb(ref msg)
+            => msg.buffer_len(),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.buffer_len(),
+
+            | NewTunnel(ref msg)
+            | DelTunnel(ref msg)
+            | GetTunnel(ref msg)
+            => msg.buffer_len(),
+
+            | NewCacheReport(ref msg)
             => msg.buffer_len(),
         }
     }
@@ -247,6 +1162,113 @@ impl Emitable for RouteNetlinkMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            | NewMulticastAddress(ref msg)
+            | DelMulticastAddress(ref msg)
+            | GetMulticastAddress(ref msg)
+            | NewAnycastAddress(ref msg)
+            | DelAnycastAddress(ref msg)
+            | GetAnycastAddress(ref msg)
+            => msg.emit(buffer),
+
+            | NewRoute(ref msg)
+            | DelRoute(ref msg)
+            | GetRoute(ref msg)
+            => msg.emit(buffer),
+
+            | NewNeighbour(ref msg)
+            | GetNeighbour(ref msg)
+            | DelNeighbour(ref msg)
+            => msg.emit(buffer),
+
+            | NewNeighbourTable(ref msg)
+            | GetNeighbourTable(ref msg)
+            | SetNeighbourTable(ref msg)
+            => msg.emit(buffer),
+
+            | NewRule(ref msg)
+            | DelRule(ref msg)
+            | GetRule(ref msg)
+            => msg.emit(buffer),
+
+            | NewQueueDiscipline(ref msg)
+            | DelQueueDiscipline(ref msg)
+            | GetQueueDiscipline(ref msg)
+            => msg.emit(buffer),
+
+            | NewTrafficClass(ref msg)
+            | DelTrafficClass(ref msg)
+            | GetTrafficClass(ref msg)
+            => msg.emit(buffer),
+
+            | NewTrafficFilter(ref msg)
+            | DelTrafficFilter(ref msg)
+            | GetTrafficFilter(ref msg)
+            => msg.emit(buffer),
+
+            | NewTrafficChain(ref msg)
+            | DelTrafficChain(ref msg)
+            | GetTrafficChain(ref msg)
+            => msg.emit(buffer),
+
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.emit(buffer),
+
+            | NewNsId(ref msg)
+            | DelNsId(ref msg)
+            | GetNsId(ref msg)
+            => msg.emit(buffer),
+
+            | NewPrefix(ref msg)
+            => msg.emit(buffer),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewAddressLabel(ref msg)
+            | DelAddressLabel(ref msg)
+            | GetAddressLabel(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            | SetStats(ref msg)
+            => msg.emit(buffer),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            | NewNexthopBucket(ref msg)
+            | DelNexthopBucket(ref msg)
+            | GetNexthopBucket(ref msg)
+            => msg.emit(buffer),
+
+            | NewNdUserOption(ref msg)
+            => msg.emit(buffer),
+
+            | GetDcb(ref msg)
+            | SetDcb(ref msg)
+            => msg.emit(buffer),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.emit(buffer),
+
+            | NewTunnel(ref msg)
+            | DelTunnel(ref msg)
+            | GetTunnel(ref msg)
+            => msg.emit(buffer),
+
+            | NewCacheReport(ref msg)
             => msg.emit(buffer),
         }
     }
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: MIT
+
+use axerrno::AxError;
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+pub(crate) const ND_USER_OPTION_HEADER_LEN: usize = 16;
+
+buffer!(NdUserOptionMessageBuffer {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    options_len: (u16, 2..4),
+    ifindex: (i32, 4..8),
+    icmp_type: (u8, 8),
+    icmp_code: (u8, 9),
+    pad2: (u16, 10..12),
+    pad3: (u32, 12..ND_USER_OPTION_HEADER_LEN),
+    payload: (slice, ND_USER_OPTION_HEADER_LEN..),
+});
+
+impl<T: AsRef<[u8]>> NdUserOptionMessageBuffer<T> {
+    pub fn new_checked(buffer: T) -> Result<Self, DecodeError> {
+        let packet = Self::new(buffer);
+        packet.check_buffer_length()?;
+        Ok(packet)
+    }
+
+    fn check_buffer_length(&self) -> Result<(), DecodeError> {
+        let len = self.buffer.as_ref().len();
+        if len < ND_USER_OPTION_HEADER_LEN
+            || len < ND_USER_OPTION_HEADER_LEN + self.options_len() as usize
+        {
+            return Err(AxError::InvalidInput);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NdUserOptionMessageBuffer<&'a T> {
+    /// The raw neighbour discovery options following the header
+    pub fn options(&self) -> &'a [u8] {
+        &self.payload()[..self.options_len() as usize]
+    }
+
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(&self.payload()[self.options_len() as usize..])
+    }
+}
+
+/// High level representation of `RTM_NEWNDUSEROPT` message header
+/// (`struct nduseroptmsg`). The length of the options is calculated from
+/// [super::NdUserOptionMessage::options] when emitting.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdUserOptionHeader {
+    /// Address family, always `AF_INET6`.
+    pub family: AddressFamily,
+    /// Interface index the router advertisement was received on
+    pub ifindex: i32,
+    /// ICMPv6 type of the packet carrying the options, normally
+    /// `ND_ROUTER_ADVERT`(134).
+    pub icmp_type: u8,
+    pub icmp_code: u8,
+}
+
+impl Emitable for NdUserOptionHeader {
+    fn buffer_len(&self) -> usize {
+        ND_USER_OPTION_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NdUserOptionMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_icmp_type(self.icmp_type);
+        packet.set_icmp_code(self.icmp_code);
+        packet.set_pad2(0);
+        packet.set_pad3(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NdUserOptionMessageBuffer<T>>
+    for NdUserOptionHeader
+{
+    fn parse(buf: &NdUserOptionMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NdUserOptionHeader {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+            icmp_type: buf.icmp_type(),
+            icmp_code: buf.icmp_code(),
+        })
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::nd_user_option::{
+    header::ND_USER_OPTION_HEADER_LEN, option::parse_nd_user_options,
+    NdUserOption, NdUserOptionAttribute, NdUserOptionHeader,
+    NdUserOptionMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdUserOptionMessage {
+    pub header: NdUserOptionHeader,
+    /// Neighbour discovery options, kernel sends one option per message.
+    pub options: Vec<NdUserOption>,
+    pub attributes: Vec<NdUserOptionAttribute>,
+}
+
+impl NdUserOptionMessage {
+    fn options_len(&self) -> usize {
+        self.options.iter().map(|o| o.buffer_len()).sum()
+    }
+}
+
+impl Emitable for NdUserOptionMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len()
+            + self.options_len()
+            + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        let options_len = self.options_len();
+        NdUserOptionMessageBuffer::new(&mut *buffer)
+            .set_options_len(options_len as u16);
+        let mut offset = ND_USER_OPTION_HEADER_LEN;
+        for option in self.options.iter() {
+            option.emit(&mut buffer[offset..]);
+            offset += option.buffer_len();
+        }
+        self.attributes.as_slice().emit(&mut buffer[offset..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptionMessageBuffer<&'a T>>
+    for NdUserOptionMessage
+{
+    fn parse(
+        buf: &NdUserOptionMessageBuffer<&'a T>,
+    ) -> Result<Self, DecodeError> {
+        let header = NdUserOptionHeader::parse(buf)?;
+        let options = parse_nd_user_options(buf.options())?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(NdUserOptionAttribute::parse(&nla_buf?)?);
+        }
+        Ok(NdUserOptionMessage {
+            header,
+            options,
+            attributes,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+mod option;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::attribute::NdUserOptionAttribute;
+pub use self::header::{NdUserOptionHeader, NdUserOptionMessageBuffer};
+pub use self::message::NdUserOptionMessage;
+pub use self::option::{
+    NdOptionDnssl, NdOptionPref64, NdOptionRdnss, NdUserOption,
+};
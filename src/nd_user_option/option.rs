@@ -0,0 +1,362 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::net::Ipv6Addr;
+
+use axerrno::AxError;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{traits::Emitable, DecodeError};
+
+use crate::ip::{parse_ipv6_addr, IPV6_ADDR_LEN};
+
+// The length of neighbour discovery options is in units of 8 bytes
+const ND_OPT_LEN_UNIT: usize = 8;
+const ND_OPT_HEADER_LEN: usize = 2;
+
+const ND_OPT_RDNSS: u8 = 25;
+const ND_OPT_DNSSL: u8 = 31;
+const ND_OPT_CAPTIVE_PORTAL: u8 = 37;
+const ND_OPT_PREF64: u8 = 38;
+
+// Type, length, reserved and lifetime of RDNSS and DNSSL
+const ND_OPT_DNS_HEADER_LEN: usize = 8;
+const ND_OPT_PREF64_LEN: usize = 16;
+const PREF64_PREFIX_LEN: usize = 12;
+const DNS_LABEL_MAX_LEN: usize = 63;
+
+/// Neighbour discovery option of router advertisement forwarded by kernel
+/// to user space. Lifetimes are in seconds.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NdUserOption {
+    /// Recursive DNS Server option defined in RFC 8106
+    Rdnss(NdOptionRdnss),
+    /// DNS Search List option defined in RFC 8106
+    Dnssl(NdOptionDnssl),
+    /// Captive portal URI defined in RFC 8910
+    CaptivePortal(String),
+    /// NAT64 prefix defined in RFC 8781
+    Pref64(NdOptionPref64),
+    Other {
+        kind: u8,
+        payload: Vec<u8>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdOptionRdnss {
+    /// `u32::MAX` means infinity.
+    pub lifetime: u32,
+    pub servers: Vec<Ipv6Addr>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NdOptionDnssl {
+    /// `u32::MAX` means infinity.
+    pub lifetime: u32,
+    domains: Vec<String>,
+}
+
+impl NdOptionDnssl {
+    /// Fails when any label of the domain names is empty or longer than 63
+    /// bytes.
+    pub fn new(lifetime: u32, domains: Vec<String>) -> Result<Self, AxError> {
+        for domain in domains.iter() {
+            check_dns_name(domain)?;
+        }
+        Ok(Self { lifetime, domains })
+    }
+
+    /// Domain names without the trailing dot
+    pub fn domains(&self) -> &[String] {
+        self.domains.as_slice()
+    }
+
+    /// Append a domain name, fails when any of its labels is empty or
+    /// longer than 63 bytes.
+    pub fn push_domain(&mut self, domain: String) -> Result<(), AxError> {
+        check_dns_name(&domain)?;
+        self.domains.push(domain);
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct NdOptionPref64 {
+    /// Lifetime in seconds, the option stores it in units of 8 seconds.
+    pub lifetime: u16,
+    pub prefix: Ipv6Addr,
+    prefix_len: u8,
+}
+
+impl NdOptionPref64 {
+    /// Fails when `prefix_len` is not one of 96, 64, 56, 48, 40 and 32.
+    pub fn new(
+        lifetime: u16,
+        prefix: Ipv6Addr,
+        prefix_len: u8,
+    ) -> Result<Self, AxError> {
+        pref64_plc(prefix_len)?;
+        Ok(Self {
+            lifetime,
+            prefix,
+            prefix_len,
+        })
+    }
+
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Fails when `prefix_len` is not one of 96, 64, 56, 48, 40 and 32.
+    pub fn set_prefix_len(&mut self, prefix_len: u8) -> Result<(), AxError> {
+        pref64_plc(prefix_len)?;
+        self.prefix_len = prefix_len;
+        Ok(())
+    }
+}
+
+impl Default for NdOptionPref64 {
+    fn default() -> Self {
+        Self {
+            lifetime: 0,
+            prefix: Ipv6Addr::UNSPECIFIED,
+            prefix_len: 96,
+        }
+    }
+}
+
+// Prefix length of each Prefix Length Code
+const PREF64_PLC: [u8; 6] = [96, 64, 56, 48, 40, 32];
+// Prefix Length Code 6 and 7 are invalid and ignored by receivers
+const PREF64_PLC_INVALID: u16 = 7;
+
+fn pref64_plc(prefix_len: u8) -> Result<u16, AxError> {
+    PREF64_PLC
+        .iter()
+        .position(|l| *l == prefix_len)
+        .map(|plc| plc as u16)
+        .ok_or(AxError::InvalidInput)
+}
+
+impl NdUserOption {
+    fn kind(&self) -> u8 {
+        match self {
+            Self::Rdnss(_) => ND_OPT_RDNSS,
+            Self::Dnssl(_) => ND_OPT_DNSSL,
+            Self::CaptivePortal(_) => ND_OPT_CAPTIVE_PORTAL,
+            Self::Pref64(_) => ND_OPT_PREF64,
+            Self::Other { kind, .. } => *kind,
+        }
+    }
+
+    // Length of the option without padding
+    fn unpadded_len(&self) -> usize {
+        match self {
+            Self::Rdnss(v) => {
+                ND_OPT_DNS_HEADER_LEN + v.servers.len() * IPV6_ADDR_LEN
+            }
+            Self::Dnssl(v) => {
+                ND_OPT_DNS_HEADER_LEN
+                    + v.domains
+                        .iter()
+                        .map(|d| dns_name_len(d.as_str()))
+                        .sum::<usize>()
+            }
+            Self::CaptivePortal(uri) => ND_OPT_HEADER_LEN + uri.len(),
+            Self::Pref64(_) => ND_OPT_PREF64_LEN,
+            Self::Other { payload, .. } => ND_OPT_HEADER_LEN + payload.len(),
+        }
+    }
+
+    fn parse(kind: u8, raw: &[u8]) -> Result<Self, DecodeError> {
+        Ok(match kind {
+            ND_OPT_RDNSS => {
+                let payload = dns_option_payload(raw)?;
+                if payload.len() % IPV6_ADDR_LEN != 0 {
+                    return Err(AxError::InvalidInput);
+                }
+                let mut servers = Vec::new();
+                for chunk in payload.chunks(IPV6_ADDR_LEN) {
+                    servers.push(parse_ipv6_addr(chunk)?);
+                }
+                Self::Rdnss(NdOptionRdnss {
+                    lifetime: BigEndian::read_u32(&raw[4..8]),
+                    servers,
+                })
+            }
+            ND_OPT_DNSSL => Self::Dnssl(NdOptionDnssl {
+                domains: parse_dns_names(dns_option_payload(raw)?)?,
+                lifetime: BigEndian::read_u32(&raw[4..8]),
+            }),
+            ND_OPT_CAPTIVE_PORTAL => {
+                let uri = &raw[ND_OPT_HEADER_LEN..];
+                let end = uri.iter().position(|c| *c == 0).unwrap_or(uri.len());
+                Self::CaptivePortal(
+                    String::from_utf8(uri[..end].to_vec())
+                        .map_err(|_| AxError::InvalidInput)?,
+                )
+            }
+            ND_OPT_PREF64 => {
+                if raw.len() != ND_OPT_PREF64_LEN {
+                    return Err(AxError::InvalidInput);
+                }
+                let value = BigEndian::read_u16(&raw[2..4]);
+                let prefix_len = *PREF64_PLC
+                    .get((value & 0x7) as usize)
+                    .ok_or(AxError::InvalidInput)?;
+                let mut prefix = [0u8; IPV6_ADDR_LEN];
+                prefix[..PREF64_PREFIX_LEN].copy_from_slice(&raw[4..]);
+                Self::Pref64(NdOptionPref64 {
+                    lifetime: value & !0x7,
+                    prefix: Ipv6Addr::from(prefix),
+                    prefix_len,
+                })
+            }
+            _ => Self::Other {
+                kind,
+                payload: raw[ND_OPT_HEADER_LEN..].to_vec(),
+            },
+        })
+    }
+}
+
+impl Emitable for NdUserOption {
+    fn buffer_len(&self) -> usize {
+        self.unpadded_len().div_ceil(ND_OPT_LEN_UNIT) * ND_OPT_LEN_UNIT
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        let buffer = &mut buffer[..len];
+        buffer.fill(0);
+        buffer[0] = self.kind();
+        buffer[1] = (len / ND_OPT_LEN_UNIT) as u8;
+        match self {
+            Self::Rdnss(v) => {
+                BigEndian::write_u32(&mut buffer[4..8], v.lifetime);
+                for (i, server) in v.servers.iter().enumerate() {
+                    let offset = ND_OPT_DNS_HEADER_LEN + i * IPV6_ADDR_LEN;
+                    buffer[offset..offset + IPV6_ADDR_LEN]
+                        .copy_from_slice(&server.octets());
+                }
+            }
+            Self::Dnssl(v) => {
+                BigEndian::write_u32(&mut buffer[4..8], v.lifetime);
+                let mut offset = ND_OPT_DNS_HEADER_LEN;
+                for domain in v.domains.iter() {
+                    offset += emit_dns_name(domain, &mut buffer[offset..]);
+                }
+            }
+            Self::CaptivePortal(uri) => buffer
+                [ND_OPT_HEADER_LEN..ND_OPT_HEADER_LEN + uri.len()]
+                .copy_from_slice(uri.as_bytes()),
+            Self::Pref64(v) => {
+                // `prefix_len` is checked when set, never send an invalid
+                // one as a valid PLC
+                let plc =
+                    pref64_plc(v.prefix_len).unwrap_or(PREF64_PLC_INVALID);
+                BigEndian::write_u16(
+                    &mut buffer[2..4],
+                    (v.lifetime & !0x7) | plc,
+                );
+                buffer[4..]
+                    .copy_from_slice(&v.prefix.octets()[..PREF64_PREFIX_LEN]);
+            }
+            Self::Other { payload, .. } => buffer
+                [ND_OPT_HEADER_LEN..ND_OPT_HEADER_LEN + payload.len()]
+                .copy_from_slice(payload),
+        }
+    }
+}
+
+pub(crate) fn parse_nd_user_options(
+    raw: &[u8],
+) -> Result<Vec<NdUserOption>, DecodeError> {
+    let mut options = Vec::new();
+    let mut raw = raw;
+    while !raw.is_empty() {
+        if raw.len() < ND_OPT_HEADER_LEN {
+            return Err(AxError::InvalidInput);
+        }
+        let len = raw[1] as usize * ND_OPT_LEN_UNIT;
+        if len == 0 || len > raw.len() {
+            return Err(AxError::InvalidInput);
+        }
+        options.push(NdUserOption::parse(raw[0], &raw[..len])?);
+        raw = &raw[len..];
+    }
+    Ok(options)
+}
+
+// Skip the type, length, reserved and lifetime fields
+fn dns_option_payload(raw: &[u8]) -> Result<&[u8], DecodeError> {
+    raw.get(ND_OPT_DNS_HEADER_LEN..)
+        .ok_or(AxError::InvalidInput)
+}
+
+// Length of domain name in DNS label encoding, including the root label
+fn dns_name_len(name: &str) -> usize {
+    name.split('.')
+        .filter(|l| !l.is_empty())
+        .map(|l| l.len() + 1)
+        .sum::<usize>()
+        + 1
+}
+
+fn check_dns_name(name: &str) -> Result<(), AxError> {
+    if name
+        .split('.')
+        .any(|l| l.is_empty() || l.len() > DNS_LABEL_MAX_LEN)
+    {
+        return Err(AxError::InvalidInput);
+    }
+    Ok(())
+}
+
+fn emit_dns_name(name: &str, buffer: &mut [u8]) -> usize {
+    let mut offset = 0;
+    for label in name.split('.').filter(|l| !l.is_empty()) {
+        // Labels are checked to fit in `DNS_LABEL_MAX_LEN` when set
+        buffer[offset] = label.len() as u8;
+        buffer[offset + 1..offset + 1 + label.len()]
+            .copy_from_slice(label.as_bytes());
+        offset += label.len() + 1;
+    }
+    buffer[offset] = 0;
+    offset + 1
+}
+
+// The domain names are not compressed and the option is padded with zeros
+fn parse_dns_names(raw: &[u8]) -> Result<Vec<String>, DecodeError> {
+    let mut names = Vec::new();
+    let mut offset = 0;
+    while offset < raw.len() && raw[offset] != 0 {
+        let mut name = String::new();
+        loop {
+            let len = *raw.get(offset).ok_or(AxError::InvalidInput)? as usize;
+            offset += 1;
+            if len == 0 {
+                break;
+            } else if len > DNS_LABEL_MAX_LEN {
+                return Err(AxError::InvalidInput);
+            }
+            let label =
+                raw.get(offset..offset + len).ok_or(AxError::InvalidInput)?;
+            if !name.is_empty() {
+                name.push('.');
+            }
+            name.push_str(
+                core::str::from_utf8(label)
+                    .map_err(|_| AxError::InvalidInput)?,
+            );
+            offset += len;
+        }
+        names.push(name);
+    }
+    Ok(names)
+}
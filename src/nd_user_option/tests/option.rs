@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::net::Ipv6Addr;
+
+use netlink_packet_utils::Emitable;
+
+use crate::nd_user_option::{
+    option::parse_nd_user_options, NdOptionDnssl, NdOptionPref64,
+    NdOptionRdnss, NdUserOption,
+};
+
+fn emit(option: &NdUserOption) -> Vec<u8> {
+    let mut buf = vec![0xffu8; option.buffer_len()];
+    option.emit(&mut buf);
+    buf
+}
+
+#[test]
+fn test_rdnss() {
+    let raw: Vec<u8> = vec![
+        0x19, 0x05, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x20, 0x01, 0x0d, 0xb8,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x02,
+    ];
+    let expected = NdUserOption::Rdnss(NdOptionRdnss {
+        lifetime: 1800,
+        servers: vec![
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1),
+            Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2),
+        ],
+    });
+
+    assert_eq!(parse_nd_user_options(&raw).unwrap(), vec![expected.clone()]);
+    assert_eq!(emit(&expected), raw);
+}
+
+#[test]
+fn test_dnssl() {
+    // "example.com" and "lab.example.org", padded to 40 bytes
+    let raw: Vec<u8> = vec![
+        0x1f, 0x05, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x07, 0x65, 0x78, 0x61,
+        0x6d, 0x70, 0x6c, 0x65, 0x03, 0x63, 0x6f, 0x6d, 0x00, 0x03, 0x6c, 0x61,
+        0x62, 0x07, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x03, 0x6f, 0x72,
+        0x67, 0x00, 0x00, 0x00,
+    ];
+    let expected = NdUserOption::Dnssl(
+        NdOptionDnssl::new(
+            1800,
+            vec!["example.com".to_string(), "lab.example.org".to_string()],
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(parse_nd_user_options(&raw).unwrap(), vec![expected.clone()]);
+    assert_eq!(emit(&expected), raw);
+}
+
+#[test]
+fn test_dnssl_max_label() {
+    let label = "a".repeat(63);
+    let mut dnssl = NdOptionDnssl::default();
+    dnssl.push_domain(label.clone()).unwrap();
+    let option = NdUserOption::Dnssl(dnssl);
+
+    let raw = emit(&option);
+    assert_eq!(raw[8], 63);
+    assert_eq!(parse_nd_user_options(&raw).unwrap(), vec![option]);
+}
+
+#[test]
+fn test_dnssl_invalid_label() {
+    let mut dnssl = NdOptionDnssl::default();
+    assert!(dnssl.push_domain("a".repeat(64)).is_err());
+    assert!(dnssl
+        .push_domain(format!("{}.com", "a".repeat(256)))
+        .is_err());
+    assert!(dnssl.push_domain("example..com".to_string()).is_err());
+    assert!(dnssl.push_domain(String::new()).is_err());
+    assert!(NdOptionDnssl::new(0, vec!["b".repeat(64)]).is_err());
+    assert!(dnssl.domains().is_empty());
+}
+
+#[test]
+fn test_pref64() {
+    let raw: Vec<u8> = vec![
+        0x26, 0x02, 0x07, 0x09, 0x00, 0x64, 0xff, 0x9b, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ];
+    let expected = NdUserOption::Pref64(
+        NdOptionPref64::new(
+            1800,
+            Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0, 0),
+            64,
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(parse_nd_user_options(&raw).unwrap(), vec![expected.clone()]);
+    assert_eq!(emit(&expected), raw);
+}
+
+#[test]
+fn test_pref64_invalid_prefix_len() {
+    assert!(NdOptionPref64::new(1800, Ipv6Addr::UNSPECIFIED, 80).is_err());
+    let mut pref64 = NdOptionPref64::default();
+    assert!(pref64.set_prefix_len(80).is_err());
+    assert_eq!(pref64.prefix_len(), 96);
+}
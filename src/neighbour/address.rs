@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use crate::ip::{
+    parse_ipv4_addr, parse_ipv6_addr, IPV4_ADDR_LEN, IPV6_ADDR_LEN,
+};
+use crate::AddressFamily;
+
+const ETH_ALEN: usize = 6;
+
+/// Network layer address of the neighbour carried by `NDA_DST`.
+///
+/// For bridge FDB entries([AddressFamily::Bridge]), `NDA_DST` holds the IP
+/// address of the remote tunnel endpoint(e.g. VXLAN VTEP) while the MAC
+/// address is stored in [super::NeighbourAttribute::LinkLocalAddress].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NeighbourAddress {
+    Inet(Ipv4Addr),
+    Inet6(Ipv6Addr),
+    Other(Vec<u8>),
+}
+
+impl NeighbourAddress {
+    pub(crate) fn parse(
+        address_family: AddressFamily,
+        payload: &[u8],
+    ) -> Result<Self, DecodeError> {
+        Ok(match address_family {
+            AddressFamily::Inet => Self::Inet(parse_ipv4_addr(payload)?),
+            AddressFamily::Inet6 => Self::Inet6(parse_ipv6_addr(payload)?),
+            // The bridge FDB remote address could be either IPv4 or IPv6.
+            AddressFamily::Bridge => match payload.len() {
+                IPV4_ADDR_LEN => Self::Inet(parse_ipv4_addr(payload)?),
+                IPV6_ADDR_LEN => Self::Inet6(parse_ipv6_addr(payload)?),
+                _ => Self::Other(payload.to_vec()),
+            },
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
+
+impl Emitable for NeighbourAddress {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Inet(_) => IPV4_ADDR_LEN,
+            Self::Inet6(_) => IPV6_ADDR_LEN,
+            Self::Other(v) => v.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Inet(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Inet6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
+        }
+    }
+}
+
+impl From<Ipv4Addr> for NeighbourAddress {
+    fn from(v: Ipv4Addr) -> Self {
+        Self::Inet(v)
+    }
+}
+
+impl From<Ipv6Addr> for NeighbourAddress {
+    fn from(v: Ipv6Addr) -> Self {
+        Self::Inet6(v)
+    }
+}
+
+impl From<IpAddr> for NeighbourAddress {
+    fn from(v: IpAddr) -> Self {
+        match v {
+            IpAddr::V4(v4) => Self::Inet(v4),
+            IpAddr::V6(v6) => Self::Inet6(v6),
+        }
+    }
+}
+
+/// Link layer address of the neighbour carried by `NDA_LLADDR`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NeighbourLinkLayerAddress {
+    /// Ethernet MAC address, also used by bridge FDB entries
+    Mac([u8; ETH_ALEN]),
+    /// Address of other lengths, e.g. 20 bytes of IPoIB
+    Other(Vec<u8>),
+}
+
+impl NeighbourLinkLayerAddress {
+    pub(crate) fn parse(payload: &[u8]) -> Self {
+        match <[u8; ETH_ALEN]>::try_from(payload) {
+            Ok(mac) => Self::Mac(mac),
+            Err(_) => Self::Other(payload.to_vec()),
+        }
+    }
+}
+
+impl Emitable for NeighbourLinkLayerAddress {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Mac(_) => ETH_ALEN,
+            Self::Other(v) => v.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mac(v) => buffer.copy_from_slice(v),
+            Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
+        }
+    }
+}
+
+impl From<[u8; ETH_ALEN]> for NeighbourLinkLayerAddress {
+    fn from(v: [u8; ETH_ALEN]) -> Self {
+        Self::Mac(v)
+    }
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_i32, parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::{
+    neighbour::{
+        NeighbourAddress, NeighbourCacheInfo, NeighbourCacheInfoBuffer,
+        NeighbourFdbExtAttribute, NeighbourLinkLayerAddress,
+    },
+    route::RouteProtocol,
+    AddressFamily,
+};
+
+const NDA_DST: u16 = 1;
+const NDA_LLADDR: u16 = 2;
+const NDA_CACHEINFO: u16 = 3;
+const NDA_PROBES: u16 = 4;
+const NDA_VLAN: u16 = 5;
+const NDA_PORT: u16 = 6;
+const NDA_VNI: u16 = 7;
+const NDA_IFINDEX: u16 = 8;
+// Kernel constant name is NDA_MASTER
+const NDA_CONTROLLER: u16 = 9;
+const NDA_LINK_NETNSID: u16 = 10;
+const NDA_SRC_VNI: u16 = 11;
+const NDA_PROTOCOL: u16 = 12;
+const NDA_NH_ID: u16 = 13;
+const NDA_FDB_EXT_ATTRS: u16 = 14;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NeighbourAttribute {
+    Destination(NeighbourAddress),
+    /// Link layer address, for bridge FDB entries this is the MAC address.
+    LinkLocalAddress(NeighbourLinkLayerAddress),
+    CacheInfo(NeighbourCacheInfo),
+    Probes(u32),
+    Vlan(u16),
+    /// UDP destination port of the remote tunnel endpoint.
+    Port(u16),
+    Vni(u32),
+    IfIndex(u32),
+    Controller(u32),
+    LinkNetNsId(i32),
+    SourceVni(u32),
+    Protocol(RouteProtocol),
+    NextHopId(u32),
+    FdbExtAttrs(Vec<NeighbourFdbExtAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for NeighbourAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::LinkLocalAddress(v) => v.buffer_len(),
+            Self::Destination(v) => v.buffer_len(),
+            Self::CacheInfo(v) => v.buffer_len(),
+            Self::Vlan(_) | Self::Port(_) => 2,
+            Self::Protocol(_) => 1,
+            Self::Probes(_)
+            | Self::LinkNetNsId(_)
+            | Self::Vni(_)
+            | Self::IfIndex(_)
+            | Self::Controller(_)
+            | Self::SourceVni(_)
+            | Self::NextHopId(_) => 4,
+            Self::FdbExtAttrs(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Destination(v) => v.emit(buffer),
+            Self::LinkLocalAddress(v) => v.emit(buffer),
+            Self::CacheInfo(v) => v.emit(buffer),
+            Self::Vlan(value) => NativeEndian::write_u16(buffer, *value),
+            Self::Port(value) => BigEndian::write_u16(buffer, *value),
+            Self::Protocol(v) => buffer[0] = u8::from(*v),
+            Self::Probes(value)
+            | Self::Vni(value)
+            | Self::IfIndex(value)
+            | Self::Controller(value)
+            | Self::SourceVni(value)
+            | Self::NextHopId(value) => NativeEndian::write_u32(buffer, *value),
+            Self::LinkNetNsId(value) => NativeEndian::write_i32(buffer, *value),
+            Self::FdbExtAttrs(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Destination(_) => NDA_DST,
+            Self::LinkLocalAddress(_) => NDA_LLADDR,
+            Self::CacheInfo(_) => NDA_CACHEINFO,
+            Self::Probes(_) => NDA_PROBES,
+            Self::Vlan(_) => NDA_VLAN,
+            Self::Port(_) => NDA_PORT,
+            Self::Vni(_) => NDA_VNI,
+            Self::IfIndex(_) => NDA_IFINDEX,
+            Self::Controller(_) => NDA_CONTROLLER,
+            Self::LinkNetNsId(_) => NDA_LINK_NETNSID,
+            Self::SourceVni(_) => NDA_SRC_VNI,
+            Self::Protocol(_) => NDA_PROTOCOL,
+            Self::NextHopId(_) => NDA_NH_ID,
+            Self::FdbExtAttrs(_) => NDA_FDB_EXT_ATTRS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, AddressFamily>
+    for NeighbourAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        address_family: AddressFamily,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NDA_DST => Self::Destination(NeighbourAddress::parse(
+                address_family,
+                payload,
+            )?),
+            NDA_LLADDR => Self::LinkLocalAddress(
+                NeighbourLinkLayerAddress::parse(payload),
+            ),
+            NDA_CACHEINFO => Self::CacheInfo(NeighbourCacheInfo::parse(
+                &NeighbourCacheInfoBuffer::new_checked(payload)?,
+            )?),
+            NDA_PROBES => Self::Probes(parse_u32(payload)?),
+            NDA_VLAN => Self::Vlan(parse_u16(payload)?),
+            NDA_PORT => Self::Port(parse_u16_be(payload)?),
+            NDA_VNI => Self::Vni(parse_u32(payload)?),
+            NDA_IFINDEX => Self::IfIndex(parse_u32(payload)?),
+            NDA_CONTROLLER => Self::Controller(parse_u32(payload)?),
+            NDA_LINK_NETNSID => Self::LinkNetNsId(parse_i32(payload)?),
+            NDA_SRC_VNI => Self::SourceVni(parse_u32(payload)?),
+            NDA_PROTOCOL => Self::Protocol(parse_u8(payload)?.into()),
+            NDA_NH_ID => Self::NextHopId(parse_u32(payload)?),
+            NDA_FDB_EXT_ATTRS => {
+                let mut attrs = vec![];
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(NeighbourFdbExtAttribute::parse(&nla?)?);
+                }
+                Self::FdbExtAttrs(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
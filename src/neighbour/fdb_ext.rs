@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u8,
+    traits::Parseable,
+    DecodeError,
+};
+
+const NFEA_ACTIVITY_NOTIFY: u16 = 1;
+const NFEA_DONT_REFRESH: u16 = 2;
+
+/// Bridge FDB extension attributes nested in `NDA_FDB_EXT_ATTRS`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NeighbourFdbExtAttribute {
+    /// Bitmap of `FDB_NOTIFY_BIT`(1) and `FDB_NOTIFY_INACTIVE_BIT`(2).
+    ActivityNotify(u8),
+    /// Do not refresh the entry when processing the request.
+    DontRefresh,
+    Other(DefaultNla),
+}
+
+impl Nla for NeighbourFdbExtAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ActivityNotify(_) => 1,
+            Self::DontRefresh => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ActivityNotify(v) => buffer[0] = *v,
+            Self::DontRefresh => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ActivityNotify(_) => NFEA_ACTIVITY_NOTIFY,
+            Self::DontRefresh => NFEA_DONT_REFRESH,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NeighbourFdbExtAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NFEA_ACTIVITY_NOTIFY => Self::ActivityNotify(parse_u8(payload)?),
+            NFEA_DONT_REFRESH => Self::DontRefresh,
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
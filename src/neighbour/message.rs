@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::neighbour::{
+    NeighbourAttribute, NeighbourHeader, NeighbourMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NeighbourMessage {
+    pub header: NeighbourHeader,
+    pub attributes: Vec<NeighbourAttribute>,
+}
+
+impl Emitable for NeighbourMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NeighbourMessageBuffer<&'a T>>
+    for NeighbourMessage
+{
+    fn parse(buf: &NeighbourMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = NeighbourHeader::parse(buf)?;
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(NeighbourAttribute::parse_with_param(
+                &nla_buf?,
+                header.family,
+            )?);
+        }
+        Ok(Self { header, attributes })
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+
+mod address;
+mod attribute;
+mod cache_info;
+mod fdb_ext;
+mod flags;
+mod header;
+mod message;
+mod state;
+
+pub use self::address::{NeighbourAddress, NeighbourLinkLayerAddress};
+pub use self::attribute::NeighbourAttribute;
+pub use self::cache_info::{NeighbourCacheInfo, NeighbourCacheInfoBuffer};
+pub use self::fdb_ext::NeighbourFdbExtAttribute;
+pub use self::flags::NeighbourFlags;
+pub use self::header::{NeighbourHeader, NeighbourMessageBuffer};
+pub use self::message::NeighbourMessage;
+pub use self::state::NeighbourState;
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+
+const NUD_INCOMPLETE: u16 = 0x01;
+const NUD_REACHABLE: u16 = 0x02;
+const NUD_STALE: u16 = 0x04;
+const NUD_DELAY: u16 = 0x08;
+const NUD_PROBE: u16 = 0x10;
+const NUD_FAILED: u16 = 0x20;
+const NUD_NOARP: u16 = 0x40;
+const NUD_PERMANENT: u16 = 0x80;
+
+bitflags! {
+    /// Neighbour Unreachability Detection(NUD) state of the neighbour
+    /// entry. The kernel normally only sets a single bit, an empty set
+    /// stands for `NUD_NONE`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct NeighbourState: u16 {
+        const Incomplete = NUD_INCOMPLETE;
+        const Reachable = NUD_REACHABLE;
+        const Stale = NUD_STALE;
+        const Delay = NUD_DELAY;
+        const Probe = NUD_PROBE;
+        const Failed = NUD_FAILED;
+        const Noarp = NUD_NOARP;
+        const Permanent = NUD_PERMANENT;
+        const _ = !0;
+    }
+}
+
+impl Default for NeighbourState {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_string, parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::neighbour_table::{
+    NeighbourTableConfig, NeighbourTableConfigBuffer, NeighbourTableParameter,
+    NeighbourTableStats, NeighbourTableStatsBuffer,
+};
+
+const NDTA_NAME: u16 = 1;
+const NDTA_THRESH1: u16 = 2;
+const NDTA_THRESH2: u16 = 3;
+const NDTA_THRESH3: u16 = 4;
+const NDTA_CONFIG: u16 = 5;
+const NDTA_PARMS: u16 = 6;
+const NDTA_STATS: u16 = 7;
+const NDTA_GC_INTERVAL: u16 = 8;
+// const NDTA_PAD: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NeighbourTableAttribute {
+    /// Name of the table, e.g. `arp_cache` or `ndisc_cache`. The kernel
+    /// use this to look up the table when handling `RTM_SETNEIGHTBL`.
+    Name(String),
+    Threshold1(u32),
+    Threshold2(u32),
+    Threshold3(u32),
+    Config(NeighbourTableConfig),
+    Parms(Vec<NeighbourTableParameter>),
+    Stats(NeighbourTableStats),
+    /// Garbage collection interval in milliseconds
+    GcInterval(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for NeighbourTableAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Name(s) => s.len() + 1,
+            Self::Threshold1(_) | Self::Threshold2(_) | Self::Threshold3(_) => {
+                4
+            }
+            Self::GcInterval(_) => 8,
+            Self::Config(v) => v.buffer_len(),
+            Self::Stats(v) => v.buffer_len(),
+            Self::Parms(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Name(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Config(v) => v.emit(buffer),
+            Self::Stats(v) => v.emit(buffer),
+            Self::Parms(v) => v.as_slice().emit(buffer),
+            Self::GcInterval(value) => NativeEndian::write_u64(buffer, *value),
+            Self::Threshold1(value)
+            | Self::Threshold2(value)
+            | Self::Threshold3(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Name(_) => NDTA_NAME,
+            Self::Config(_) => NDTA_CONFIG,
+            Self::Stats(_) => NDTA_STATS,
+            Self::Parms(_) => NDTA_PARMS | NLA_F_NESTED,
+            Self::GcInterval(_) => NDTA_GC_INTERVAL,
+            Self::Threshold1(_) => NDTA_THRESH1,
+            Self::Threshold2(_) => NDTA_THRESH2,
+            Self::Threshold3(_) => NDTA_THRESH3,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NeighbourTableAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NDTA_NAME => Self::Name(parse_string(payload)?),
+            NDTA_CONFIG => Self::Config(NeighbourTableConfig::parse(
+                &NeighbourTableConfigBuffer::new_checked(payload)?,
+            )?),
+            NDTA_STATS => Self::Stats(NeighbourTableStats::parse(
+                &NeighbourTableStatsBuffer::new_checked(payload)?,
+            )?),
+            NDTA_PARMS => {
+                let mut parms = vec![];
+                for nla in NlasIterator::new(payload) {
+                    parms.push(NeighbourTableParameter::parse(&nla?)?);
+                }
+                Self::Parms(parms)
+            }
+            NDTA_GC_INTERVAL => Self::GcInterval(parse_u64(payload)?),
+            NDTA_THRESH1 => Self::Threshold1(parse_u32(payload)?),
+            NDTA_THRESH2 => Self::Threshold2(parse_u32(payload)?),
+            NDTA_THRESH3 => Self::Threshold3(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod config;
+mod header;
+mod message;
+mod param;
+mod stats;
+
+pub use self::attribute::NeighbourTableAttribute;
+pub use self::config::{NeighbourTableConfig, NeighbourTableConfigBuffer};
+pub use self::header::{NeighbourTableHeader, NeighbourTableMessageBuffer};
+pub use self::message::NeighbourTableMessage;
+pub use self::param::NeighbourTableParameter;
+pub use self::stats::{NeighbourTableStats, NeighbourTableStatsBuffer};
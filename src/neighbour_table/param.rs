@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+const NDTPA_IFINDEX: u16 = 1;
+const NDTPA_REFCNT: u16 = 2;
+const NDTPA_REACHABLE_TIME: u16 = 3;
+const NDTPA_BASE_REACHABLE_TIME: u16 = 4;
+const NDTPA_RETRANS_TIME: u16 = 5;
+const NDTPA_GC_STALETIME: u16 = 6;
+const NDTPA_DELAY_PROBE_TIME: u16 = 7;
+const NDTPA_QUEUE_LEN: u16 = 8;
+const NDTPA_APP_PROBES: u16 = 9;
+const NDTPA_UCAST_PROBES: u16 = 10;
+const NDTPA_MCAST_PROBES: u16 = 11;
+const NDTPA_ANYCAST_DELAY: u16 = 12;
+const NDTPA_PROXY_DELAY: u16 = 13;
+const NDTPA_PROXY_QLEN: u16 = 14;
+const NDTPA_LOCKTIME: u16 = 15;
+const NDTPA_QUEUE_LENBYTES: u16 = 16;
+const NDTPA_MCAST_REPROBES: u16 = 17;
+// const NDTPA_PAD: u16 = 18;
+const NDTPA_INTERVAL_PROBE_TIME_MS: u16 = 19;
+
+/// Per-interface neighbour table parameters nested in `NDTA_PARMS`. All the
+/// time values are in milliseconds.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NeighbourTableParameter {
+    /// Interface index, 0 means the default parameters of the table.
+    IfIndex(u32),
+    ReferenceCount(u32),
+    ReachableTime(u64),
+    BaseReachableTime(u64),
+    RetransTime(u64),
+    GcStaleTime(u64),
+    DelayProbeTime(u64),
+    QueueLen(u32),
+    AppProbes(u32),
+    UcastProbes(u32),
+    McastProbes(u32),
+    AnycastDelay(u64),
+    ProxyDelay(u64),
+    ProxyQueueLen(u32),
+    Locktime(u64),
+    QueueLenBytes(u32),
+    McastReprobes(u32),
+    IntervalProbeTimeMs(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for NeighbourTableParameter {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IfIndex(_)
+            | Self::ReferenceCount(_)
+            | Self::QueueLen(_)
+            | Self::AppProbes(_)
+            | Self::UcastProbes(_)
+            | Self::McastProbes(_)
+            | Self::ProxyQueueLen(_)
+            | Self::QueueLenBytes(_)
+            | Self::McastReprobes(_) => 4,
+            Self::ReachableTime(_)
+            | Self::BaseReachableTime(_)
+            | Self::RetransTime(_)
+            | Self::GcStaleTime(_)
+            | Self::DelayProbeTime(_)
+            | Self::AnycastDelay(_)
+            | Self::ProxyDelay(_)
+            | Self::Locktime(_)
+            | Self::IntervalProbeTimeMs(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IfIndex(value)
+            | Self::ReferenceCount(value)
+            | Self::QueueLen(value)
+            | Self::AppProbes(value)
+            | Self::UcastProbes(value)
+            | Self::McastProbes(value)
+            | Self::ProxyQueueLen(value)
+            | Self::QueueLenBytes(value)
+            | Self::McastReprobes(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::ReachableTime(value)
+            | Self::BaseReachableTime(value)
+            | Self::RetransTime(value)
+            | Self::GcStaleTime(value)
+            | Self::DelayProbeTime(value)
+            | Self::AnycastDelay(value)
+            | Self::ProxyDelay(value)
+            | Self::Locktime(value)
+            | Self::IntervalProbeTimeMs(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IfIndex(_) => NDTPA_IFINDEX,
+            Self::ReferenceCount(_) => NDTPA_REFCNT,
+            Self::ReachableTime(_) => NDTPA_REACHABLE_TIME,
+            Self::BaseReachableTime(_) => NDTPA_BASE_REACHABLE_TIME,
+            Self::RetransTime(_) => NDTPA_RETRANS_TIME,
+            Self::GcStaleTime(_) => NDTPA_GC_STALETIME,
+            Self::DelayProbeTime(_) => NDTPA_DELAY_PROBE_TIME,
+            Self::QueueLen(_) => NDTPA_QUEUE_LEN,
+            Self::AppProbes(_) => NDTPA_APP_PROBES,
+            Self::UcastProbes(_) => NDTPA_UCAST_PROBES,
+            Self::McastProbes(_) => NDTPA_MCAST_PROBES,
+            Self::AnycastDelay(_) => NDTPA_ANYCAST_DELAY,
+            Self::ProxyDelay(_) => NDTPA_PROXY_DELAY,
+            Self::ProxyQueueLen(_) => NDTPA_PROXY_QLEN,
+            Self::Locktime(_) => NDTPA_LOCKTIME,
+            Self::QueueLenBytes(_) => NDTPA_QUEUE_LENBYTES,
+            Self::McastReprobes(_) => NDTPA_MCAST_REPROBES,
+            Self::IntervalProbeTimeMs(_) => NDTPA_INTERVAL_PROBE_TIME_MS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NeighbourTableParameter
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NDTPA_IFINDEX => Self::IfIndex(parse_u32(payload)?),
+            NDTPA_REFCNT => Self::ReferenceCount(parse_u32(payload)?),
+            NDTPA_REACHABLE_TIME => Self::ReachableTime(parse_u64(payload)?),
+            NDTPA_BASE_REACHABLE_TIME => {
+                Self::BaseReachableTime(parse_u64(payload)?)
+            }
+            NDTPA_RETRANS_TIME => Self::RetransTime(parse_u64(payload)?),
+            NDTPA_GC_STALETIME => Self::GcStaleTime(parse_u64(payload)?),
+            NDTPA_DELAY_PROBE_TIME => Self::DelayProbeTime(parse_u64(payload)?),
+            NDTPA_QUEUE_LEN => Self::QueueLen(parse_u32(payload)?),
+            NDTPA_APP_PROBES => Self::AppProbes(parse_u32(payload)?),
+            NDTPA_UCAST_PROBES => Self::UcastProbes(parse_u32(payload)?),
+            NDTPA_MCAST_PROBES => Self::McastProbes(parse_u32(payload)?),
+            NDTPA_ANYCAST_DELAY => Self::AnycastDelay(parse_u64(payload)?),
+            NDTPA_PROXY_DELAY => Self::ProxyDelay(parse_u64(payload)?),
+            NDTPA_PROXY_QLEN => Self::ProxyQueueLen(parse_u32(payload)?),
+            NDTPA_LOCKTIME => Self::Locktime(parse_u64(payload)?),
+            NDTPA_QUEUE_LENBYTES => Self::QueueLenBytes(parse_u32(payload)?),
+            NDTPA_MCAST_REPROBES => Self::McastReprobes(parse_u32(payload)?),
+            NDTPA_INTERVAL_PROBE_TIME_MS => {
+                Self::IntervalProbeTimeMs(parse_u64(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
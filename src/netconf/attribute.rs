@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_i32,
+    traits::Parseable,
+    DecodeError,
+};
+
+const NETCONFA_IFINDEX: u16 = 1;
+const NETCONFA_FORWARDING: u16 = 2;
+const NETCONFA_RP_FILTER: u16 = 3;
+const NETCONFA_MC_FORWARDING: u16 = 4;
+const NETCONFA_PROXY_NEIGH: u16 = 5;
+const NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN: u16 = 6;
+const NETCONFA_INPUT: u16 = 7;
+const NETCONFA_BC_FORWARDING: u16 = 8;
+
+/// Per-interface IP configuration, the values mirror the sysctl
+/// files under `/proc/sys/net/ipv{4,6}/conf/<interface>/`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NetconfAttribute {
+    /// Interface index, or [Self::IFINDEX_ALL] and [Self::IFINDEX_DEFAULT]
+    /// for the `all` and `default` configurations.
+    IfIndex(i32),
+    Forwarding(i32),
+    /// Reverse path filter, 0 for none, 1 for strict mode and 2 for loose
+    /// mode.
+    RpFilter(i32),
+    McForwarding(i32),
+    ProxyNeigh(i32),
+    IgnoreRoutesWithLinkdown(i32),
+    /// Whether the interface accepts incoming packets, `AF_MPLS` only
+    Input(i32),
+    BcForwarding(i32),
+    Other(DefaultNla),
+}
+
+impl NetconfAttribute {
+    /// Kernel constant `NETCONFA_IFINDEX_ALL`
+    pub const IFINDEX_ALL: i32 = -1;
+    /// Kernel constant `NETCONFA_IFINDEX_DEFAULT`
+    pub const IFINDEX_DEFAULT: i32 = -2;
+}
+
+impl Nla for NetconfAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IfIndex(_)
+            | Self::Forwarding(_)
+            | Self::RpFilter(_)
+            | Self::McForwarding(_)
+            | Self::ProxyNeigh(_)
+            | Self::IgnoreRoutesWithLinkdown(_)
+            | Self::Input(_)
+            | Self::BcForwarding(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IfIndex(v)
+            | Self::Forwarding(v)
+            | Self::RpFilter(v)
+            | Self::McForwarding(v)
+            | Self::ProxyNeigh(v)
+            | Self::IgnoreRoutesWithLinkdown(v)
+            | Self::Input(v)
+            | Self::BcForwarding(v) => NativeEndian::write_i32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IfIndex(_) => NETCONFA_IFINDEX,
+            Self::Forwarding(_) => NETCONFA_FORWARDING,
+            Self::RpFilter(_) => NETCONFA_RP_FILTER,
+            Self::McForwarding(_) => NETCONFA_MC_FORWARDING,
+            Self::ProxyNeigh(_) => NETCONFA_PROXY_NEIGH,
+            Self::IgnoreRoutesWithLinkdown(_) => {
+                NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN
+            }
+            Self::Input(_) => NETCONFA_INPUT,
+            Self::BcForwarding(_) => NETCONFA_BC_FORWARDING,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NetconfAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NETCONFA_IFINDEX => Self::IfIndex(parse_i32(payload)?),
+            NETCONFA_FORWARDING => Self::Forwarding(parse_i32(payload)?),
+            NETCONFA_RP_FILTER => Self::RpFilter(parse_i32(payload)?),
+            NETCONFA_MC_FORWARDING => Self::McForwarding(parse_i32(payload)?),
+            NETCONFA_PROXY_NEIGH => Self::ProxyNeigh(parse_i32(payload)?),
+            NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN => {
+                Self::IgnoreRoutesWithLinkdown(parse_i32(payload)?)
+            }
+            NETCONFA_INPUT => Self::Input(parse_i32(payload)?),
+            NETCONFA_BC_FORWARDING => Self::BcForwarding(parse_i32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
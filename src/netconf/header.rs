@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+// The `struct netconfmsg` holds a single byte, but netlink aligns the
+// payload to 4 bytes.
+const NETCONF_HEADER_LEN: usize = 4;
+
+buffer!(NetconfMessageBuffer(NETCONF_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..NETCONF_HEADER_LEN),
+    payload: (slice, NETCONF_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NetconfMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_GETNETCONF`, `RTM_NEWNETCONF` and
+/// `RTM_DELNETCONF` messages headers(`struct netconfmsg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NetconfHeader {
+    /// Address family of the configuration, `AF_INET`, `AF_INET6` or
+    /// `AF_MPLS`.
+    pub family: AddressFamily,
+}
+
+impl Emitable for NetconfHeader {
+    fn buffer_len(&self) -> usize {
+        NETCONF_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NetconfMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NetconfMessageBuffer<T>> for NetconfHeader {
+    fn parse(buf: &NetconfMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NetconfHeader {
+            family: buf.family().into(),
+        })
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::netconf::{NetconfAttribute, NetconfHeader, NetconfMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NetconfMessage {
+    pub header: NetconfHeader,
+    pub attributes: Vec<NetconfAttribute>,
+}
+
+impl Emitable for NetconfMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NetconfMessageBuffer<&'a T>>
+    for NetconfMessage
+{
+    fn parse(buf: &NetconfMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = NetconfHeader::parse(buf)?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(NetconfAttribute::parse(&nla_buf?)?);
+        }
+        Ok(NetconfMessage { header, attributes })
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+
+pub use self::attribute::NetconfAttribute;
+pub use self::header::{NetconfHeader, NetconfMessageBuffer};
+pub use self::message::NetconfMessage;
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::IpAddr;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::{
+    ip::{emit_ip_addr, ip_addr_len, parse_ip_addr},
+    lwtunnel::{LwtunnelEncap, LwtunnelEncapType},
+    nexthop::{
+        group::NEXTHOP_GROUP_ENTRY_LEN, NexthopGroupEntry,
+        NexthopGroupEntryBuffer, NexthopGroupType, NexthopResBucketAttribute,
+        NexthopResGroupAttribute,
+    },
+};
+
+const NHA_ID: u16 = 1;
+const NHA_GROUP: u16 = 2;
+const NHA_GROUP_TYPE: u16 = 3;
+const NHA_BLACKHOLE: u16 = 4;
+const NHA_OIF: u16 = 5;
+const NHA_GATEWAY: u16 = 6;
+const NHA_ENCAP_TYPE: u16 = 7;
+const NHA_ENCAP: u16 = 8;
+const NHA_GROUPS: u16 = 9;
+const NHA_MASTER: u16 = 10;
+const NHA_FDB: u16 = 11;
+const NHA_RES_GROUP: u16 = 12;
+const NHA_RES_BUCKET: u16 = 13;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NexthopAttribute {
+    /// Nexthop ID, also used as dump filter of `RTM_GETNEXTHOPBUCKET`.
+    Id(u32),
+    /// Members of the nexthop group
+    Group(Vec<NexthopGroupEntry>),
+    GroupType(NexthopGroupType),
+    /// Blackhole nexthop, no [Self::Oif], [Self::Gateway] or
+    /// [Self::Encap] allowed.
+    Blackhole,
+    /// Output interface index, also used as dump filter.
+    Oif(u32),
+    Gateway(IpAddr),
+    EncapType(LwtunnelEncapType),
+    /// Lightweight tunnel encapsulation, the layout depends on
+    /// [Self::EncapType].
+    Encap(LwtunnelEncap),
+    /// Dump filter, only dump nexthop groups.
+    Groups,
+    /// Dump filter, only dump nexthops using devices enslaved to the
+    /// specified controller interface index.
+    Master(u32),
+    /// Nexthop used by FDB entries of VXLAN, also used as dump filter.
+    Fdb,
+    /// Resilient nexthop group properties
+    ResGroup(Vec<NexthopResGroupAttribute>),
+    /// Resilient nexthop group bucket of `RTM_NEWNEXTHOPBUCKET`
+    ResBucket(Vec<NexthopResBucketAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Blackhole | Self::Groups | Self::Fdb => 0,
+            Self::GroupType(_) | Self::EncapType(_) => 2,
+            Self::Id(_) | Self::Oif(_) | Self::Master(_) => 4,
+            Self::Group(entries) => entries.len() * NEXTHOP_GROUP_ENTRY_LEN,
+            Self::Gateway(addr) => ip_addr_len(addr),
+            Self::Encap(v) => v.buffer_len(),
+            Self::ResGroup(attrs) => attrs.as_slice().buffer_len(),
+            Self::ResBucket(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Blackhole | Self::Groups | Self::Fdb => (),
+            Self::GroupType(v) => NativeEndian::write_u16(buffer, (*v).into()),
+            Self::EncapType(v) => NativeEndian::write_u16(buffer, (*v).into()),
+            Self::Id(v) | Self::Oif(v) | Self::Master(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Group(entries) => {
+                for (i, entry) in entries.iter().enumerate() {
+                    entry.emit(
+                        &mut buffer[i * NEXTHOP_GROUP_ENTRY_LEN
+                            ..(i + 1) * NEXTHOP_GROUP_ENTRY_LEN],
+                    );
+                }
+            }
+            Self::Gateway(addr) => emit_ip_addr(addr, buffer),
+            Self::Encap(v) => v.emit(buffer),
+            Self::ResGroup(attrs) => attrs.as_slice().emit(buffer),
+            Self::ResBucket(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Id(_) => NHA_ID,
+            Self::Group(_) => NHA_GROUP,
+            Self::GroupType(_) => NHA_GROUP_TYPE,
+            Self::Blackhole => NHA_BLACKHOLE,
+            Self::Oif(_) => NHA_OIF,
+            Self::Gateway(_) => NHA_GATEWAY,
+            Self::EncapType(_) => NHA_ENCAP_TYPE,
+            Self::Encap(_) => NHA_ENCAP | NLA_F_NESTED,
+            Self::Groups => NHA_GROUPS,
+            Self::Master(_) => NHA_MASTER,
+            Self::Fdb => NHA_FDB,
+            Self::ResGroup(_) => NHA_RES_GROUP | NLA_F_NESTED,
+            Self::ResBucket(_) => NHA_RES_BUCKET | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl NexthopAttribute {
+    // The `NHA_ENCAP` is parsed according to `NHA_ENCAP_TYPE`, which might
+    // be placed after it.
+    pub(crate) fn find_encap_type<'a>(
+        nlas: impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>>,
+    ) -> Result<LwtunnelEncapType, DecodeError> {
+        for nla in nlas {
+            let nla = nla?;
+            if nla.kind() == NHA_ENCAP_TYPE {
+                return Ok(parse_u16(nla.value())?.into());
+            }
+        }
+        Ok(LwtunnelEncapType::None)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, LwtunnelEncapType>
+    for NexthopAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        encap_type: LwtunnelEncapType,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_ID => Self::Id(parse_u32(payload)?),
+            NHA_GROUP => {
+                let mut entries = Vec::new();
+                for chunk in payload.chunks(NEXTHOP_GROUP_ENTRY_LEN) {
+                    entries.push(NexthopGroupEntry::parse(
+                        &NexthopGroupEntryBuffer::new_checked(chunk)?,
+                    )?);
+                }
+                Self::Group(entries)
+            }
+            NHA_GROUP_TYPE => Self::GroupType(parse_u16(payload)?.into()),
+            NHA_BLACKHOLE => Self::Blackhole,
+            NHA_OIF => Self::Oif(parse_u32(payload)?),
+            NHA_GATEWAY => Self::Gateway(parse_ip_addr(payload)?),
+            NHA_ENCAP_TYPE => Self::EncapType(parse_u16(payload)?.into()),
+            NHA_ENCAP => Self::Encap(LwtunnelEncap::parse_with_param(
+                payload, encap_type,
+            )?),
+            NHA_GROUPS => Self::Groups,
+            NHA_MASTER => Self::Master(parse_u32(payload)?),
+            NHA_FDB => Self::Fdb,
+            NHA_RES_GROUP => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(NexthopResGroupAttribute::parse(&nla?)?);
+                }
+                Self::ResGroup(attrs)
+            }
+            NHA_RES_BUCKET => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(NexthopResBucketAttribute::parse(&nla?)?);
+                }
+                Self::ResBucket(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+// Without the encapsulation type, `NHA_ENCAP` is parsed as
+// [LwtunnelEncap::Other].
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Self::parse_with_param(buf, LwtunnelEncapType::None)
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub(crate) const NEXTHOP_GROUP_ENTRY_LEN: usize = 8;
+
+buffer!(NexthopGroupEntryBuffer(NEXTHOP_GROUP_ENTRY_LEN) {
+    id: (u32, 0..4),
+    weight: (u8, 4),
+    weight_high: (u8, 5),
+    resvd2: (u16, 6..NEXTHOP_GROUP_ENTRY_LEN),
+});
+
+/// Member of `NHA_GROUP`(`struct nexthop_grp`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct NexthopGroupEntry {
+    /// Nexthop ID of the group member
+    pub id: u32,
+    /// Weight of the member minus one, the lower 8 bits.
+    pub weight: u8,
+    /// Weight of the member minus one, the higher 8 bits. Ignored by
+    /// kernels older than 6.12.
+    pub weight_high: u8,
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopGroupEntryBuffer<T>>
+    for NexthopGroupEntry
+{
+    fn parse(buf: &NexthopGroupEntryBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            id: buf.id(),
+            weight: buf.weight(),
+            weight_high: buf.weight_high(),
+        })
+    }
+}
+
+impl Emitable for NexthopGroupEntry {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_GROUP_ENTRY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NexthopGroupEntryBuffer::new(buffer);
+        buffer.set_id(self.id);
+        buffer.set_weight(self.weight);
+        buffer.set_weight_high(self.weight_high);
+        buffer.set_resvd2(0);
+    }
+}
+
+const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum NexthopGroupType {
+    /// Hash-threshold multipath group
+    #[default]
+    Mpath,
+    /// Resilient hashing group
+    Resilient,
+    Other(u16),
+}
+
+impl From<u16> for NexthopGroupType {
+    fn from(d: u16) -> Self {
+        match d {
+            NEXTHOP_GRP_TYPE_MPATH => Self::Mpath,
+            NEXTHOP_GRP_TYPE_RES => Self::Resilient,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<NexthopGroupType> for u16 {
+    fn from(v: NexthopGroupType) -> u16 {
+        match v {
+            NexthopGroupType::Mpath => NEXTHOP_GRP_TYPE_MPATH,
+            NexthopGroupType::Resilient => NEXTHOP_GRP_TYPE_RES,
+            NexthopGroupType::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::nexthop::{NexthopAttribute, NexthopHeader, NexthopMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NexthopMessage {
+    pub header: NexthopHeader,
+    pub attributes: Vec<NexthopAttribute>,
+}
+
+impl Emitable for NexthopMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NexthopMessageBuffer<&'a T>>
+    for NexthopMessage
+{
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = NexthopHeader::parse(buf)?;
+        let encap_type = NexthopAttribute::find_encap_type(buf.attributes())?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(NexthopAttribute::parse_with_param(
+                &nla_buf?, encap_type,
+            )?);
+        }
+        Ok(NexthopMessage { header, attributes })
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod flags;
+mod group;
+mod header;
+mod message;
+mod resilient;
+
+pub use self::attribute::NexthopAttribute;
+pub use self::flags::NexthopFlags;
+pub use self::group::{
+    NexthopGroupEntry, NexthopGroupEntryBuffer, NexthopGroupType,
+};
+pub use self::header::{NexthopHeader, NexthopMessageBuffer};
+pub use self::message::NexthopMessage;
+pub use self::resilient::{
+    NexthopResBucketAttribute, NexthopResGroupAttribute,
+};
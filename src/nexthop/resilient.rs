@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+// const NHA_RES_GROUP_PAD: u16 = 0;
+const NHA_RES_GROUP_BUCKETS: u16 = 1;
+const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+/// Member of `NHA_RES_GROUP`. The time values are in clock ticks
+/// (`USER_HZ`, normally centiseconds).
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NexthopResGroupAttribute {
+    /// Number of buckets in the resilient group
+    Buckets(u16),
+    /// Time after which an idle bucket can be migrated to a new nexthop
+    IdleTimer(u32),
+    /// Maximum time the group can stay unbalanced
+    UnbalancedTimer(u32),
+    /// Time the group has been unbalanced, only in replies.
+    UnbalancedTime(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopResGroupAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Buckets(_) => 2,
+            Self::IdleTimer(_) | Self::UnbalancedTimer(_) => 4,
+            Self::UnbalancedTime(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Buckets(v) => NativeEndian::write_u16(buffer, *v),
+            Self::IdleTimer(v) | Self::UnbalancedTimer(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::UnbalancedTime(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Buckets(_) => NHA_RES_GROUP_BUCKETS,
+            Self::IdleTimer(_) => NHA_RES_GROUP_IDLE_TIMER,
+            Self::UnbalancedTimer(_) => NHA_RES_GROUP_UNBALANCED_TIMER,
+            Self::UnbalancedTime(_) => NHA_RES_GROUP_UNBALANCED_TIME,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopResGroupAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_GROUP_BUCKETS => Self::Buckets(parse_u16(payload)?),
+            NHA_RES_GROUP_IDLE_TIMER => Self::IdleTimer(parse_u32(payload)?),
+            NHA_RES_GROUP_UNBALANCED_TIMER => {
+                Self::UnbalancedTimer(parse_u32(payload)?)
+            }
+            NHA_RES_GROUP_UNBALANCED_TIME => {
+                Self::UnbalancedTime(parse_u64(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+// const NHA_RES_BUCKET_PAD: u16 = 0;
+const NHA_RES_BUCKET_INDEX: u16 = 1;
+const NHA_RES_BUCKET_IDLE_TIME: u16 = 2;
+const NHA_RES_BUCKET_NH_ID: u16 = 3;
+
+/// Member of `NHA_RES_BUCKET`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum NexthopResBucketAttribute {
+    Index(u16),
+    /// Time the bucket has been idle in clock ticks
+    IdleTime(u64),
+    /// Nexthop ID the bucket is assigned to
+    NexthopId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for NexthopResBucketAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Index(_) => 2,
+            Self::NexthopId(_) => 4,
+            Self::IdleTime(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Index(v) => NativeEndian::write_u16(buffer, *v),
+            Self::NexthopId(v) => NativeEndian::write_u32(buffer, *v),
+            Self::IdleTime(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Index(_) => NHA_RES_BUCKET_INDEX,
+            Self::IdleTime(_) => NHA_RES_BUCKET_IDLE_TIME,
+            Self::NexthopId(_) => NHA_RES_BUCKET_NH_ID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for NexthopResBucketAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_BUCKET_INDEX => Self::Index(parse_u16(payload)?),
+            NHA_RES_BUCKET_IDLE_TIME => Self::IdleTime(parse_u64(payload)?),
+            NHA_RES_BUCKET_NH_ID => Self::NexthopId(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+// The `struct rtgenmsg` holds a single byte, but netlink aligns the
+// payload to 4 bytes.
+const NSID_HEADER_LEN: usize = 4;
+
+buffer!(NsidMessageBuffer(NSID_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..NSID_HEADER_LEN),
+    payload: (slice, NSID_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NsidMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_GETNSID`, `RTM_NEWNSID` and
+/// `RTM_DELNSID` messages headers(`struct rtgenmsg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct NsidHeader {
+    /// Address family, kernel always uses `AF_UNSPEC`.
+    pub family: AddressFamily,
+}
+
+impl Emitable for NsidHeader {
+    fn buffer_len(&self) -> usize {
+        NSID_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NsidMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NsidMessageBuffer<T>> for NsidHeader {
+    fn parse(buf: &NsidMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NsidHeader {
+            family: buf.family().into(),
+        })
+    }
+}
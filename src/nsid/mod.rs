@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+
+pub use self::attribute::NsidAttribute;
+pub use self::header::{NsidHeader, NsidMessageBuffer};
+pub use self::message::NsidMessage;
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::Ipv6Addr;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    ip::parse_ipv6_addr,
+    prefix::{PrefixCacheInfo, PrefixCacheInfoBuffer},
+};
+
+const PREFIX_ADDRESS: u16 = 1;
+const PREFIX_CACHEINFO: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum PrefixAttribute {
+    Address(Ipv6Addr),
+    CacheInfo(PrefixCacheInfo),
+    Other(DefaultNla),
+}
+
+impl Nla for PrefixAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Address(_) => 16,
+            Self::CacheInfo(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Address(v) => buffer.copy_from_slice(&v.octets()),
+            Self::CacheInfo(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Address(_) => PREFIX_ADDRESS,
+            Self::CacheInfo(_) => PREFIX_CACHEINFO,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for PrefixAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            PREFIX_ADDRESS => Self::Address(parse_ipv6_addr(payload)?),
+            PREFIX_CACHEINFO => Self::CacheInfo(PrefixCacheInfo::parse(
+                &PrefixCacheInfoBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
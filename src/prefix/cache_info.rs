@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Lifetimes of a prefix(`struct prefix_cacheinfo`) in seconds
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct PrefixCacheInfo {
+    pub preferred_time: u32,
+    pub valid_time: u32,
+}
+
+const PREFIX_CACHE_INFO_LEN: usize = 8;
+
+buffer!(PrefixCacheInfoBuffer(PREFIX_CACHE_INFO_LEN) {
+    preferred_time: (u32, 0..4),
+    valid_time: (u32, 4..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<PrefixCacheInfoBuffer<T>> for PrefixCacheInfo {
+    fn parse(buf: &PrefixCacheInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            preferred_time: buf.preferred_time(),
+            valid_time: buf.valid_time(),
+        })
+    }
+}
+
+impl Emitable for PrefixCacheInfo {
+    fn buffer_len(&self) -> usize {
+        PREFIX_CACHE_INFO_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = PrefixCacheInfoBuffer::new(buffer);
+        buffer.set_preferred_time(self.preferred_time);
+        buffer.set_valid_time(self.valid_time);
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+const IF_PREFIX_ONLINK: u8 = 0x01;
+const IF_PREFIX_AUTOCONF: u8 = 0x02;
+
+bitflags! {
+    /// Flags of the prefix information option in router advertisement
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct PrefixFlags: u8 {
+        /// The prefix can be used for on-link determination
+        const OnLink = IF_PREFIX_ONLINK;
+        /// The prefix can be used for stateless address autoconfiguration
+        const Autoconf = IF_PREFIX_AUTOCONF;
+        const _ = !0;
+    }
+}
+
+impl Default for PrefixFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
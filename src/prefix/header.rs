@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{prefix::PrefixFlags, AddressFamily};
+
+const PREFIX_HEADER_LEN: usize = 12;
+
+buffer!(PrefixMessageBuffer(PREFIX_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    ifindex: (i32, 4..8),
+    prefix_type: (u8, 8),
+    prefix_len: (u8, 9),
+    flags: (u8, 10),
+    pad3: (u8, 11),
+    payload: (slice, PREFIX_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> PrefixMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_NEWPREFIX` message header
+/// (`struct prefixmsg`), sent by kernel when an IPv6 router advertisement
+/// carries a prefix information option.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct PrefixHeader {
+    /// Address family, always `AF_INET6`.
+    pub family: AddressFamily,
+    /// Interface index the router advertisement was received on.
+    pub ifindex: i32,
+    /// Type of the neighbour discovery option, 3 for prefix information.
+    pub prefix_type: u8,
+    /// Prefix length.
+    pub prefix_len: u8,
+    pub flags: PrefixFlags,
+}
+
+impl Emitable for PrefixHeader {
+    fn buffer_len(&self) -> usize {
+        PREFIX_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = PrefixMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_prefix_type(self.prefix_type);
+        packet.set_prefix_len(self.prefix_len);
+        packet.set_flags(self.flags.bits());
+        packet.set_pad3(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<PrefixMessageBuffer<T>> for PrefixHeader {
+    fn parse(buf: &PrefixMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(PrefixHeader {
+            family: buf.family().into(),
+            ifindex: buf.ifindex(),
+            prefix_type: buf.prefix_type(),
+            prefix_len: buf.prefix_len(),
+            flags: PrefixFlags::from_bits_retain(buf.flags()),
+        })
+    }
+}
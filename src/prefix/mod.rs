@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod cache_info;
+mod flags;
+mod header;
+mod message;
+
+pub use self::attribute::PrefixAttribute;
+pub use self::cache_info::{PrefixCacheInfo, PrefixCacheInfoBuffer};
+pub use self::flags::PrefixFlags;
+pub use self::header::{PrefixHeader, PrefixMessageBuffer};
+pub use self::message::PrefixMessage;
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use alloc::vec::Vec;
+use axerrno::AxError;
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use crate::ip::{
+    parse_ipv4_addr, parse_ipv6_addr, IPV4_ADDR_LEN, IPV6_ADDR_LEN,
+};
+use crate::lwtunnel::{
+    emit_mpls_labels, parse_mpls_labels, MplsLabel, MPLS_LABEL_LEN,
+};
+use crate::AddressFamily;
+
+/// Address carried by `RTA_DST`, `RTA_SRC`, `RTA_GATEWAY` and
+/// `RTA_PREFSRC`. The layout depends on the address family of the route.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteAddress {
+    Inet(Ipv4Addr),
+    Inet6(Ipv6Addr),
+    /// Incoming label of MPLS route, only used by `RTA_DST`.
+    Mpls(MplsLabel),
+    Other(Vec<u8>),
+}
+
+impl RouteAddress {
+    pub(crate) fn parse(
+        address_family: AddressFamily,
+        payload: &[u8],
+    ) -> Result<Self, DecodeError> {
+        Ok(match address_family {
+            AddressFamily::Inet | AddressFamily::Ipmr => {
+                Self::Inet(parse_ipv4_addr(payload)?)
+            }
+            AddressFamily::Inet6 | AddressFamily::Ip6mr => {
+                Self::Inet6(parse_ipv6_addr(payload)?)
+            }
+            AddressFamily::Mpls => {
+                let labels = parse_mpls_labels(payload)?;
+                if labels.len() != 1 {
+                    return Err(AxError::InvalidInput);
+                }
+                Self::Mpls(labels[0])
+            }
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
+
+impl Emitable for RouteAddress {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Inet(_) => IPV4_ADDR_LEN,
+            Self::Inet6(_) => IPV6_ADDR_LEN,
+            Self::Mpls(_) => MPLS_LABEL_LEN,
+            Self::Other(v) => v.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Inet(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Inet6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Mpls(v) => emit_mpls_labels(&[*v], buffer),
+            Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
+        }
+    }
+}
+
+impl From<Ipv4Addr> for RouteAddress {
+    fn from(v: Ipv4Addr) -> Self {
+        Self::Inet(v)
+    }
+}
+
+impl From<Ipv6Addr> for RouteAddress {
+    fn from(v: Ipv6Addr) -> Self {
+        Self::Inet6(v)
+    }
+}
+
+impl From<MplsLabel> for RouteAddress {
+    fn from(v: MplsLabel) -> Self {
+        Self::Mpls(v)
+    }
+}
+
+impl From<IpAddr> for RouteAddress {
+    fn from(v: IpAddr) -> Self {
+        match v {
+            IpAddr::V4(v4) => Self::Inet(v4),
+            IpAddr::V6(v6) => Self::Inet6(v6),
+        }
+    }
+}
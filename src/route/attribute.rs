@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::{
+    lwtunnel::{
+        emit_mpls_labels, mpls_labels_len, parse_mpls_labels, LwtunnelEncap,
+        LwtunnelEncapType, MplsLabel,
+    },
+    route::{
+        RouteAddress, RouteMetric, RouteMfcStats, RouteMfcStatsBuffer,
+        RouteNextHop, RouteNextHopBuffer, RoutePreference, RouteVia,
+        RouteViaBuffer,
+    },
+    AddressFamily,
+};
+
+const RTA_DST: u16 = 1;
+const RTA_SRC: u16 = 2;
+const RTA_IIF: u16 = 3;
+const RTA_OIF: u16 = 4;
+const RTA_GATEWAY: u16 = 5;
+const RTA_PRIORITY: u16 = 6;
+const RTA_PREFSRC: u16 = 7;
+const RTA_METRICS: u16 = 8;
+const RTA_MULTIPATH: u16 = 9;
+// const RTA_PROTOINFO: u16 = 10; // linux kernel has no actual user
+// const RTA_FLOW: u16 = 11;
+// const RTA_CACHEINFO: u16 = 12;
+// const RTA_SESSION: u16 = 13; // linux kernel has no actual user
+// const RTA_MP_ALGO: u16 = 14; // linux kernel has no actual user
+const RTA_TABLE: u16 = 15;
+const RTA_MARK: u16 = 16;
+const RTA_MFC_STATS: u16 = 17;
+const RTA_VIA: u16 = 18;
+const RTA_NEWDST: u16 = 19;
+const RTA_PREF: u16 = 20;
+const RTA_ENCAP_TYPE: u16 = 21;
+const RTA_ENCAP: u16 = 22;
+const RTA_EXPIRES: u16 = 23;
+// const RTA_PAD: u16 = 24;
+const RTA_UID: u16 = 25;
+const RTA_TTL_PROPAGATE: u16 = 26;
+// const RTA_IP_PROTO: u16 = 27;
+// const RTA_SPORT: u16 = 28;
+// const RTA_DPORT: u16 = 29;
+// const RTA_NH_ID: u16 = 30;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteAttribute {
+    Metrics(Vec<RouteMetric>),
+    MultiPath(Vec<RouteNextHop>),
+    Destination(RouteAddress),
+    Source(RouteAddress),
+    Gateway(RouteAddress),
+    PrefSource(RouteAddress),
+    Via(RouteVia),
+    /// Label stack to swap the incoming label with, MPLS route only. Empty
+    /// means popping the label.
+    NewDestination(Vec<MplsLabel>),
+    /// Whether the TTL of the popped label is propagated to the IP header,
+    /// MPLS route only. The `net.mpls.ip_ttl_propagate` sysctl is followed
+    /// when absent.
+    TtlPropagate(bool),
+    Preference(RoutePreference),
+    EncapType(LwtunnelEncapType),
+    /// Lightweight tunnel encapsulation, the layout depends on
+    /// [Self::EncapType].
+    Encap(LwtunnelEncap),
+    /// Expire time of the route in seconds.
+    Expires(u32),
+    /// Time since the multicast forwarding cache entry was last used, in
+    /// clock ticks(`USER_HZ`). Kernel constant name is `RTA_EXPIRES`, only
+    /// used by [AddressFamily::Ipmr] and [AddressFamily::Ip6mr] routes.
+    MulticastExpires(u64),
+    /// Statistics of multicast forwarding cache entry.
+    MfcStats(RouteMfcStats),
+    Uid(u32),
+    Iif(u32),
+    Oif(u32),
+    Priority(u32),
+    /// Full routing table ID, used when the ID does not fit into
+    /// [super::RouteHeader::table].
+    Table(u32),
+    Mark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Destination(addr)
+            | Self::PrefSource(addr)
+            | Self::Gateway(addr)
+            | Self::Source(addr) => addr.buffer_len(),
+            Self::Via(v) => v.buffer_len(),
+            Self::NewDestination(v) => mpls_labels_len(v),
+            Self::Preference(_) | Self::TtlPropagate(_) => 1,
+            Self::EncapType(_) => 2,
+            Self::Encap(v) => v.buffer_len(),
+            Self::MulticastExpires(_) => 8,
+            Self::MfcStats(v) => v.buffer_len(),
+            Self::Expires(_)
+            | Self::Uid(_)
+            | Self::Iif(_)
+            | Self::Oif(_)
+            | Self::Priority(_)
+            | Self::Table(_)
+            | Self::Mark(_) => 4,
+            Self::Metrics(attrs) => attrs.as_slice().buffer_len(),
+            Self::MultiPath(next_hops) => {
+                next_hops.iter().map(|nh| nh.buffer_len()).sum()
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Destination(addr)
+            | Self::PrefSource(addr)
+            | Self::Source(addr)
+            | Self::Gateway(addr) => addr.emit(buffer),
+            Self::Via(v) => v.emit(buffer),
+            Self::NewDestination(v) => emit_mpls_labels(v, buffer),
+            Self::TtlPropagate(v) => buffer[0] = (*v).into(),
+            Self::Preference(p) => buffer[0] = (*p).into(),
+            Self::EncapType(v) => NativeEndian::write_u16(buffer, (*v).into()),
+            Self::Encap(v) => v.emit(buffer),
+            Self::MulticastExpires(v) => NativeEndian::write_u64(buffer, *v),
+            Self::MfcStats(v) => v.emit(buffer),
+            Self::Expires(value)
+            | Self::Uid(value)
+            | Self::Iif(value)
+            | Self::Oif(value)
+            | Self::Priority(value)
+            | Self::Table(value)
+            | Self::Mark(value) => NativeEndian::write_u32(buffer, *value),
+            Self::Metrics(attrs) => attrs.as_slice().emit(buffer),
+            Self::MultiPath(next_hops) => {
+                let mut offset = 0;
+                for nh in next_hops {
+                    let len = nh.buffer_len();
+                    nh.emit(&mut buffer[offset..offset + len]);
+                    offset += len
+                }
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Destination(_) => RTA_DST,
+            Self::Source(_) => RTA_SRC,
+            Self::Iif(_) => RTA_IIF,
+            Self::Oif(_) => RTA_OIF,
+            Self::Gateway(_) => RTA_GATEWAY,
+            Self::Priority(_) => RTA_PRIORITY,
+            Self::PrefSource(_) => RTA_PREFSRC,
+            Self::Metrics(_) => RTA_METRICS,
+            Self::MultiPath(_) => RTA_MULTIPATH,
+            Self::Table(_) => RTA_TABLE,
+            Self::Mark(_) => RTA_MARK,
+            Self::MfcStats(_) => RTA_MFC_STATS,
+            Self::Via(_) => RTA_VIA,
+            Self::NewDestination(_) => RTA_NEWDST,
+            Self::Preference(_) => RTA_PREF,
+            Self::EncapType(_) => RTA_ENCAP_TYPE,
+            Self::Encap(_) => RTA_ENCAP | NLA_F_NESTED,
+            Self::Expires(_) | Self::MulticastExpires(_) => RTA_EXPIRES,
+            Self::Uid(_) => RTA_UID,
+            Self::TtlPropagate(_) => RTA_TTL_PROPAGATE,
+            Self::Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl RouteAttribute {
+    // The `RTA_ENCAP` is parsed according to `RTA_ENCAP_TYPE`, which might
+    // be placed after it.
+    pub(crate) fn find_encap_type<'a>(
+        nlas: impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>>,
+    ) -> Result<LwtunnelEncapType, DecodeError> {
+        for nla in nlas {
+            let nla = nla?;
+            if nla.kind() == RTA_ENCAP_TYPE {
+                return Ok(parse_u16(nla.value())?.into());
+            }
+        }
+        Ok(LwtunnelEncapType::None)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, (AddressFamily, LwtunnelEncapType)>
+    for RouteAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        (address_family, encap_type): (AddressFamily, LwtunnelEncapType),
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            RTA_DST => {
+                Self::Destination(RouteAddress::parse(address_family, payload)?)
+            }
+            RTA_SRC => {
+                Self::Source(RouteAddress::parse(address_family, payload)?)
+            }
+            RTA_GATEWAY => {
+                Self::Gateway(RouteAddress::parse(address_family, payload)?)
+            }
+            RTA_PREFSRC => {
+                Self::PrefSource(RouteAddress::parse(address_family, payload)?)
+            }
+            RTA_VIA => Self::Via(RouteVia::parse(
+                &RouteViaBuffer::new_checked(payload)?,
+            )?),
+            RTA_NEWDST => Self::NewDestination(parse_mpls_labels(payload)?),
+            RTA_TTL_PROPAGATE => Self::TtlPropagate(parse_u8(payload)? > 0),
+            RTA_PREF => Self::Preference(parse_u8(payload)?.into()),
+            RTA_ENCAP_TYPE => Self::EncapType(parse_u16(payload)?.into()),
+            RTA_ENCAP => Self::Encap(LwtunnelEncap::parse_with_param(
+                payload, encap_type,
+            )?),
+            RTA_EXPIRES
+                if matches!(
+                    address_family,
+                    AddressFamily::Ipmr | AddressFamily::Ip6mr
+                ) =>
+            {
+                Self::MulticastExpires(parse_u64(payload)?)
+            }
+            RTA_EXPIRES => Self::Expires(parse_u32(payload)?),
+            RTA_MFC_STATS => Self::MfcStats(RouteMfcStats::parse(
+                &RouteMfcStatsBuffer::new_checked(payload)?,
+            )?),
+            RTA_UID => Self::Uid(parse_u32(payload)?),
+            RTA_IIF => Self::Iif(parse_u32(payload)?),
+            RTA_OIF => Self::Oif(parse_u32(payload)?),
+            RTA_PRIORITY => Self::Priority(parse_u32(payload)?),
+            RTA_TABLE => Self::Table(parse_u32(payload)?),
+            RTA_MARK => Self::Mark(parse_u32(payload)?),
+            RTA_METRICS => {
+                let mut metrics = vec![];
+                for nla in NlasIterator::new(payload) {
+                    metrics.push(RouteMetric::parse(&nla?)?);
+                }
+                Self::Metrics(metrics)
+            }
+            RTA_MULTIPATH => {
+                let mut next_hops = vec![];
+                let mut buf = payload;
+                while !buf.is_empty() {
+                    let nh_buf = RouteNextHopBuffer::new_checked(&buf)?;
+                    let len = nh_buf.length() as usize;
+                    next_hops.push(RouteNextHop::parse_with_param(
+                        &nh_buf,
+                        address_family,
+                    )?);
+                    buf = &buf[len..];
+                }
+                Self::MultiPath(next_hops)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+// Without the encapsulation type, `RTA_ENCAP` is parsed as
+// [LwtunnelEncap::Other].
+impl<'a, T: AsRef<[u8]> + ?Sized>
+    ParseableParametrized<NlaBuffer<&'a T>, AddressFamily> for RouteAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        address_family: AddressFamily,
+    ) -> Result<Self, DecodeError> {
+        Self::parse_with_param(buf, (address_family, LwtunnelEncapType::None))
+    }
+}
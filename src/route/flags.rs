@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+const RTNH_F_DEAD: u8 = 1 << 0;
+const RTNH_F_PERVASIVE: u8 = 1 << 1;
+const RTNH_F_ONLINK: u8 = 1 << 2;
+const RTNH_F_OFFLOAD: u8 = 1 << 3;
+const RTNH_F_LINKDOWN: u8 = 1 << 4;
+const RTNH_F_UNRESOLVED: u8 = 1 << 5;
+const RTNH_F_TRAP: u8 = 1 << 6;
+
+const RTM_F_NOTIFY: u32 = 0x100;
+const RTM_F_CLONED: u32 = 0x200;
+const RTM_F_EQUALIZE: u32 = 0x400;
+const RTM_F_PREFIX: u32 = 0x800;
+const RTM_F_LOOKUP_TABLE: u32 = 0x1000;
+const RTM_F_FIB_MATCH: u32 = 0x2000;
+const RTM_F_OFFLOAD: u32 = 0x4000;
+const RTM_F_TRAP: u32 = 0x8000;
+const RTM_F_OFFLOAD_FAILED: u32 = 0x20000000;
+
+bitflags! {
+    /// Flags of `rtmsg.rtm_flags`. The lowest byte holds the `RTNH_F_*`
+    /// next hop flags, the rest holds the `RTM_F_*` route flags.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct RouteFlags: u32 {
+        const Dead = RTNH_F_DEAD as u32;
+        const Pervasive = RTNH_F_PERVASIVE as u32;
+        const Onlink = RTNH_F_ONLINK as u32;
+        const Offload = RTNH_F_OFFLOAD as u32;
+        const Linkdown = RTNH_F_LINKDOWN as u32;
+        const Unresolved = RTNH_F_UNRESOLVED as u32;
+        const Trap = RTNH_F_TRAP as u32;
+        const Notify = RTM_F_NOTIFY;
+        const Cloned = RTM_F_CLONED;
+        const Equalize = RTM_F_EQUALIZE;
+        const Prefix = RTM_F_PREFIX;
+        const LookupTable = RTM_F_LOOKUP_TABLE;
+        const FibMatch = RTM_F_FIB_MATCH;
+        const RtOffload = RTM_F_OFFLOAD;
+        const RtTrap = RTM_F_TRAP;
+        const RtOffloadFailed = RTM_F_OFFLOAD_FAILED;
+        const _ = !0;
+    }
+}
+
+impl Default for RouteFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+bitflags! {
+    /// Flags of `rtnexthop.rtnh_flags`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct RouteNextHopFlags: u8 {
+        const Dead = RTNH_F_DEAD;
+        const Pervasive = RTNH_F_PERVASIVE;
+        const Onlink = RTNH_F_ONLINK;
+        const Offload = RTNH_F_OFFLOAD;
+        const Linkdown = RTNH_F_LINKDOWN;
+        const Unresolved = RTNH_F_UNRESOLVED;
+        const Trap = RTNH_F_TRAP;
+        const _ = !0;
+    }
+}
+
+impl Default for RouteNextHopFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,362 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{route::RouteFlags, AddressFamily};
+
+const ROUTE_HEADER_LEN: usize = 12;
+
+buffer!(RouteMessageBuffer(ROUTE_HEADER_LEN) {
+    address_family: (u8, 0),
+    destination_prefix_length: (u8, 1),
+    source_prefix_length: (u8, 2),
+    tos: (u8, 3),
+    table: (u8, 4),
+    protocol: (u8, 5),
+    scope: (u8, 6),
+    kind: (u8, 7),
+    flags: (u32, 8..ROUTE_HEADER_LEN),
+    payload: (slice, ROUTE_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> RouteMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_GETROUTE`, `RTM_NEWROUTE` and
+/// `RTM_DELROUTE` messages headers.
+///
+/// These headers have the following structure:
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// | address family | dest. length   | source length  |      tos       |
+/// +----------------+----------------+----------------+----------------+
+/// |     table      |    protocol    |     scope      |   route type   |
+/// +----------------+----------------+----------------+----------------+
+/// |                               flags                               |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct RouteHeader {
+    /// Address family of the route: either [AddressFamily::Inet] for IPv4,
+    /// or [AddressFamily::Inet6] for IPv6. Multicast forwarding cache
+    /// entries use [AddressFamily::Ipmr] or [AddressFamily::Ip6mr].
+    pub address_family: AddressFamily,
+    /// Prefix length of the destination subnet.
+    pub destination_prefix_length: u8,
+    /// Prefix length of the source address.
+    pub source_prefix_length: u8,
+    /// The Type of Service (TOS) field.
+    pub tos: u8,
+    /// The routing table ID. Tables with ID bigger than 255 are stored in
+    /// [super::RouteAttribute::Table] instead, with this field set to
+    /// [RouteHeader::RT_TABLE_UNSPEC].
+    pub table: u8,
+    /// Route Protocol
+    pub protocol: RouteProtocol,
+    /// Route scope
+    pub scope: RouteScope,
+    /// Route type.
+    pub kind: RouteType,
+    /// Flags when querying the kernel with a `RTM_GETROUTE` message.
+    pub flags: RouteFlags,
+}
+
+impl RouteHeader {
+    pub const RT_TABLE_UNSPEC: u8 = 0;
+    pub const RT_TABLE_COMPAT: u8 = 252;
+    pub const RT_TABLE_DEFAULT: u8 = 253;
+    pub const RT_TABLE_MAIN: u8 = 254;
+    pub const RT_TABLE_LOCAL: u8 = 255;
+}
+
+impl<T: AsRef<[u8]>> Parseable<RouteMessageBuffer<T>> for RouteHeader {
+    fn parse(buf: &RouteMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(RouteHeader {
+            address_family: buf.address_family().into(),
+            destination_prefix_length: buf.destination_prefix_length(),
+            source_prefix_length: buf.source_prefix_length(),
+            tos: buf.tos(),
+            table: buf.table(),
+            protocol: buf.protocol().into(),
+            scope: buf.scope().into(),
+            kind: buf.kind().into(),
+            flags: RouteFlags::from_bits_retain(buf.flags()),
+        })
+    }
+}
+
+impl Emitable for RouteHeader {
+    fn buffer_len(&self) -> usize {
+        ROUTE_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = RouteMessageBuffer::new(buffer);
+        buffer.set_address_family(self.address_family.into());
+        buffer.set_destination_prefix_length(self.destination_prefix_length);
+        buffer.set_source_prefix_length(self.source_prefix_length);
+        buffer.set_tos(self.tos);
+        buffer.set_table(self.table);
+        buffer.set_protocol(self.protocol.into());
+        buffer.set_scope(self.scope.into());
+        buffer.set_kind(self.kind.into());
+        buffer.set_flags(self.flags.bits());
+    }
+}
+
+const RTPROT_UNSPEC: u8 = 0;
+const RTPROT_REDIRECT: u8 = 1;
+const RTPROT_KERNEL: u8 = 2;
+const RTPROT_BOOT: u8 = 3;
+const RTPROT_STATIC: u8 = 4;
+const RTPROT_GATED: u8 = 8;
+const RTPROT_RA: u8 = 9;
+const RTPROT_MRT: u8 = 10;
+const RTPROT_ZEBRA: u8 = 11;
+const RTPROT_BIRD: u8 = 12;
+const RTPROT_DNROUTED: u8 = 13;
+const RTPROT_XORP: u8 = 14;
+const RTPROT_NTK: u8 = 15;
+const RTPROT_DHCP: u8 = 16;
+const RTPROT_MROUTED: u8 = 17;
+const RTPROT_KEEPALIVED: u8 = 18;
+const RTPROT_BABEL: u8 = 42;
+const RTPROT_OPENR: u8 = 99;
+const RTPROT_BGP: u8 = 186;
+const RTPROT_ISIS: u8 = 187;
+const RTPROT_OSPF: u8 = 188;
+const RTPROT_RIP: u8 = 189;
+const RTPROT_EIGRP: u8 = 192;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum RouteProtocol {
+    #[default]
+    Unspec,
+    IcmpRedirect,
+    Kernel,
+    Boot,
+    Static,
+    Gated,
+    Ra,
+    Mrt,
+    Zebra,
+    Bird,
+    DnRouted,
+    Xorp,
+    Ntk,
+    Dhcp,
+    Mrouted,
+    KeepAlived,
+    Babel,
+    OpenR,
+    Bgp,
+    Isis,
+    Ospf,
+    Rip,
+    Eigrp,
+    Other(u8),
+}
+
+impl From<u8> for RouteProtocol {
+    fn from(d: u8) -> Self {
+        match d {
+            RTPROT_UNSPEC => Self::Unspec,
+            RTPROT_REDIRECT => Self::IcmpRedirect,
+            RTPROT_KERNEL => Self::Kernel,
+            RTPROT_BOOT => Self::Boot,
+            RTPROT_STATIC => Self::Static,
+            RTPROT_GATED => Self::Gated,
+            RTPROT_RA => Self::Ra,
+            RTPROT_MRT => Self::Mrt,
+            RTPROT_ZEBRA => Self::Zebra,
+            RTPROT_BIRD => Self::Bird,
+            RTPROT_DNROUTED => Self::DnRouted,
+            RTPROT_XORP => Self::Xorp,
+            RTPROT_NTK => Self::Ntk,
+            RTPROT_DHCP => Self::Dhcp,
+            RTPROT_MROUTED => Self::Mrouted,
+            RTPROT_KEEPALIVED => Self::KeepAlived,
+            RTPROT_BABEL => Self::Babel,
+            RTPROT_OPENR => Self::OpenR,
+            RTPROT_BGP => Self::Bgp,
+            RTPROT_ISIS => Self::Isis,
+            RTPROT_OSPF => Self::Ospf,
+            RTPROT_RIP => Self::Rip,
+            RTPROT_EIGRP => Self::Eigrp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<RouteProtocol> for u8 {
+    fn from(v: RouteProtocol) -> u8 {
+        match v {
+            RouteProtocol::Unspec => RTPROT_UNSPEC,
+            RouteProtocol::IcmpRedirect => RTPROT_REDIRECT,
+            RouteProtocol::Kernel => RTPROT_KERNEL,
+            RouteProtocol::Boot => RTPROT_BOOT,
+            RouteProtocol::Static => RTPROT_STATIC,
+            RouteProtocol::Gated => RTPROT_GATED,
+            RouteProtocol::Ra => RTPROT_RA,
+            RouteProtocol::Mrt => RTPROT_MRT,
+            RouteProtocol::Zebra => RTPROT_ZEBRA,
+            RouteProtocol::Bird => RTPROT_BIRD,
+            RouteProtocol::DnRouted => RTPROT_DNROUTED,
+            RouteProtocol::Xorp => RTPROT_XORP,
+            RouteProtocol::Ntk => RTPROT_NTK,
+            RouteProtocol::Dhcp => RTPROT_DHCP,
+            RouteProtocol::Mrouted => RTPROT_MROUTED,
+            RouteProtocol::KeepAlived => RTPROT_KEEPALIVED,
+            RouteProtocol::Babel => RTPROT_BABEL,
+            RouteProtocol::OpenR => RTPROT_OPENR,
+            RouteProtocol::Bgp => RTPROT_BGP,
+            RouteProtocol::Isis => RTPROT_ISIS,
+            RouteProtocol::Ospf => RTPROT_OSPF,
+            RouteProtocol::Rip => RTPROT_RIP,
+            RouteProtocol::Eigrp => RTPROT_EIGRP,
+            RouteProtocol::Other(d) => d,
+        }
+    }
+}
+
+const RT_SCOPE_UNIVERSE: u8 = 0;
+// 1 and 199 is user defined values
+const RT_SCOPE_SITE: u8 = 200;
+const RT_SCOPE_LINK: u8 = 253;
+const RT_SCOPE_HOST: u8 = 254;
+const RT_SCOPE_NOWHERE: u8 = 255;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum RouteScope {
+    #[default]
+    Universe,
+    Site,
+    Link,
+    Host,
+    NoWhere,
+    Other(u8),
+}
+
+impl From<u8> for RouteScope {
+    fn from(d: u8) -> Self {
+        match d {
+            RT_SCOPE_UNIVERSE => Self::Universe,
+            RT_SCOPE_SITE => Self::Site,
+            RT_SCOPE_LINK => Self::Link,
+            RT_SCOPE_HOST => Self::Host,
+            RT_SCOPE_NOWHERE => Self::NoWhere,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<RouteScope> for u8 {
+    fn from(v: RouteScope) -> u8 {
+        match v {
+            RouteScope::Universe => RT_SCOPE_UNIVERSE,
+            RouteScope::Site => RT_SCOPE_SITE,
+            RouteScope::Link => RT_SCOPE_LINK,
+            RouteScope::Host => RT_SCOPE_HOST,
+            RouteScope::NoWhere => RT_SCOPE_NOWHERE,
+            RouteScope::Other(d) => d,
+        }
+    }
+}
+
+const RTN_UNSPEC: u8 = 0;
+const RTN_UNICAST: u8 = 1;
+const RTN_LOCAL: u8 = 2;
+const RTN_BROADCAST: u8 = 3;
+const RTN_ANYCAST: u8 = 4;
+const RTN_MULTICAST: u8 = 5;
+const RTN_BLACKHOLE: u8 = 6;
+const RTN_UNREACHABLE: u8 = 7;
+const RTN_PROHIBIT: u8 = 8;
+const RTN_THROW: u8 = 9;
+const RTN_NAT: u8 = 10;
+const RTN_XRESOLVE: u8 = 11;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum RouteType {
+    /// Unknown
+    Unspec,
+    /// A gateway or direct route
+    #[default]
+    Unicast,
+    /// A local interface route
+    Local,
+    /// A local broadcast route (sent as a broadcast)
+    Broadcast,
+    /// A local broadcast route (sent as a unicast)
+    Anycast,
+    /// A multicast route
+    Multicast,
+    /// A packet dropping route
+    BlackHole,
+    /// An unreachable destination
+    Unreachable,
+    /// A packet rejection route
+    Prohibit,
+    /// Continue routing lookup in another table
+    Throw,
+    /// A network address translation rule
+    Nat,
+    /// Refer to an external resolver (not implemented)
+    ExternalResolve,
+    Other(u8),
+}
+
+impl From<u8> for RouteType {
+    fn from(d: u8) -> Self {
+        match d {
+            RTN_UNSPEC => Self::Unspec,
+            RTN_UNICAST => Self::Unicast,
+            RTN_LOCAL => Self::Local,
+            RTN_BROADCAST => Self::Broadcast,
+            RTN_ANYCAST => Self::Anycast,
+            RTN_MULTICAST => Self::Multicast,
+            RTN_BLACKHOLE => Self::BlackHole,
+            RTN_UNREACHABLE => Self::Unreachable,
+            RTN_PROHIBIT => Self::Prohibit,
+            RTN_THROW => Self::Throw,
+            RTN_NAT => Self::Nat,
+            RTN_XRESOLVE => Self::ExternalResolve,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<RouteType> for u8 {
+    fn from(v: RouteType) -> u8 {
+        match v {
+            RouteType::Unspec => RTN_UNSPEC,
+            RouteType::Unicast => RTN_UNICAST,
+            RouteType::Local => RTN_LOCAL,
+            RouteType::Broadcast => RTN_BROADCAST,
+            RouteType::Anycast => RTN_ANYCAST,
+            RouteType::Multicast => RTN_MULTICAST,
+            RouteType::BlackHole => RTN_BLACKHOLE,
+            RouteType::Unreachable => RTN_UNREACHABLE,
+            RouteType::Prohibit => RTN_PROHIBIT,
+            RouteType::Throw => RTN_THROW,
+            RouteType::Nat => RTN_NAT,
+            RouteType::ExternalResolve => RTN_XRESOLVE,
+            RouteType::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::route::{RouteAttribute, RouteHeader, RouteMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct RouteMessage {
+    pub header: RouteHeader,
+    pub attributes: Vec<RouteAttribute>,
+}
+
+impl Emitable for RouteMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<RouteMessageBuffer<&'a T>>
+    for RouteMessage
+{
+    fn parse(buf: &RouteMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = RouteHeader::parse(buf)?;
+        let address_family = header.address_family;
+        let encap_type = RouteAttribute::find_encap_type(buf.attributes())?;
+        let mut attributes = vec![];
+        for nla_buf in buf.attributes() {
+            attributes.push(RouteAttribute::parse_with_param(
+                &nla_buf?,
+                (address_family, encap_type),
+            )?);
+        }
+        Ok(RouteMessage { header, attributes })
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_string, parse_u32},
+    traits::Parseable,
+    DecodeError,
+};
+
+const RTAX_LOCK: u16 = 1;
+const RTAX_MTU: u16 = 2;
+const RTAX_WINDOW: u16 = 3;
+const RTAX_RTT: u16 = 4;
+const RTAX_RTTVAR: u16 = 5;
+const RTAX_SSTHRESH: u16 = 6;
+const RTAX_CWND: u16 = 7;
+const RTAX_ADVMSS: u16 = 8;
+const RTAX_REORDERING: u16 = 9;
+const RTAX_HOPLIMIT: u16 = 10;
+const RTAX_INITCWND: u16 = 11;
+const RTAX_FEATURES: u16 = 12;
+const RTAX_RTO_MIN: u16 = 13;
+const RTAX_INITRWND: u16 = 14;
+const RTAX_QUICKACK: u16 = 15;
+const RTAX_CC_ALGO: u16 = 16;
+const RTAX_FASTOPEN_NO_COOKIE: u16 = 17;
+
+/// Metrics nested in `RTA_METRICS`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteMetric {
+    /// Bitmask of locked metrics, `1 << RTAX_*`
+    Lock(u32),
+    Mtu(u32),
+    Window(u32),
+    Rtt(u32),
+    RttVar(u32),
+    SsThresh(u32),
+    Cwnd(u32),
+    Advmss(u32),
+    Reordering(u32),
+    Hoplimit(u32),
+    InitCwnd(u32),
+    Features(u32),
+    RtoMin(u32),
+    InitRwnd(u32),
+    QuickAck(u32),
+    /// Name of TCP congestion control algorithm
+    CcAlgo(String),
+    FastopenNoCookie(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for RouteMetric {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Lock(_)
+            | Self::Mtu(_)
+            | Self::Window(_)
+            | Self::Rtt(_)
+            | Self::RttVar(_)
+            | Self::SsThresh(_)
+            | Self::Cwnd(_)
+            | Self::Advmss(_)
+            | Self::Reordering(_)
+            | Self::Hoplimit(_)
+            | Self::InitCwnd(_)
+            | Self::Features(_)
+            | Self::RtoMin(_)
+            | Self::InitRwnd(_)
+            | Self::QuickAck(_)
+            | Self::FastopenNoCookie(_) => 4,
+            Self::CcAlgo(s) => s.len() + 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Lock(value)
+            | Self::Mtu(value)
+            | Self::Window(value)
+            | Self::Rtt(value)
+            | Self::RttVar(value)
+            | Self::SsThresh(value)
+            | Self::Cwnd(value)
+            | Self::Advmss(value)
+            | Self::Reordering(value)
+            | Self::Hoplimit(value)
+            | Self::InitCwnd(value)
+            | Self::Features(value)
+            | Self::RtoMin(value)
+            | Self::InitRwnd(value)
+            | Self::QuickAck(value)
+            | Self::FastopenNoCookie(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Self::CcAlgo(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Lock(_) => RTAX_LOCK,
+            Self::Mtu(_) => RTAX_MTU,
+            Self::Window(_) => RTAX_WINDOW,
+            Self::Rtt(_) => RTAX_RTT,
+            Self::RttVar(_) => RTAX_RTTVAR,
+            Self::SsThresh(_) => RTAX_SSTHRESH,
+            Self::Cwnd(_) => RTAX_CWND,
+            Self::Advmss(_) => RTAX_ADVMSS,
+            Self::Reordering(_) => RTAX_REORDERING,
+            Self::Hoplimit(_) => RTAX_HOPLIMIT,
+            Self::InitCwnd(_) => RTAX_INITCWND,
+            Self::Features(_) => RTAX_FEATURES,
+            Self::RtoMin(_) => RTAX_RTO_MIN,
+            Self::InitRwnd(_) => RTAX_INITRWND,
+            Self::QuickAck(_) => RTAX_QUICKACK,
+            Self::CcAlgo(_) => RTAX_CC_ALGO,
+            Self::FastopenNoCookie(_) => RTAX_FASTOPEN_NO_COOKIE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouteMetric {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            RTAX_LOCK => Self::Lock(parse_u32(payload)?),
+            RTAX_MTU => Self::Mtu(parse_u32(payload)?),
+            RTAX_WINDOW => Self::Window(parse_u32(payload)?),
+            RTAX_RTT => Self::Rtt(parse_u32(payload)?),
+            RTAX_RTTVAR => Self::RttVar(parse_u32(payload)?),
+            RTAX_SSTHRESH => Self::SsThresh(parse_u32(payload)?),
+            RTAX_CWND => Self::Cwnd(parse_u32(payload)?),
+            RTAX_ADVMSS => Self::Advmss(parse_u32(payload)?),
+            RTAX_REORDERING => Self::Reordering(parse_u32(payload)?),
+            RTAX_HOPLIMIT => Self::Hoplimit(parse_u32(payload)?),
+            RTAX_INITCWND => Self::InitCwnd(parse_u32(payload)?),
+            RTAX_FEATURES => Self::Features(parse_u32(payload)?),
+            RTAX_RTO_MIN => Self::RtoMin(parse_u32(payload)?),
+            RTAX_INITRWND => Self::InitRwnd(parse_u32(payload)?),
+            RTAX_QUICKACK => Self::QuickAck(parse_u32(payload)?),
+            RTAX_CC_ALGO => Self::CcAlgo(parse_string(payload)?),
+            RTAX_FASTOPEN_NO_COOKIE => {
+                Self::FastopenNoCookie(parse_u32(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
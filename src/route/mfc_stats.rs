@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Statistics of multicast forwarding cache entry(`struct rta_mfc_stats`),
+/// only provided by kernel.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct RouteMfcStats {
+    pub packets: u64,
+    pub bytes: u64,
+    /// Packets received on an interface other than the incoming interface
+    /// of the entry.
+    pub wrong_if: u64,
+}
+
+const ROUTE_MFC_STATS_LEN: usize = 24;
+
+buffer!(RouteMfcStatsBuffer(ROUTE_MFC_STATS_LEN) {
+    packets: (u64, 0..8),
+    bytes: (u64, 8..16),
+    wrong_if: (u64, 16..24),
+});
+
+impl<T: AsRef<[u8]>> Parseable<RouteMfcStatsBuffer<T>> for RouteMfcStats {
+    fn parse(buf: &RouteMfcStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            packets: buf.packets(),
+            bytes: buf.bytes(),
+            wrong_if: buf.wrong_if(),
+        })
+    }
+}
+
+impl Emitable for RouteMfcStats {
+    fn buffer_len(&self) -> usize {
+        ROUTE_MFC_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = RouteMfcStatsBuffer::new(buffer);
+        buffer.set_packets(self.packets);
+        buffer.set_bytes(self.bytes);
+        buffer.set_wrong_if(self.wrong_if);
+    }
+}
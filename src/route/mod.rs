@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+mod address;
+mod attribute;
+mod flags;
+mod header;
+mod message;
+mod metrics;
+mod mfc_stats;
+mod next_hops;
+mod preference;
+mod via;
+
+pub use self::address::RouteAddress;
+pub use self::attribute::RouteAttribute;
+pub use self::flags::{RouteFlags, RouteNextHopFlags};
+pub use self::header::{
+    RouteHeader, RouteMessageBuffer, RouteProtocol, RouteScope, RouteType,
+};
+pub use self::message::RouteMessage;
+pub use self::metrics::RouteMetric;
+pub use self::mfc_stats::{RouteMfcStats, RouteMfcStatsBuffer};
+pub use self::next_hops::{RouteNextHop, RouteNextHopBuffer};
+pub use self::preference::RoutePreference;
+pub use self::via::{RouteVia, RouteViaBuffer};
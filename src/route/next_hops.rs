@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec;
+use alloc::vec::Vec;
+use axerrno::AxError;
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::{
+    route::{RouteAttribute, RouteNextHopFlags},
+    AddressFamily,
+};
+
+pub(crate) const RTNH_LEN: usize = 8;
+
+buffer!(RouteNextHopBuffer {
+    length: (u16, 0..2),
+    flags: (u8, 2),
+    hops: (u8, 3),
+    interface_index: (u32, 4..RTNH_LEN),
+    payload: (slice, RTNH_LEN..),
+});
+
+impl<T: AsRef<[u8]>> RouteNextHopBuffer<T> {
+    pub fn new_checked(buffer: T) -> Result<Self, DecodeError> {
+        let packet = Self::new(buffer);
+        packet.check_buffer_length()?;
+        Ok(packet)
+    }
+
+    fn check_buffer_length(&self) -> Result<(), DecodeError> {
+        let len = self.buffer.as_ref().len();
+        if len < RTNH_LEN
+            || (self.length() as usize) < RTNH_LEN
+            || len < self.length() as usize
+        {
+            return Err(AxError::InvalidInput);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> RouteNextHopBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(
+            &self.payload()[..(self.length() as usize - RTNH_LEN)],
+        )
+    }
+}
+
+/// A single `struct rtnexthop` entry of `RTA_MULTIPATH`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct RouteNextHop {
+    /// Next hop flags
+    pub flags: RouteNextHopFlags,
+    /// Next hop priority, the weight of ECMP is `hops + 1`. For multicast
+    /// routes of [AddressFamily::Ipmr] and [AddressFamily::Ip6mr], this is
+    /// the TTL threshold of the outgoing interface.
+    pub hops: u8,
+    /// Interface index for the next hop
+    pub interface_index: u32,
+    /// Attributes, like `RTA_GATEWAY`, `RTA_VIA` or `RTA_ENCAP`.
+    pub attributes: Vec<RouteAttribute>,
+}
+
+impl<'a, T: AsRef<[u8]>>
+    ParseableParametrized<RouteNextHopBuffer<&'a T>, AddressFamily>
+    for RouteNextHop
+{
+    fn parse_with_param(
+        buf: &RouteNextHopBuffer<&'a T>,
+        address_family: AddressFamily,
+    ) -> Result<RouteNextHop, DecodeError> {
+        let encap_type = RouteAttribute::find_encap_type(buf.attributes())?;
+        let mut attributes = vec![];
+        for nla in buf.attributes() {
+            attributes.push(RouteAttribute::parse_with_param(
+                &nla?,
+                (address_family, encap_type),
+            )?);
+        }
+        Ok(RouteNextHop {
+            flags: RouteNextHopFlags::from_bits_retain(buf.flags()),
+            hops: buf.hops(),
+            interface_index: buf.interface_index(),
+            attributes,
+        })
+    }
+}
+
+impl Emitable for RouteNextHop {
+    fn buffer_len(&self) -> usize {
+        // len, flags, hops and interface id fields
+        RTNH_LEN + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut nh_buffer = RouteNextHopBuffer::new(buffer);
+        nh_buffer.set_length(self.buffer_len() as u16);
+        nh_buffer.set_flags(self.flags.bits());
+        nh_buffer.set_hops(self.hops);
+        nh_buffer.set_interface_index(self.interface_index);
+        self.attributes.as_slice().emit(nh_buffer.payload_mut())
+    }
+}
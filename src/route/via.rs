@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{
+    parse_ipv4_addr, parse_ipv6_addr, IPV4_ADDR_LEN, IPV6_ADDR_LEN,
+};
+use crate::AddressFamily;
+
+const RTVIA_LEN: usize = 2;
+
+buffer!(RouteViaBuffer(RTVIA_LEN) {
+    address_family: (u16, 0..2),
+    address: (slice, RTVIA_LEN..),
+});
+
+/// Gateway of a different address family than the route itself, carried by
+/// `RTA_VIA` as `struct rtvia`. MPLS routes always use it for the next hop,
+/// where `AF_PACKET` means a link layer address.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum RouteVia {
+    Inet(Ipv4Addr),
+    Inet6(Ipv6Addr),
+    /// Unknown address family with the raw address bytes.
+    Other(AddressFamily, Vec<u8>),
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<RouteViaBuffer<&'a T>>
+    for RouteVia
+{
+    fn parse(buf: &RouteViaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let address_family = AddressFamily::from(buf.address_family() as u8);
+        Ok(match address_family {
+            AddressFamily::Inet => Self::Inet(parse_ipv4_addr(buf.address())?),
+            AddressFamily::Inet6 => {
+                Self::Inet6(parse_ipv6_addr(buf.address())?)
+            }
+            _ => Self::Other(address_family, buf.address().to_vec()),
+        })
+    }
+}
+
+impl Emitable for RouteVia {
+    fn buffer_len(&self) -> usize {
+        RTVIA_LEN
+            + match self {
+                Self::Inet(_) => IPV4_ADDR_LEN,
+                Self::Inet6(_) => IPV6_ADDR_LEN,
+                Self::Other(_, v) => v.len(),
+            }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = RouteViaBuffer::new(buffer);
+        match self {
+            Self::Inet(v) => {
+                buffer.set_address_family(u8::from(AddressFamily::Inet).into());
+                buffer.address_mut().copy_from_slice(&v.octets());
+            }
+            Self::Inet6(v) => {
+                buffer
+                    .set_address_family(u8::from(AddressFamily::Inet6).into());
+                buffer.address_mut().copy_from_slice(&v.octets());
+            }
+            Self::Other(family, v) => {
+                buffer.set_address_family(u8::from(*family).into());
+                buffer.address_mut().copy_from_slice(v.as_slice());
+            }
+        }
+    }
+}
+
+impl From<Ipv4Addr> for RouteVia {
+    fn from(v: Ipv4Addr) -> Self {
+        Self::Inet(v)
+    }
+}
+
+impl From<Ipv6Addr> for RouteVia {
+    fn from(v: Ipv6Addr) -> Self {
+        Self::Inet6(v)
+    }
+}
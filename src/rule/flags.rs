@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+const FIB_RULE_PERMANENT: u32 = 0x00000001;
+const FIB_RULE_INVERT: u32 = 0x00000002;
+const FIB_RULE_UNRESOLVED: u32 = 0x00000004;
+const FIB_RULE_IIF_DETACHED: u32 = 0x00000008;
+const FIB_RULE_OIF_DETACHED: u32 = 0x00000010;
+const FIB_RULE_FIND_SADDR: u32 = 0x00010000;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct RuleFlags: u32 {
+        const Permanent = FIB_RULE_PERMANENT;
+        const Invert = FIB_RULE_INVERT;
+        const Unresolved = FIB_RULE_UNRESOLVED;
+        const IifDetached = FIB_RULE_IIF_DETACHED;
+        const OifDetached = FIB_RULE_OIF_DETACHED;
+        /// Try to find source address in routing lookups
+        const FindSaddr = FIB_RULE_FIND_SADDR;
+        const _ = !0;
+    }
+}
+
+impl Default for RuleFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
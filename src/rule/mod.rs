@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+mod action;
+mod attribute;
+mod flags;
+mod header;
+mod message;
+mod port_range;
+mod uid_range;
+
+pub use self::action::RuleAction;
+pub use self::attribute::RuleAttribute;
+pub use self::flags::RuleFlags;
+pub use self::header::{RuleHeader, RuleMessageBuffer};
+pub use self::message::RuleMessage;
+pub use self::port_range::{RulePortRange, RulePortRangeBuffer};
+pub use self::uid_range::{RuleUidRange, RuleUidRangeBuffer};
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const RULE_PORT_RANGE_LEN: usize = 4;
+
+buffer!(RulePortRangeBuffer(RULE_PORT_RANGE_LEN) {
+    start: (u16, 0..2),
+    end: (u16, 2..4),
+});
+
+/// Inclusive port range(`struct fib_rule_port_range`) used by
+/// `FRA_SPORT_RANGE` and `FRA_DPORT_RANGE`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct RulePortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl<T: AsRef<[u8]>> Parseable<RulePortRangeBuffer<T>> for RulePortRange {
+    fn parse(buf: &RulePortRangeBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            start: buf.start(),
+            end: buf.end(),
+        })
+    }
+}
+
+impl Emitable for RulePortRange {
+    fn buffer_len(&self) -> usize {
+        RULE_PORT_RANGE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = RulePortRangeBuffer::new(buffer);
+        buffer.set_start(self.start);
+        buffer.set_end(self.end);
+    }
+}
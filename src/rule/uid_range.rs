@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const RULE_UID_RANGE_LEN: usize = 8;
+
+buffer!(RuleUidRangeBuffer(RULE_UID_RANGE_LEN) {
+    start: (u32, 0..4),
+    end: (u32, 4..8),
+});
+
+/// Inclusive UID range(`struct fib_rule_uid_range`) used by
+/// `FRA_UID_RANGE`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct RuleUidRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<RuleUidRangeBuffer<T>> for RuleUidRange {
+    fn parse(buf: &RuleUidRangeBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            start: buf.start(),
+            end: buf.end(),
+        })
+    }
+}
+
+impl Emitable for RuleUidRange {
+    fn buffer_len(&self) -> usize {
+        RULE_UID_RANGE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = RuleUidRangeBuffer::new(buffer);
+        buffer.set_start(self.start);
+        buffer.set_end(self.end);
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{link::AfSpecMpls, AddressFamily};
+
+/// Member of `IFLA_STATS_AF_SPEC`, the attribute type is the address
+/// family.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsAfSpec {
+    Mpls(Vec<AfSpecMpls>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsAfSpec {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mpls(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mpls(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mpls(_) => u8::from(AddressFamily::Mpls) as u16,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for StatsAfSpec {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            k if k == u8::from(AddressFamily::Mpls) as u16 => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(buf.value()) {
+                    attrs.push(AfSpecMpls::parse(&nla?)?);
+                }
+                Self::Mpls(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
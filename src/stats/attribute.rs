@@ -0,0 +1,153 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    link::{
+        buffer_tool::expand_buffer_if_small, stats64::LINK_STATS64_LEN,
+        Stats64, Stats64Buffer,
+    },
+    stats::{StatsAfSpec, StatsGetFilter, StatsOffloadXstats, StatsXstats},
+};
+
+const IFLA_STATS_LINK_64: u16 = 1;
+const IFLA_STATS_LINK_XSTATS: u16 = 2;
+const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+const IFLA_STATS_AF_SPEC: u16 = 5;
+
+// Attributes of `RTM_GETSTATS` and `RTM_SETSTATS` requests
+const IFLA_STATS_GET_FILTERS: u16 = 1;
+const IFLA_STATS_SET_OFFLOAD_XSTATS_L3_STATS: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsAttribute {
+    Link64(Stats64),
+    /// Statistics provided by the interface driver
+    LinkXstats(Vec<StatsXstats>),
+    /// Statistics provided by the controller of the interface
+    LinkXstatsSlave(Vec<StatsXstats>),
+    LinkOffloadXstats(Vec<StatsOffloadXstats>),
+    AfSpec(Vec<StatsAfSpec>),
+    /// `RTM_GETSTATS` only.
+    GetFilters(Vec<StatsGetFilter>),
+    /// `RTM_SETSTATS` only, enable or disable the layer 3 hardware
+    /// statistics of the interface.
+    SetOffloadXstatsL3Stats(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Link64(v) => v.buffer_len(),
+            Self::LinkXstats(attrs) | Self::LinkXstatsSlave(attrs) => {
+                attrs.as_slice().buffer_len()
+            }
+            Self::LinkOffloadXstats(attrs) => attrs.as_slice().buffer_len(),
+            Self::AfSpec(attrs) => attrs.as_slice().buffer_len(),
+            Self::GetFilters(attrs) => attrs.as_slice().buffer_len(),
+            Self::SetOffloadXstatsL3Stats(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Link64(v) => v.emit(buffer),
+            Self::LinkXstats(attrs) | Self::LinkXstatsSlave(attrs) => {
+                attrs.as_slice().emit(buffer)
+            }
+            Self::LinkOffloadXstats(attrs) => attrs.as_slice().emit(buffer),
+            Self::AfSpec(attrs) => attrs.as_slice().emit(buffer),
+            Self::GetFilters(attrs) => attrs.as_slice().emit(buffer),
+            Self::SetOffloadXstatsL3Stats(v) => buffer[0] = *v as u8,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Link64(_) => IFLA_STATS_LINK_64,
+            Self::LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            Self::LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            Self::LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            Self::AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Self::GetFilters(_) => IFLA_STATS_GET_FILTERS | NLA_F_NESTED,
+            Self::SetOffloadXstatsL3Stats(_) => {
+                IFLA_STATS_SET_OFFLOAD_XSTATS_L3_STATS
+            }
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl StatsAttribute {
+    /// The `RTM_GETSTATS` and `RTM_SETSTATS` requests reuse the attribute
+    /// numbers of `RTM_NEWSTATS` for `IFLA_STATS_GETSET_*`.
+    pub(crate) fn parse_with_param<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        is_request: bool,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match (buf.kind(), is_request) {
+            (IFLA_STATS_GET_FILTERS, true) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(StatsGetFilter::parse(&nla?)?);
+                }
+                Self::GetFilters(attrs)
+            }
+            (IFLA_STATS_SET_OFFLOAD_XSTATS_L3_STATS, true) => {
+                Self::SetOffloadXstatsL3Stats(parse_u8(payload)? > 0)
+            }
+            (IFLA_STATS_LINK_64, false) => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    LINK_STATS64_LEN,
+                    "IFLA_STATS_LINK_64",
+                );
+                Self::Link64(Stats64::parse(&Stats64Buffer::new(
+                    payload.as_slice(),
+                ))?)
+            }
+            (IFLA_STATS_LINK_XSTATS, false) => {
+                Self::LinkXstats(parse_xstats(payload)?)
+            }
+            (IFLA_STATS_LINK_XSTATS_SLAVE, false) => {
+                Self::LinkXstatsSlave(parse_xstats(payload)?)
+            }
+            (IFLA_STATS_LINK_OFFLOAD_XSTATS, false) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(StatsOffloadXstats::parse(&nla?)?);
+                }
+                Self::LinkOffloadXstats(attrs)
+            }
+            (IFLA_STATS_AF_SPEC, false) => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(StatsAfSpec::parse(&nla?)?);
+                }
+                Self::AfSpec(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn parse_xstats(payload: &[u8]) -> Result<Vec<StatsXstats>, DecodeError> {
+    let mut attrs = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        attrs.push(StatsXstats::parse(&nla?)?);
+    }
+    Ok(attrs)
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u64,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const BOND_XSTATS_3AD: u16 = 1;
+// const BOND_XSTATS_PAD: u16 = 2;
+
+/// Member of `LINK_XSTATS_TYPE_BOND`, provided by bond and bond ports.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BondXstats {
+    /// 802.3ad statistics
+    Ad(Vec<BondAdXstats>),
+    Other(DefaultNla),
+}
+
+impl Nla for BondXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ad(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ad(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ad(_) => BOND_XSTATS_3AD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BondXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_XSTATS_3AD => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(BondAdXstats::parse(&nla?)?);
+                }
+                Self::Ad(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const BOND_3AD_STAT_LACPDU_RX: u16 = 0;
+const BOND_3AD_STAT_LACPDU_TX: u16 = 1;
+const BOND_3AD_STAT_LACPDU_UNKNOWN_RX: u16 = 2;
+const BOND_3AD_STAT_LACPDU_ILLEGAL_RX: u16 = 3;
+const BOND_3AD_STAT_MARKER_RX: u16 = 4;
+const BOND_3AD_STAT_MARKER_TX: u16 = 5;
+const BOND_3AD_STAT_MARKER_RESP_RX: u16 = 6;
+const BOND_3AD_STAT_MARKER_RESP_TX: u16 = 7;
+const BOND_3AD_STAT_MARKER_UNKNOWN_RX: u16 = 8;
+// const BOND_3AD_STAT_PAD: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum BondAdXstats {
+    LacpduRx(u64),
+    LacpduTx(u64),
+    LacpduUnknownRx(u64),
+    LacpduIllegalRx(u64),
+    MarkerRx(u64),
+    MarkerTx(u64),
+    MarkerRespRx(u64),
+    MarkerRespTx(u64),
+    MarkerUnknownRx(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for BondAdXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+            _ => 8,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::LacpduRx(v)
+            | Self::LacpduTx(v)
+            | Self::LacpduUnknownRx(v)
+            | Self::LacpduIllegalRx(v)
+            | Self::MarkerRx(v)
+            | Self::MarkerTx(v)
+            | Self::MarkerRespRx(v)
+            | Self::MarkerRespTx(v)
+            | Self::MarkerUnknownRx(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::LacpduRx(_) => BOND_3AD_STAT_LACPDU_RX,
+            Self::LacpduTx(_) => BOND_3AD_STAT_LACPDU_TX,
+            Self::LacpduUnknownRx(_) => BOND_3AD_STAT_LACPDU_UNKNOWN_RX,
+            Self::LacpduIllegalRx(_) => BOND_3AD_STAT_LACPDU_ILLEGAL_RX,
+            Self::MarkerRx(_) => BOND_3AD_STAT_MARKER_RX,
+            Self::MarkerTx(_) => BOND_3AD_STAT_MARKER_TX,
+            Self::MarkerRespRx(_) => BOND_3AD_STAT_MARKER_RESP_RX,
+            Self::MarkerRespTx(_) => BOND_3AD_STAT_MARKER_RESP_TX,
+            Self::MarkerUnknownRx(_) => BOND_3AD_STAT_MARKER_UNKNOWN_RX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BondAdXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_3AD_STAT_LACPDU_RX => Self::LacpduRx(parse_u64(payload)?),
+            BOND_3AD_STAT_LACPDU_TX => Self::LacpduTx(parse_u64(payload)?),
+            BOND_3AD_STAT_LACPDU_UNKNOWN_RX => {
+                Self::LacpduUnknownRx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_LACPDU_ILLEGAL_RX => {
+                Self::LacpduIllegalRx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_MARKER_RX => Self::MarkerRx(parse_u64(payload)?),
+            BOND_3AD_STAT_MARKER_TX => Self::MarkerTx(parse_u64(payload)?),
+            BOND_3AD_STAT_MARKER_RESP_RX => {
+                Self::MarkerRespRx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_MARKER_RESP_TX => {
+                Self::MarkerRespTx(parse_u64(payload)?)
+            }
+            BOND_3AD_STAT_MARKER_UNKNOWN_RX => {
+                Self::MarkerUnknownRx(parse_u64(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+// The filter bit of each attribute is `1 << (attribute_type - 1)`.
+const IFLA_STATS_FILTER_LINK_64: u32 = 1 << 0;
+const IFLA_STATS_FILTER_LINK_XSTATS: u32 = 1 << 1;
+const IFLA_STATS_FILTER_LINK_XSTATS_SLAVE: u32 = 1 << 2;
+const IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS: u32 = 1 << 3;
+const IFLA_STATS_FILTER_AF_SPEC: u32 = 1 << 4;
+
+bitflags! {
+    /// Statistics attributes requested by `RTM_GETSTATS`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct StatsFilterMask: u32 {
+        const Link64 = IFLA_STATS_FILTER_LINK_64;
+        const LinkXstats = IFLA_STATS_FILTER_LINK_XSTATS;
+        const LinkXstatsSlave = IFLA_STATS_FILTER_LINK_XSTATS_SLAVE;
+        const LinkOffloadXstats = IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS;
+        const AfSpec = IFLA_STATS_FILTER_AF_SPEC;
+        const _ = !0;
+    }
+}
+
+impl Default for StatsFilterMask {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const IFLA_OFFLOAD_XSTATS_FILTER_CPU_HIT: u32 = 1 << 0;
+const IFLA_OFFLOAD_XSTATS_FILTER_HW_S_INFO: u32 = 1 << 1;
+const IFLA_OFFLOAD_XSTATS_FILTER_L3_STATS: u32 = 1 << 2;
+
+bitflags! {
+    /// Members of `IFLA_STATS_LINK_OFFLOAD_XSTATS` requested by
+    /// `RTM_GETSTATS`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct StatsOffloadFilterMask: u32 {
+        const CpuHit = IFLA_OFFLOAD_XSTATS_FILTER_CPU_HIT;
+        const HwStatsInfo = IFLA_OFFLOAD_XSTATS_FILTER_HW_S_INFO;
+        const L3Stats = IFLA_OFFLOAD_XSTATS_FILTER_L3_STATS;
+        const _ = !0;
+    }
+}
+
+impl Default for StatsOffloadFilterMask {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+
+/// Member of `IFLA_STATS_GET_FILTERS`, refining the attributes selected
+/// by [StatsFilterMask].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsGetFilter {
+    LinkOffloadXstats(StatsOffloadFilterMask),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsGetFilter {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::LinkOffloadXstats(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::LinkOffloadXstats(v) => {
+                NativeEndian::write_u32(buffer, v.bits())
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsGetFilter
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => Self::LinkOffloadXstats(
+                StatsOffloadFilterMask::from_bits_retain(parse_u32(payload)?),
+            ),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
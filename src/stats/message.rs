@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::stats::{StatsAttribute, StatsHeader, StatsMessageBuffer};
+
+const RTM_NEWSTATS: u16 = 92;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct StatsMessage {
+    pub header: StatsHeader,
+    pub attributes: Vec<StatsAttribute>,
+}
+
+impl Emitable for StatsMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+/// The parameter is the netlink message type, as the attributes of
+/// `RTM_GETSTATS` and `RTM_SETSTATS` requests differ from `RTM_NEWSTATS`.
+impl<'a, T: AsRef<[u8]> + 'a>
+    ParseableParametrized<StatsMessageBuffer<&'a T>, u16> for StatsMessage
+{
+    fn parse_with_param(
+        buf: &StatsMessageBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        let header = StatsHeader::parse(buf)?;
+        let is_request = message_type != RTM_NEWSTATS;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes
+                .push(StatsAttribute::parse_with_param(&nla_buf?, is_request)?);
+        }
+        Ok(StatsMessage { header, attributes })
+    }
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+mod af_spec;
+mod attribute;
+mod bond;
+mod bridge;
+mod filter;
+mod header;
+mod message;
+mod offload;
+mod xstats;
+
+pub use self::af_spec::StatsAfSpec;
+pub use self::attribute::StatsAttribute;
+pub use self::bond::{BondAdXstats, BondXstats};
+pub use self::bridge::{
+    BridgeMcastXstats, BridgeMcastXstatsBuffer, BridgeStpXstats,
+    BridgeStpXstatsBuffer, BridgeVlanXstats, BridgeVlanXstatsBuffer,
+    BridgeXstats,
+};
+pub use self::filter::{
+    StatsFilterMask, StatsGetFilter, StatsOffloadFilterMask,
+};
+pub use self::header::{StatsHeader, StatsMessageBuffer};
+pub use self::message::StatsMessage;
+pub use self::offload::{
+    HwStats64, HwStats64Buffer, StatsHwStatsInfo, StatsHwStatsInfoAttribute,
+    StatsOffloadXstats,
+};
+pub use self::xstats::StatsXstats;
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::link::{
+    buffer_tool::expand_buffer_if_small, stats64::LINK_STATS64_LEN, Stats64,
+    Stats64Buffer,
+};
+
+const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO: u16 = 2;
+const IFLA_OFFLOAD_XSTATS_L3_STATS: u16 = 3;
+
+const HW_STATS64_LEN: usize = 72;
+
+/// Member of `IFLA_STATS_LINK_OFFLOAD_XSTATS`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsOffloadXstats {
+    /// Statistics of packets trapped to CPU by the offloading device
+    CpuHit(Stats64),
+    /// Whether the hardware statistics are requested and in use
+    HwStatsInfo(Vec<StatsHwStatsInfo>),
+    /// Statistics of layer 3 traffic forwarded by the offloading device
+    L3Stats(HwStats64),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsOffloadXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::CpuHit(v) => v.buffer_len(),
+            Self::HwStatsInfo(attrs) => attrs.as_slice().buffer_len(),
+            Self::L3Stats(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::CpuHit(v) => v.emit(buffer),
+            Self::HwStatsInfo(attrs) => attrs.as_slice().emit(buffer),
+            Self::L3Stats(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            Self::HwStatsInfo(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO,
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsOffloadXstats
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => {
+                let payload = expand_buffer_if_small(
+                    payload,
+                    LINK_STATS64_LEN,
+                    "IFLA_OFFLOAD_XSTATS_CPU_HIT",
+                );
+                Self::CpuHit(Stats64::parse(&Stats64Buffer::new(
+                    payload.as_slice(),
+                ))?)
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(StatsHwStatsInfo::parse(&nla?)?);
+                }
+                Self::HwStatsInfo(attrs)
+            }
+            IFLA_OFFLOAD_XSTATS_L3_STATS => Self::L3Stats(HwStats64::parse(
+                &HwStats64Buffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// Member of `IFLA_OFFLOAD_XSTATS_HW_S_INFO`, the attribute type is the
+/// type of hardware statistics.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsHwStatsInfo {
+    L3Stats(Vec<StatsHwStatsInfoAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsHwStatsInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::L3Stats(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::L3Stats(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsHwStatsInfo
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(StatsHwStatsInfoAttribute::parse(&nla?)?);
+                }
+                Self::L3Stats(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST: u16 = 1;
+const IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsHwStatsInfoAttribute {
+    /// Whether the statistics are requested by user space
+    Request(bool),
+    /// Whether the statistics are provided by any driver
+    Used(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsHwStatsInfoAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Request(_) | Self::Used(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Request(v) | Self::Used(v) => buffer[0] = *v as u8,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Request(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST,
+            Self::Used(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for StatsHwStatsInfoAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST => {
+                Self::Request(parse_u8(payload)? > 0)
+            }
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED => {
+                Self::Used(parse_u8(payload)? > 0)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+buffer!(HwStats64Buffer(HW_STATS64_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    multicast: (u64, 64..HW_STATS64_LEN),
+});
+
+/// Hardware statistics(`struct rtnl_hw_stats64`)
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[non_exhaustive]
+pub struct HwStats64 {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub multicast: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<HwStats64Buffer<T>> for HwStats64 {
+    fn parse(buf: &HwStats64Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            multicast: buf.multicast(),
+        })
+    }
+}
+
+impl Emitable for HwStats64 {
+    fn buffer_len(&self) -> usize {
+        HW_STATS64_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = HwStats64Buffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_multicast(self.multicast);
+    }
+}
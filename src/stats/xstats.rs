@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::stats::{BondXstats, BridgeXstats};
+
+const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+/// Member of `IFLA_STATS_LINK_XSTATS` and `IFLA_STATS_LINK_XSTATS_SLAVE`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum StatsXstats {
+    Bridge(Vec<BridgeXstats>),
+    Bond(Vec<BondXstats>),
+    Other(DefaultNla),
+}
+
+impl Nla for StatsXstats {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Bridge(attrs) => attrs.as_slice().buffer_len(),
+            Self::Bond(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Bridge(attrs) => attrs.as_slice().emit(buffer),
+            Self::Bond(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Self::Bond(_) => LINK_XSTATS_TYPE_BOND,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for StatsXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_BRIDGE => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(BridgeXstats::parse(&nla?)?);
+                }
+                Self::Bridge(attrs)
+            }
+            LINK_XSTATS_TYPE_BOND => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(BondXstats::parse(&nla?)?);
+                }
+                Self::Bond(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{
+    flags::NLA_BITFIELD32_LEN, TcActionFlags, TcActionHwStats, TcActionOptions,
+    TcBitfield32,
+};
+use crate::tc::TcStats2;
+
+const TCA_ACT_KIND: u16 = 1;
+const TCA_ACT_OPTIONS: u16 = 2;
+const TCA_ACT_INDEX: u16 = 3;
+const TCA_ACT_STATS: u16 = 4;
+// const TCA_ACT_PAD: u16 = 5;
+const TCA_ACT_COOKIE: u16 = 6;
+const TCA_ACT_FLAGS: u16 = 7;
+const TCA_ACT_HW_STATS: u16 = 8;
+const TCA_ACT_USED_HW_STATS: u16 = 9;
+const TCA_ACT_IN_HW_COUNT: u16 = 10;
+
+/// A single action, either standalone in [super::TcActionMessage] or
+/// nested inside the options of a filter.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct TcAction {
+    /// Order of the action in the list, starting from 1. Kernel executes
+    /// the actions in this order and ignores actions with 0.
+    pub tab: u16,
+    pub attributes: Vec<TcActionAttribute>,
+}
+
+impl Default for TcAction {
+    fn default() -> Self {
+        Self {
+            tab: 1,
+            attributes: Vec::new(),
+        }
+    }
+}
+
+impl Nla for TcAction {
+    fn value_len(&self) -> usize {
+        self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes.as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        self.tab | NLA_F_NESTED
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcAction {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = Vec::new();
+        // TCA_ACT_OPTIONS can only be parsed after TCA_ACT_KIND
+        let mut kind = String::new();
+        for nla_buf in NlasIterator::new(buf.value()) {
+            let attr = TcActionAttribute::parse_with_param(&nla_buf?, &kind)?;
+            if let TcActionAttribute::Kind(k) = &attr {
+                kind = k.clone();
+            }
+            attributes.push(attr);
+        }
+        Ok(Self {
+            tab: buf.kind(),
+            attributes,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionAttribute {
+    /// Name of action, e.g. `mirred`
+    Kind(String),
+    Options(TcActionOptions),
+    /// Index of the action instance
+    Index(u32),
+    /// Statistics of packets processed by this action
+    Stats(Vec<TcStats2>),
+    /// Opaque data attached by user space, up to 16 bytes, and reported
+    /// back in dump and statistics
+    Cookie(Vec<u8>),
+    Flags(TcBitfield32<TcActionFlags>),
+    /// Type of hardware statistics requested
+    HwStats(TcBitfield32<TcActionHwStats>),
+    /// Type of hardware statistics used by the driver
+    UsedHwStats(TcBitfield32<TcActionHwStats>),
+    /// Number of hardware devices this action is offloaded to
+    InHwCount(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Kind(s) => s.len() + 1,
+            Self::Options(v) => v.buffer_len(),
+            Self::Index(_) | Self::InHwCount(_) => 4,
+            Self::Stats(v) => v.as_slice().buffer_len(),
+            Self::Cookie(v) => v.len(),
+            Self::Flags(_) | Self::HwStats(_) | Self::UsedHwStats(_) => {
+                NLA_BITFIELD32_LEN
+            }
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Kind(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Options(v) => v.emit(buffer),
+            Self::Index(v) | Self::InHwCount(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Stats(v) => v.as_slice().emit(buffer),
+            Self::Cookie(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::Flags(v) => v.emit(buffer),
+            Self::HwStats(v) | Self::UsedHwStats(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Kind(_) => TCA_ACT_KIND,
+            Self::Options(_) => TCA_ACT_OPTIONS | NLA_F_NESTED,
+            Self::Index(_) => TCA_ACT_INDEX,
+            Self::Stats(_) => TCA_ACT_STATS | NLA_F_NESTED,
+            Self::Cookie(_) => TCA_ACT_COOKIE,
+            Self::Flags(_) => TCA_ACT_FLAGS,
+            Self::HwStats(_) => TCA_ACT_HW_STATS,
+            Self::UsedHwStats(_) => TCA_ACT_USED_HW_STATS,
+            Self::InHwCount(_) => TCA_ACT_IN_HW_COUNT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str>
+    for TcActionAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        kind: &str,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ACT_KIND => Self::Kind(parse_string(payload)?),
+            TCA_ACT_OPTIONS => {
+                Self::Options(TcActionOptions::parse_with_param(payload, kind)?)
+            }
+            TCA_ACT_INDEX => Self::Index(parse_u32(payload)?),
+            TCA_ACT_STATS => {
+                let mut stats = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    stats.push(TcStats2::parse_with_param(&nla?, kind)?);
+                }
+                Self::Stats(stats)
+            }
+            TCA_ACT_COOKIE => Self::Cookie(payload.to_vec()),
+            TCA_ACT_FLAGS => Self::Flags(TcBitfield32::parse(payload)?),
+            TCA_ACT_HW_STATS => Self::HwStats(TcBitfield32::parse(payload)?),
+            TCA_ACT_USED_HW_STATS => {
+                Self::UsedHwStats(TcBitfield32::parse(payload)?)
+            }
+            TCA_ACT_IN_HW_COUNT => Self::InHwCount(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    generic::TC_GEN_LEN, TcActionGeneric, TcActionGenericBuffer,
+    TcActionTimestamp, TcActionTimestampBuffer,
+};
+
+const TCA_CSUM_PARMS: u16 = 1;
+const TCA_CSUM_TM: u16 = 2;
+// const TCA_CSUM_PAD: u16 = 3;
+
+/// Options of `csum` action which recalculates the checksums of packet
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionCsumOption {
+    Parms(TcCsum),
+    Tm(TcActionTimestamp),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionCsumOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Parms(v) => v.buffer_len(),
+            Self::Tm(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Parms(v) => v.emit(buffer),
+            Self::Tm(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Parms(_) => TCA_CSUM_PARMS,
+            Self::Tm(_) => TCA_CSUM_TM,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionCsumOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_CSUM_PARMS => Self::Parms(TcCsum::parse(
+                &TcCsumBuffer::new_checked(payload)?,
+            )?),
+            TCA_CSUM_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TCA_CSUM_UPDATE_FLAG_IPV4HDR: u32 = 1 << 0;
+const TCA_CSUM_UPDATE_FLAG_ICMP: u32 = 1 << 1;
+const TCA_CSUM_UPDATE_FLAG_IGMP: u32 = 1 << 2;
+const TCA_CSUM_UPDATE_FLAG_TCP: u32 = 1 << 3;
+const TCA_CSUM_UPDATE_FLAG_UDP: u32 = 1 << 4;
+const TCA_CSUM_UPDATE_FLAG_UDPLITE: u32 = 1 << 5;
+const TCA_CSUM_UPDATE_FLAG_SCTP: u32 = 1 << 6;
+
+bitflags! {
+    /// Checksums to update
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcCsumUpdateFlags: u32 {
+        const Ipv4Hdr = TCA_CSUM_UPDATE_FLAG_IPV4HDR;
+        const Icmp = TCA_CSUM_UPDATE_FLAG_ICMP;
+        const Igmp = TCA_CSUM_UPDATE_FLAG_IGMP;
+        const Tcp = TCA_CSUM_UPDATE_FLAG_TCP;
+        const Udp = TCA_CSUM_UPDATE_FLAG_UDP;
+        const UdpLite = TCA_CSUM_UPDATE_FLAG_UDPLITE;
+        const Sctp = TCA_CSUM_UPDATE_FLAG_SCTP;
+        const _ = !0;
+    }
+}
+
+impl Default for TcCsumUpdateFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const TC_CSUM_LEN: usize = TC_GEN_LEN + 4;
+
+buffer!(TcCsumBuffer(TC_CSUM_LEN) {
+    generic: (slice, 0..TC_GEN_LEN),
+    update_flags: (u32, TC_GEN_LEN..TC_CSUM_LEN),
+});
+
+/// Parameters(`struct tc_csum`) of `csum` action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcCsum {
+    pub generic: TcActionGeneric,
+    pub update_flags: TcCsumUpdateFlags,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcCsumBuffer<&'a T>> for TcCsum {
+    fn parse(buf: &TcCsumBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+            update_flags: TcCsumUpdateFlags::from_bits_retain(
+                buf.update_flags(),
+            ),
+        })
+    }
+}
+
+impl Emitable for TcCsum {
+    fn buffer_len(&self) -> usize {
+        TC_CSUM_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcCsumBuffer::new(buffer);
+        self.generic.emit(buffer.generic_mut());
+        buffer.set_update_flags(self.update_flags.bits());
+    }
+}
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use axerrno::AxError;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_string, parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    TcActionGeneric, TcActionGenericBuffer, TcActionTimestamp,
+    TcActionTimestampBuffer,
+};
+use crate::{
+    ip::{parse_ipv4_addr, parse_ipv6_addr},
+    AddressFamily, IpProtocol,
+};
+
+const TCA_CT_PARMS: u16 = 1;
+const TCA_CT_TM: u16 = 2;
+const TCA_CT_ACTION: u16 = 3;
+const TCA_CT_ZONE: u16 = 4;
+const TCA_CT_MARK: u16 = 5;
+const TCA_CT_MARK_MASK: u16 = 6;
+const TCA_CT_LABELS: u16 = 7;
+const TCA_CT_LABELS_MASK: u16 = 8;
+const TCA_CT_NAT_IPV4_MIN: u16 = 9;
+const TCA_CT_NAT_IPV4_MAX: u16 = 10;
+const TCA_CT_NAT_IPV6_MIN: u16 = 11;
+const TCA_CT_NAT_IPV6_MAX: u16 = 12;
+const TCA_CT_NAT_PORT_MIN: u16 = 13;
+const TCA_CT_NAT_PORT_MAX: u16 = 14;
+// const TCA_CT_PAD: u16 = 15;
+const TCA_CT_HELPER_NAME: u16 = 16;
+const TCA_CT_HELPER_FAMILY: u16 = 17;
+const TCA_CT_HELPER_PROTO: u16 = 18;
+
+const TCA_CT_ACT_COMMIT: u16 = 1 << 0;
+const TCA_CT_ACT_FORCE: u16 = 1 << 1;
+const TCA_CT_ACT_CLEAR: u16 = 1 << 2;
+const TCA_CT_ACT_NAT: u16 = 1 << 3;
+const TCA_CT_ACT_NAT_SRC: u16 = 1 << 4;
+const TCA_CT_ACT_NAT_DST: u16 = 1 << 5;
+
+const CT_LABELS_LEN: usize = 16;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcCtActionFlags: u16 {
+        /// Commit the connection to the connection tracking table
+        const Commit = TCA_CT_ACT_COMMIT;
+        const Force = TCA_CT_ACT_FORCE;
+        /// Clear the connection tracking state of packet
+        const Clear = TCA_CT_ACT_CLEAR;
+        const Nat = TCA_CT_ACT_NAT;
+        const NatSrc = TCA_CT_ACT_NAT_SRC;
+        const NatDst = TCA_CT_ACT_NAT_DST;
+        const _ = !0;
+    }
+}
+
+impl Default for TcCtActionFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Options of `ct` action which sends the packet to connection tracking
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionCtOption {
+    Parms(TcActionGeneric),
+    Tm(TcActionTimestamp),
+    Action(TcCtActionFlags),
+    Zone(u16),
+    Mark(u32),
+    MarkMask(u32),
+    Labels([u8; CT_LABELS_LEN]),
+    LabelsMask([u8; CT_LABELS_LEN]),
+    NatIpv4Min(Ipv4Addr),
+    NatIpv4Max(Ipv4Addr),
+    NatIpv6Min(Ipv6Addr),
+    NatIpv6Max(Ipv6Addr),
+    NatPortMin(u16),
+    NatPortMax(u16),
+    /// Name of connection tracking helper, e.g. `ftp`
+    HelperName(String),
+    HelperFamily(AddressFamily),
+    HelperProto(IpProtocol),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionCtOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Parms(v) => v.buffer_len(),
+            Self::Tm(v) => v.buffer_len(),
+            Self::Action(_)
+            | Self::Zone(_)
+            | Self::NatPortMin(_)
+            | Self::NatPortMax(_) => 2,
+            Self::Mark(_)
+            | Self::MarkMask(_)
+            | Self::NatIpv4Min(_)
+            | Self::NatIpv4Max(_) => 4,
+            Self::Labels(_)
+            | Self::LabelsMask(_)
+            | Self::NatIpv6Min(_)
+            | Self::NatIpv6Max(_) => 16,
+            Self::HelperName(s) => s.len() + 1,
+            Self::HelperFamily(_) | Self::HelperProto(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Parms(v) => v.emit(buffer),
+            Self::Tm(v) => v.emit(buffer),
+            Self::Action(v) => NativeEndian::write_u16(buffer, v.bits()),
+            Self::Zone(v) => NativeEndian::write_u16(buffer, *v),
+            Self::NatPortMin(v) | Self::NatPortMax(v) => {
+                BigEndian::write_u16(buffer, *v)
+            }
+            Self::Mark(v) | Self::MarkMask(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::NatIpv4Min(v) | Self::NatIpv4Max(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::NatIpv6Min(v) | Self::NatIpv6Max(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::Labels(v) | Self::LabelsMask(v) => buffer.copy_from_slice(v),
+            Self::HelperName(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::HelperFamily(v) => buffer[0] = (*v).into(),
+            Self::HelperProto(v) => buffer[0] = i32::from(*v) as u8,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Parms(_) => TCA_CT_PARMS,
+            Self::Tm(_) => TCA_CT_TM,
+            Self::Action(_) => TCA_CT_ACTION,
+            Self::Zone(_) => TCA_CT_ZONE,
+            Self::Mark(_) => TCA_CT_MARK,
+            Self::MarkMask(_) => TCA_CT_MARK_MASK,
+            Self::Labels(_) => TCA_CT_LABELS,
+            Self::LabelsMask(_) => TCA_CT_LABELS_MASK,
+            Self::NatIpv4Min(_) => TCA_CT_NAT_IPV4_MIN,
+            Self::NatIpv4Max(_) => TCA_CT_NAT_IPV4_MAX,
+            Self::NatIpv6Min(_) => TCA_CT_NAT_IPV6_MIN,
+            Self::NatIpv6Max(_) => TCA_CT_NAT_IPV6_MAX,
+            Self::NatPortMin(_) => TCA_CT_NAT_PORT_MIN,
+            Self::NatPortMax(_) => TCA_CT_NAT_PORT_MAX,
+            Self::HelperName(_) => TCA_CT_HELPER_NAME,
+            Self::HelperFamily(_) => TCA_CT_HELPER_FAMILY,
+            Self::HelperProto(_) => TCA_CT_HELPER_PROTO,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionCtOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_CT_PARMS => Self::Parms(TcActionGeneric::parse(
+                &TcActionGenericBuffer::new_checked(payload)?,
+            )?),
+            TCA_CT_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_CT_ACTION => Self::Action(TcCtActionFlags::from_bits_retain(
+                parse_u16(payload)?,
+            )),
+            TCA_CT_ZONE => Self::Zone(parse_u16(payload)?),
+            TCA_CT_MARK => Self::Mark(parse_u32(payload)?),
+            TCA_CT_MARK_MASK => Self::MarkMask(parse_u32(payload)?),
+            TCA_CT_LABELS => Self::Labels(parse_ct_labels(payload)?),
+            TCA_CT_LABELS_MASK => Self::LabelsMask(parse_ct_labels(payload)?),
+            TCA_CT_NAT_IPV4_MIN => Self::NatIpv4Min(parse_ipv4_addr(payload)?),
+            TCA_CT_NAT_IPV4_MAX => Self::NatIpv4Max(parse_ipv4_addr(payload)?),
+            TCA_CT_NAT_IPV6_MIN => Self::NatIpv6Min(parse_ipv6_addr(payload)?),
+            TCA_CT_NAT_IPV6_MAX => Self::NatIpv6Max(parse_ipv6_addr(payload)?),
+            TCA_CT_NAT_PORT_MIN => Self::NatPortMin(parse_u16_be(payload)?),
+            TCA_CT_NAT_PORT_MAX => Self::NatPortMax(parse_u16_be(payload)?),
+            TCA_CT_HELPER_NAME => Self::HelperName(parse_string(payload)?),
+            TCA_CT_HELPER_FAMILY => {
+                Self::HelperFamily(parse_u8(payload)?.into())
+            }
+            TCA_CT_HELPER_PROTO => {
+                Self::HelperProto(IpProtocol::from(parse_u8(payload)? as i32))
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn parse_ct_labels(payload: &[u8]) -> Result<[u8; CT_LABELS_LEN], DecodeError> {
+    if payload.len() != CT_LABELS_LEN {
+        return Err(AxError::InvalidInput);
+    }
+    let mut labels = [0u8; CT_LABELS_LEN];
+    labels.copy_from_slice(payload);
+    Ok(labels)
+}
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+
+use axerrno::AxError;
+use bitflags::Flags;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::DecodeError;
+
+const TCA_ACT_FLAGS_NO_PERCPU_STATS: u32 = 1 << 0;
+const TCA_ACT_FLAGS_SKIP_HW: u32 = 1 << 1;
+const TCA_ACT_FLAGS_SKIP_SW: u32 = 1 << 2;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcActionFlags: u32 {
+        /// Use a single set of statistics counters instead of per-CPU ones
+        const NoPercpuStats = TCA_ACT_FLAGS_NO_PERCPU_STATS;
+        const SkipHw = TCA_ACT_FLAGS_SKIP_HW;
+        const SkipSw = TCA_ACT_FLAGS_SKIP_SW;
+        const _ = !0;
+    }
+}
+
+impl Default for TcActionFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const TCA_ACT_HW_STATS_IMMEDIATE: u32 = 1 << 0;
+const TCA_ACT_HW_STATS_DELAYED: u32 = 1 << 1;
+
+bitflags! {
+    /// Type of hardware statistics of offloaded action
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcActionHwStats: u32 {
+        const Immediate = TCA_ACT_HW_STATS_IMMEDIATE;
+        const Delayed = TCA_ACT_HW_STATS_DELAYED;
+        const _ = !0;
+    }
+}
+
+impl Default for TcActionHwStats {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const TCA_ACT_FLAG_LARGE_DUMP_ON: u32 = 1 << 0;
+const TCA_ACT_FLAG_TERSE_DUMP: u32 = 1 << 1;
+
+bitflags! {
+    /// Flags of action dump request(`TCA_ROOT_FLAGS`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcActionRootFlags: u32 {
+        /// Allow more than 32 actions in a single dump message
+        const LargeDump = TCA_ACT_FLAG_LARGE_DUMP_ON;
+        /// Only dump the kind, index, stats and cookie of actions
+        const TerseDump = TCA_ACT_FLAG_TERSE_DUMP;
+        const _ = !0;
+    }
+}
+
+impl Default for TcActionRootFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+pub(crate) const NLA_BITFIELD32_LEN: usize = 8;
+
+/// The `struct nla_bitfield32`. Only the bits set in `selector` are
+/// valid in `value`, the kernel ignores or leaves unchanged the others.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Default)]
+#[non_exhaustive]
+pub struct TcBitfield32<F> {
+    pub value: F,
+    pub selector: F,
+}
+
+impl<F: Flags<Bits = u32>> TcBitfield32<F> {
+    pub fn new(value: F, selector: F) -> Self {
+        Self { value, selector }
+    }
+
+    pub(crate) fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() != NLA_BITFIELD32_LEN {
+            return Err(AxError::InvalidInput);
+        }
+        Ok(Self {
+            value: F::from_bits_retain(NativeEndian::read_u32(&payload[..4])),
+            selector: F::from_bits_retain(NativeEndian::read_u32(
+                &payload[4..],
+            )),
+        })
+    }
+
+    pub(crate) fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u32(&mut buffer[..4], self.value.bits());
+        NativeEndian::write_u32(
+            &mut buffer[4..NLA_BITFIELD32_LEN],
+            self.selector.bits(),
+        );
+    }
+}
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    TcActionGeneric, TcActionGenericBuffer, TcActionTimestamp,
+    TcActionTimestampBuffer, TcActionType,
+};
+
+const TCA_GACT_TM: u16 = 1;
+const TCA_GACT_PARMS: u16 = 2;
+const TCA_GACT_PROB: u16 = 3;
+// const TCA_GACT_PAD: u16 = 4;
+
+/// Options of `gact` action which applies a verdict to the packet
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionGactOption {
+    Tm(TcActionTimestamp),
+    Parms(TcActionGeneric),
+    Prob(TcGactProbability),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionGactOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::Prob(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(v) => v.emit(buffer),
+            Self::Prob(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_GACT_TM,
+            Self::Parms(_) => TCA_GACT_PARMS,
+            Self::Prob(_) => TCA_GACT_PROB,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionGactOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_GACT_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_GACT_PARMS => Self::Parms(TcActionGeneric::parse(
+                &TcActionGenericBuffer::new_checked(payload)?,
+            )?),
+            TCA_GACT_PROB => Self::Prob(TcGactProbability::parse(
+                &TcGactProbabilityBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const PGACT_NONE: u16 = 0;
+const PGACT_NETRAND: u16 = 1;
+const PGACT_DETERM: u16 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcGactProbabilityType {
+    #[default]
+    None,
+    /// Apply the alternative verdict randomly
+    NetRand,
+    /// Apply the alternative verdict to every `pval`-th packet
+    Determ,
+    Other(u16),
+}
+
+impl From<u16> for TcGactProbabilityType {
+    fn from(d: u16) -> Self {
+        match d {
+            PGACT_NONE => Self::None,
+            PGACT_NETRAND => Self::NetRand,
+            PGACT_DETERM => Self::Determ,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcGactProbabilityType> for u16 {
+    fn from(v: TcGactProbabilityType) -> u16 {
+        match v {
+            TcGactProbabilityType::None => PGACT_NONE,
+            TcGactProbabilityType::NetRand => PGACT_NETRAND,
+            TcGactProbabilityType::Determ => PGACT_DETERM,
+            TcGactProbabilityType::Other(d) => d,
+        }
+    }
+}
+
+const TC_GACT_P_LEN: usize = 8;
+
+buffer!(TcGactProbabilityBuffer(TC_GACT_P_LEN) {
+    ptype: (u16, 0..2),
+    pval: (u16, 2..4),
+    paction: (i32, 4..TC_GACT_P_LEN),
+});
+
+/// Probabilistic verdict(`struct tc_gact_p`) of `gact` action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcGactProbability {
+    pub ptype: TcGactProbabilityType,
+    /// Probability in 1/10000 for [TcGactProbabilityType::NetRand], or
+    /// the period for [TcGactProbabilityType::Determ]
+    pub pval: u16,
+    /// Alternative verdict
+    pub paction: TcActionType,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcGactProbabilityBuffer<T>>
+    for TcGactProbability
+{
+    fn parse(buf: &TcGactProbabilityBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            ptype: buf.ptype().into(),
+            pval: buf.pval(),
+            paction: buf.paction().into(),
+        })
+    }
+}
+
+impl Emitable for TcGactProbability {
+    fn buffer_len(&self) -> usize {
+        TC_GACT_P_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcGactProbabilityBuffer::new(buffer);
+        buffer.set_ptype(self.ptype.into());
+        buffer.set_pval(self.pval);
+        buffer.set_paction(self.paction.into());
+    }
+}
@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TC_ACT_UNSPEC: i32 = -1;
+const TC_ACT_OK: i32 = 0;
+const TC_ACT_RECLASSIFY: i32 = 1;
+const TC_ACT_SHOT: i32 = 2;
+const TC_ACT_PIPE: i32 = 3;
+const TC_ACT_STOLEN: i32 = 4;
+const TC_ACT_QUEUED: i32 = 5;
+const TC_ACT_REPEAT: i32 = 6;
+const TC_ACT_REDIRECT: i32 = 7;
+const TC_ACT_TRAP: i32 = 8;
+
+/// Verdict(`TC_ACT_*`) of an action. Verdicts carrying an operand like
+/// `TC_ACT_JUMP` and `TC_ACT_GOTO_CHAIN` are stored in [Self::Other].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcActionType {
+    Unspec,
+    /// Finish the classification and accept the packet
+    #[default]
+    Ok,
+    Reclassify,
+    /// Drop the packet
+    Shot,
+    /// Continue with the next action
+    Pipe,
+    Stolen,
+    Queued,
+    Repeat,
+    Redirect,
+    Trap,
+    Other(i32),
+}
+
+impl From<i32> for TcActionType {
+    fn from(d: i32) -> Self {
+        match d {
+            TC_ACT_UNSPEC => Self::Unspec,
+            TC_ACT_OK => Self::Ok,
+            TC_ACT_RECLASSIFY => Self::Reclassify,
+            TC_ACT_SHOT => Self::Shot,
+            TC_ACT_PIPE => Self::Pipe,
+            TC_ACT_STOLEN => Self::Stolen,
+            TC_ACT_QUEUED => Self::Queued,
+            TC_ACT_REPEAT => Self::Repeat,
+            TC_ACT_REDIRECT => Self::Redirect,
+            TC_ACT_TRAP => Self::Trap,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcActionType> for i32 {
+    fn from(v: TcActionType) -> i32 {
+        match v {
+            TcActionType::Unspec => TC_ACT_UNSPEC,
+            TcActionType::Ok => TC_ACT_OK,
+            TcActionType::Reclassify => TC_ACT_RECLASSIFY,
+            TcActionType::Shot => TC_ACT_SHOT,
+            TcActionType::Pipe => TC_ACT_PIPE,
+            TcActionType::Stolen => TC_ACT_STOLEN,
+            TcActionType::Queued => TC_ACT_QUEUED,
+            TcActionType::Repeat => TC_ACT_REPEAT,
+            TcActionType::Redirect => TC_ACT_REDIRECT,
+            TcActionType::Trap => TC_ACT_TRAP,
+            TcActionType::Other(d) => d,
+        }
+    }
+}
+
+pub(crate) const TC_GEN_LEN: usize = 20;
+
+buffer!(TcActionGenericBuffer(TC_GEN_LEN) {
+    index: (u32, 0..4),
+    capab: (u32, 4..8),
+    action: (i32, 8..12),
+    refcnt: (i32, 12..16),
+    bindcnt: (i32, 16..TC_GEN_LEN),
+});
+
+/// Common parameters(`tc_gen`) leading the `TCA_*_PARMS` of every action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcActionGeneric {
+    /// Index of the action instance, 0 lets kernel allocate one
+    pub index: u32,
+    pub capab: u32,
+    pub action: TcActionType,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcActionGenericBuffer<T>> for TcActionGeneric {
+    fn parse(buf: &TcActionGenericBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            capab: buf.capab(),
+            action: buf.action().into(),
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+        })
+    }
+}
+
+impl Emitable for TcActionGeneric {
+    fn buffer_len(&self) -> usize {
+        TC_GEN_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcActionGenericBuffer::new(buffer);
+        buffer.set_index(self.index);
+        buffer.set_capab(self.capab);
+        buffer.set_action(self.action.into());
+        buffer.set_refcnt(self.refcnt);
+        buffer.set_bindcnt(self.bindcnt);
+    }
+}
+
+const TCF_T_LEN: usize = 32;
+
+buffer!(TcActionTimestampBuffer(TCF_T_LEN) {
+    install: (u64, 0..8),
+    lastuse: (u64, 8..16),
+    expires: (u64, 16..24),
+    firstuse: (u64, 24..32),
+});
+
+/// Timestamps(`struct tcf_t`) of an action in jiffies, reported by
+/// kernel in `TCA_*_TM`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcActionTimestamp {
+    pub install: u64,
+    pub lastuse: u64,
+    pub expires: u64,
+    pub firstuse: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcActionTimestampBuffer<T>>
+    for TcActionTimestamp
+{
+    fn parse(buf: &TcActionTimestampBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            install: buf.install(),
+            lastuse: buf.lastuse(),
+            expires: buf.expires(),
+            firstuse: buf.firstuse(),
+        })
+    }
+}
+
+impl Emitable for TcActionTimestamp {
+    fn buffer_len(&self) -> usize {
+        TCF_T_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcActionTimestampBuffer::new(buffer);
+        buffer.set_install(self.install);
+        buffer.set_lastuse(self.lastuse);
+        buffer.set_expires(self.expires);
+        buffer.set_firstuse(self.firstuse);
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const TC_ACTION_HEADER_LEN: usize = 4;
+
+buffer!(TcActionMessageBuffer(TC_ACTION_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..TC_ACTION_HEADER_LEN),
+    payload: (slice, TC_ACTION_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> TcActionMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `struct tcamsg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcActionMessageHeader {
+    pub family: AddressFamily,
+}
+
+impl Emitable for TcActionMessageHeader {
+    fn buffer_len(&self) -> usize {
+        TC_ACTION_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcActionMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcActionMessageBuffer<T>>
+    for TcActionMessageHeader
+{
+    fn parse(buf: &TcActionMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+        })
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    flags::NLA_BITFIELD32_LEN, TcAction, TcActionMessageBuffer,
+    TcActionMessageHeader, TcActionRootFlags, TcBitfield32,
+};
+
+const TCA_ROOT_TAB: u16 = 1;
+const TCA_ROOT_FLAGS: u16 = 2;
+const TCA_ROOT_COUNT: u16 = 3;
+const TCA_ROOT_TIME_DELTA: u16 = 4;
+const TCA_ROOT_EXT_WARN_MSG: u16 = 5;
+
+/// Message of `RTM_NEWACTION`, `RTM_DELACTION` and `RTM_GETACTION` for
+/// actions not bound to any filter.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcActionMessage {
+    pub header: TcActionMessageHeader,
+    pub attributes: Vec<TcActionMessageAttribute>,
+}
+
+impl Emitable for TcActionMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcActionMessageBuffer<&'a T>>
+    for TcActionMessage
+{
+    fn parse(buf: &TcActionMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = TcActionMessageHeader::parse(buf)?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(TcActionMessageAttribute::parse(&nla_buf?)?);
+        }
+        Ok(Self { header, attributes })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionMessageAttribute {
+    Actions(Vec<TcAction>),
+    Flags(TcBitfield32<TcActionRootFlags>),
+    /// Number of actions in this dump message
+    RootCount(u32),
+    /// Only dump actions used within this many milliseconds
+    RootTimeDelta(u32),
+    /// Warning message from kernel
+    RootExtWarnMsg(String),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionMessageAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Actions(v) => v.as_slice().buffer_len(),
+            Self::Flags(_) => NLA_BITFIELD32_LEN,
+            Self::RootCount(_) | Self::RootTimeDelta(_) => 4,
+            Self::RootExtWarnMsg(s) => s.len() + 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Actions(v) => v.as_slice().emit(buffer),
+            Self::Flags(v) => v.emit(buffer),
+            Self::RootCount(v) | Self::RootTimeDelta(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::RootExtWarnMsg(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Actions(_) => TCA_ROOT_TAB | NLA_F_NESTED,
+            Self::Flags(_) => TCA_ROOT_FLAGS,
+            Self::RootCount(_) => TCA_ROOT_COUNT,
+            Self::RootTimeDelta(_) => TCA_ROOT_TIME_DELTA,
+            Self::RootExtWarnMsg(_) => TCA_ROOT_EXT_WARN_MSG,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionMessageAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ROOT_TAB => Self::Actions(parse_actions(payload)?),
+            TCA_ROOT_FLAGS => Self::Flags(TcBitfield32::parse(payload)?),
+            TCA_ROOT_COUNT => Self::RootCount(parse_u32(payload)?),
+            TCA_ROOT_TIME_DELTA => Self::RootTimeDelta(parse_u32(payload)?),
+            TCA_ROOT_EXT_WARN_MSG => {
+                Self::RootExtWarnMsg(parse_string(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// Parse the nested list of actions used by `TCA_ROOT_TAB` and the
+/// `TCA_*_ACT` attributes of filters.
+pub(crate) fn parse_actions(
+    payload: &[u8],
+) -> Result<Vec<TcAction>, DecodeError> {
+    let mut actions = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        actions.push(TcAction::parse(&nla?)?);
+    }
+    Ok(actions)
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    generic::TC_GEN_LEN, TcActionGeneric, TcActionGenericBuffer,
+    TcActionTimestamp, TcActionTimestampBuffer,
+};
+
+const TCA_MIRRED_TM: u16 = 1;
+const TCA_MIRRED_PARMS: u16 = 2;
+// const TCA_MIRRED_PAD: u16 = 3;
+const TCA_MIRRED_BLOCKID: u16 = 4;
+
+/// Options of `mirred` action which redirects or mirrors the packet to
+/// another interface
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionMirredOption {
+    Tm(TcActionTimestamp),
+    Parms(TcMirred),
+    /// Shared block to redirect or mirror to instead of an interface
+    BlockId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionMirredOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::BlockId(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(v) => v.emit(buffer),
+            Self::BlockId(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_MIRRED_TM,
+            Self::Parms(_) => TCA_MIRRED_PARMS,
+            Self::BlockId(_) => TCA_MIRRED_BLOCKID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionMirredOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_MIRRED_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_MIRRED_PARMS => Self::Parms(TcMirred::parse(
+                &TcMirredBuffer::new_checked(payload)?,
+            )?),
+            TCA_MIRRED_BLOCKID => Self::BlockId(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TCA_EGRESS_REDIR: i32 = 1;
+const TCA_EGRESS_MIRROR: i32 = 2;
+const TCA_INGRESS_REDIR: i32 = 3;
+const TCA_INGRESS_MIRROR: i32 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcMirredActionType {
+    #[default]
+    EgressRedir,
+    EgressMirror,
+    IngressRedir,
+    IngressMirror,
+    Other(i32),
+}
+
+impl From<i32> for TcMirredActionType {
+    fn from(d: i32) -> Self {
+        match d {
+            TCA_EGRESS_REDIR => Self::EgressRedir,
+            TCA_EGRESS_MIRROR => Self::EgressMirror,
+            TCA_INGRESS_REDIR => Self::IngressRedir,
+            TCA_INGRESS_MIRROR => Self::IngressMirror,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcMirredActionType> for i32 {
+    fn from(v: TcMirredActionType) -> i32 {
+        match v {
+            TcMirredActionType::EgressRedir => TCA_EGRESS_REDIR,
+            TcMirredActionType::EgressMirror => TCA_EGRESS_MIRROR,
+            TcMirredActionType::IngressRedir => TCA_INGRESS_REDIR,
+            TcMirredActionType::IngressMirror => TCA_INGRESS_MIRROR,
+            TcMirredActionType::Other(d) => d,
+        }
+    }
+}
+
+const TC_MIRRED_LEN: usize = TC_GEN_LEN + 8;
+
+buffer!(TcMirredBuffer(TC_MIRRED_LEN) {
+    generic: (slice, 0..TC_GEN_LEN),
+    eaction: (i32, TC_GEN_LEN..(TC_GEN_LEN + 4)),
+    ifindex: (u32, (TC_GEN_LEN + 4)..TC_MIRRED_LEN),
+});
+
+/// Parameters(`struct tc_mirred`) of `mirred` action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcMirred {
+    pub generic: TcActionGeneric,
+    pub eaction: TcMirredActionType,
+    /// Interface index to redirect or mirror to
+    pub ifindex: u32,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcMirredBuffer<&'a T>>
+    for TcMirred
+{
+    fn parse(buf: &TcMirredBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+            eaction: buf.eaction().into(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
+
+impl Emitable for TcMirred {
+    fn buffer_len(&self) -> usize {
+        TC_MIRRED_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcMirredBuffer::new(buffer);
+        self.generic.emit(buffer.generic_mut());
+        buffer.set_eaction(self.eaction.into());
+        buffer.set_ifindex(self.ifindex);
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+
+mod action;
+mod csum;
+mod ct;
+mod flags;
+mod gact;
+mod generic;
+mod header;
+mod message;
+mod mirred;
+mod options;
+mod pedit;
+mod police;
+mod skbedit;
+mod tunnel_key;
+mod vlan;
+
+pub(crate) use self::message::parse_actions;
+
+pub use self::action::{TcAction, TcActionAttribute};
+pub use self::csum::{
+    TcActionCsumOption, TcCsum, TcCsumBuffer, TcCsumUpdateFlags,
+};
+pub use self::ct::{TcActionCtOption, TcCtActionFlags};
+pub use self::flags::{
+    TcActionFlags, TcActionHwStats, TcActionRootFlags, TcBitfield32,
+};
+pub use self::gact::{
+    TcActionGactOption, TcGactProbability, TcGactProbabilityBuffer,
+    TcGactProbabilityType,
+};
+pub use self::generic::{
+    TcActionGeneric, TcActionGenericBuffer, TcActionTimestamp,
+    TcActionTimestampBuffer, TcActionType,
+};
+pub use self::header::{TcActionMessageBuffer, TcActionMessageHeader};
+pub use self::message::{TcActionMessage, TcActionMessageAttribute};
+pub use self::mirred::{
+    TcActionMirredOption, TcMirred, TcMirredActionType, TcMirredBuffer,
+};
+pub use self::options::TcActionOptions;
+pub use self::pedit::{
+    TcActionPeditOption, TcPeditCmd, TcPeditHeaderType, TcPeditKey,
+    TcPeditKeyBuffer, TcPeditKeyEx, TcPeditSelector, TcPeditSelectorBuffer,
+};
+pub use self::police::{TcActionPoliceOption, TcPolice, TcPoliceBuffer};
+pub use self::skbedit::{TcActionSkbEditOption, TcSkbEditFlags};
+pub use self::tunnel_key::{
+    TcActionTunnelKeyOption, TcTunnelKey, TcTunnelKeyActionType,
+    TcTunnelKeyBuffer, TcTunnelKeyEncOpt, TcTunnelKeyErspanOpt,
+    TcTunnelKeyGeneveOpt, TcTunnelKeyVxlanOpt,
+};
+pub use self::vlan::{
+    TcActionVlanOption, TcVlan, TcVlanActionType, TcVlanBuffer,
+};
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{DecodeError, Emitable};
+
+use super::{
+    TcActionCsumOption, TcActionCtOption, TcActionGactOption,
+    TcActionMirredOption, TcActionPeditOption, TcActionPoliceOption,
+    TcActionSkbEditOption, TcActionTunnelKeyOption, TcActionVlanOption,
+};
+use crate::tc::options::parse_nlas;
+
+const GACT: &str = "gact";
+const MIRRED: &str = "mirred";
+const POLICE: &str = "police";
+const VLAN: &str = "vlan";
+const TUNNEL_KEY: &str = "tunnel_key";
+const PEDIT: &str = "pedit";
+const SKBEDIT: &str = "skbedit";
+const CSUM: &str = "csum";
+const CT: &str = "ct";
+
+/// The payload of `TCA_ACT_OPTIONS`, its layout depends on the preceding
+/// `TCA_ACT_KIND`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionOptions {
+    Gact(Vec<TcActionGactOption>),
+    Mirred(Vec<TcActionMirredOption>),
+    Police(Vec<TcActionPoliceOption>),
+    Vlan(Vec<TcActionVlanOption>),
+    TunnelKey(Vec<TcActionTunnelKeyOption>),
+    Pedit(Vec<TcActionPeditOption>),
+    SkbEdit(Vec<TcActionSkbEditOption>),
+    Csum(Vec<TcActionCsumOption>),
+    Ct(Vec<TcActionCtOption>),
+    Other(Vec<u8>),
+}
+
+impl Emitable for TcActionOptions {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Gact(nlas) => nlas.as_slice().buffer_len(),
+            Self::Mirred(nlas) => nlas.as_slice().buffer_len(),
+            Self::Police(nlas) => nlas.as_slice().buffer_len(),
+            Self::Vlan(nlas) => nlas.as_slice().buffer_len(),
+            Self::TunnelKey(nlas) => nlas.as_slice().buffer_len(),
+            Self::Pedit(nlas) => nlas.as_slice().buffer_len(),
+            Self::SkbEdit(nlas) => nlas.as_slice().buffer_len(),
+            Self::Csum(nlas) => nlas.as_slice().buffer_len(),
+            Self::Ct(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(v) => v.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Gact(nlas) => nlas.as_slice().emit(buffer),
+            Self::Mirred(nlas) => nlas.as_slice().emit(buffer),
+            Self::Police(nlas) => nlas.as_slice().emit(buffer),
+            Self::Vlan(nlas) => nlas.as_slice().emit(buffer),
+            Self::TunnelKey(nlas) => nlas.as_slice().emit(buffer),
+            Self::Pedit(nlas) => nlas.as_slice().emit(buffer),
+            Self::SkbEdit(nlas) => nlas.as_slice().emit(buffer),
+            Self::Csum(nlas) => nlas.as_slice().emit(buffer),
+            Self::Ct(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
+        }
+    }
+}
+
+impl TcActionOptions {
+    pub(crate) fn parse_with_param(
+        payload: &[u8],
+        kind: &str,
+    ) -> Result<TcActionOptions, DecodeError> {
+        Ok(match kind {
+            GACT => TcActionOptions::Gact(parse_nlas(payload)?),
+            MIRRED => TcActionOptions::Mirred(parse_nlas(payload)?),
+            POLICE => TcActionOptions::Police(parse_nlas(payload)?),
+            VLAN => TcActionOptions::Vlan(parse_nlas(payload)?),
+            TUNNEL_KEY => TcActionOptions::TunnelKey(parse_nlas(payload)?),
+            PEDIT => TcActionOptions::Pedit(parse_nlas(payload)?),
+            SKBEDIT => TcActionOptions::SkbEdit(parse_nlas(payload)?),
+            CSUM => TcActionOptions::Csum(parse_nlas(payload)?),
+            CT => TcActionOptions::Ct(parse_nlas(payload)?),
+            _ => TcActionOptions::Other(payload.to_vec()),
+        })
+    }
+}
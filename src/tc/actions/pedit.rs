@@ -0,0 +1,385 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use axerrno::AxError;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::parse_u16,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    generic::TC_GEN_LEN, TcActionGeneric, TcActionGenericBuffer,
+    TcActionTimestamp, TcActionTimestampBuffer,
+};
+
+const TCA_PEDIT_TM: u16 = 1;
+const TCA_PEDIT_PARMS: u16 = 2;
+// const TCA_PEDIT_PAD: u16 = 3;
+const TCA_PEDIT_PARMS_EX: u16 = 4;
+const TCA_PEDIT_KEYS_EX: u16 = 5;
+const TCA_PEDIT_KEY_EX: u16 = 6;
+
+/// Options of `pedit` action which edits the packet data
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionPeditOption {
+    Tm(TcActionTimestamp),
+    Parms(TcPeditSelector),
+    /// Same as [Self::Parms], used along with [Self::KeysEx]
+    ParmsEx(TcPeditSelector),
+    /// Extended information of each key in [Self::ParmsEx], in the same
+    /// order
+    KeysEx(Vec<TcPeditKeyEx>),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionPeditOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) | Self::ParmsEx(v) => v.buffer_len(),
+            Self::KeysEx(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(v) | Self::ParmsEx(v) => v.emit(buffer),
+            Self::KeysEx(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_PEDIT_TM,
+            Self::Parms(_) => TCA_PEDIT_PARMS,
+            Self::ParmsEx(_) => TCA_PEDIT_PARMS_EX,
+            Self::KeysEx(_) => TCA_PEDIT_KEYS_EX | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionPeditOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_PEDIT_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_PEDIT_PARMS => Self::Parms(TcPeditSelector::parse(
+                &TcPeditSelectorBuffer::new_checked(payload)?,
+            )?),
+            TCA_PEDIT_PARMS_EX => Self::ParmsEx(TcPeditSelector::parse(
+                &TcPeditSelectorBuffer::new_checked(payload)?,
+            )?),
+            TCA_PEDIT_KEYS_EX => {
+                let mut keys = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    keys.push(TcPeditKeyEx::parse(&nla?)?);
+                }
+                Self::KeysEx(keys)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TC_PEDIT_SEL_LEN: usize = TC_GEN_LEN + 4;
+const TC_PEDIT_KEY_LEN: usize = 24;
+
+buffer!(TcPeditSelectorBuffer(TC_PEDIT_SEL_LEN) {
+    generic: (slice, 0..TC_GEN_LEN),
+    nkeys: (u8, TC_GEN_LEN),
+    flags: (u8, TC_GEN_LEN + 1),
+    pad: (u16, (TC_GEN_LEN + 2)..TC_PEDIT_SEL_LEN),
+    keys: (slice, TC_PEDIT_SEL_LEN..),
+});
+
+/// Parameters(`struct tc_pedit_sel`) of `pedit` action
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcPeditSelector {
+    pub generic: TcActionGeneric,
+    pub flags: u8,
+    keys: Vec<TcPeditKey>,
+}
+
+impl TcPeditSelector {
+    /// The number of keys is stored in a `u8`
+    pub const MAX_KEYS: usize = u8::MAX as usize;
+
+    pub fn keys(&self) -> &[TcPeditKey] {
+        self.keys.as_slice()
+    }
+
+    pub fn keys_mut(&mut self) -> &mut [TcPeditKey] {
+        self.keys.as_mut_slice()
+    }
+
+    /// Append a key, fails when the selector already holds
+    /// [Self::MAX_KEYS] keys.
+    pub fn push_key(&mut self, key: TcPeditKey) -> Result<(), AxError> {
+        if self.keys.len() >= Self::MAX_KEYS {
+            return Err(AxError::InvalidInput);
+        }
+        self.keys.push(key);
+        Ok(())
+    }
+
+    /// Replace all keys, fails when there are more than [Self::MAX_KEYS]
+    /// keys.
+    pub fn set_keys(&mut self, keys: Vec<TcPeditKey>) -> Result<(), AxError> {
+        if keys.len() > Self::MAX_KEYS {
+            return Err(AxError::InvalidInput);
+        }
+        self.keys = keys;
+        Ok(())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcPeditSelectorBuffer<&'a T>>
+    for TcPeditSelector
+{
+    fn parse(buf: &TcPeditSelectorBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let nkeys = buf.nkeys() as usize;
+        let payload = buf.keys();
+        if payload.len() < nkeys * TC_PEDIT_KEY_LEN {
+            return Err(AxError::InvalidInput);
+        }
+        let mut keys = Vec::with_capacity(nkeys);
+        for key_buf in payload.chunks_exact(TC_PEDIT_KEY_LEN).take(nkeys) {
+            keys.push(TcPeditKey::parse(&TcPeditKeyBuffer::new(key_buf))?);
+        }
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+            flags: buf.flags(),
+            keys,
+        })
+    }
+}
+
+impl Emitable for TcPeditSelector {
+    fn buffer_len(&self) -> usize {
+        TC_PEDIT_SEL_LEN + self.keys.len() * TC_PEDIT_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcPeditSelectorBuffer::new(buffer);
+        self.generic.emit(buffer.generic_mut());
+        // Bounded by `MAX_KEYS`
+        buffer.set_nkeys(self.keys.len() as u8);
+        buffer.set_flags(self.flags);
+        buffer.set_pad(0);
+        for (key, key_buf) in self
+            .keys
+            .iter()
+            .zip(buffer.keys_mut().chunks_exact_mut(TC_PEDIT_KEY_LEN))
+        {
+            key.emit(key_buf);
+        }
+    }
+}
+
+buffer!(TcPeditKeyBuffer(TC_PEDIT_KEY_LEN) {
+    mask: (u32, 0..4),
+    val: (u32, 4..8),
+    off: (u32, 8..12),
+    at: (u32, 12..16),
+    offmask: (u32, 16..20),
+    shift: (u32, 20..TC_PEDIT_KEY_LEN),
+});
+
+/// Key(`struct tc_pedit_key`) of `pedit` action, which rewrites the 32
+/// bits word at `off` to `(word & mask) ^ val`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcPeditKey {
+    pub mask: u32,
+    pub val: u32,
+    pub off: u32,
+    pub at: u32,
+    pub offmask: u32,
+    pub shift: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcPeditKeyBuffer<T>> for TcPeditKey {
+    fn parse(buf: &TcPeditKeyBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mask: buf.mask(),
+            val: buf.val(),
+            off: buf.off(),
+            at: buf.at(),
+            offmask: buf.offmask(),
+            shift: buf.shift(),
+        })
+    }
+}
+
+impl Emitable for TcPeditKey {
+    fn buffer_len(&self) -> usize {
+        TC_PEDIT_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcPeditKeyBuffer::new(buffer);
+        buffer.set_mask(self.mask);
+        buffer.set_val(self.val);
+        buffer.set_off(self.off);
+        buffer.set_at(self.at);
+        buffer.set_offmask(self.offmask);
+        buffer.set_shift(self.shift);
+    }
+}
+
+const TCA_PEDIT_KEY_EX_HTYPE: u16 = 1;
+const TCA_PEDIT_KEY_EX_CMD: u16 = 2;
+
+/// Extended key(`TCA_PEDIT_KEY_EX`) of `pedit` action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcPeditKeyEx {
+    /// Header the offset of key is relative to
+    pub htype: TcPeditHeaderType,
+    pub cmd: TcPeditCmd,
+}
+
+impl TcPeditKeyEx {
+    fn attributes(&self) -> [DefaultNla; 2] {
+        [
+            DefaultNla::new(
+                TCA_PEDIT_KEY_EX_HTYPE,
+                u16::from(self.htype).to_ne_bytes().to_vec(),
+            ),
+            DefaultNla::new(
+                TCA_PEDIT_KEY_EX_CMD,
+                u16::from(self.cmd).to_ne_bytes().to_vec(),
+            ),
+        ]
+    }
+}
+
+impl Nla for TcPeditKeyEx {
+    fn value_len(&self) -> usize {
+        self.attributes().as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.attributes().as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        TCA_PEDIT_KEY_EX | NLA_F_NESTED
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcPeditKeyEx {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        if buf.kind() != TCA_PEDIT_KEY_EX {
+            return Err(AxError::InvalidInput);
+        }
+        let mut key = Self::default();
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = nla?;
+            match nla.kind() {
+                TCA_PEDIT_KEY_EX_HTYPE => {
+                    key.htype = parse_u16(nla.value())?.into()
+                }
+                TCA_PEDIT_KEY_EX_CMD => {
+                    key.cmd = parse_u16(nla.value())?.into()
+                }
+                _ => (),
+            }
+        }
+        Ok(key)
+    }
+}
+
+const TCA_PEDIT_KEY_EX_HDR_TYPE_NETWORK: u16 = 0;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_ETH: u16 = 1;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_IP4: u16 = 2;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_IP6: u16 = 3;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_TCP: u16 = 4;
+const TCA_PEDIT_KEY_EX_HDR_TYPE_UDP: u16 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcPeditHeaderType {
+    #[default]
+    Network,
+    Eth,
+    Ip4,
+    Ip6,
+    Tcp,
+    Udp,
+    Other(u16),
+}
+
+impl From<u16> for TcPeditHeaderType {
+    fn from(d: u16) -> Self {
+        match d {
+            TCA_PEDIT_KEY_EX_HDR_TYPE_NETWORK => Self::Network,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_ETH => Self::Eth,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_IP4 => Self::Ip4,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_IP6 => Self::Ip6,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_TCP => Self::Tcp,
+            TCA_PEDIT_KEY_EX_HDR_TYPE_UDP => Self::Udp,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcPeditHeaderType> for u16 {
+    fn from(v: TcPeditHeaderType) -> u16 {
+        match v {
+            TcPeditHeaderType::Network => TCA_PEDIT_KEY_EX_HDR_TYPE_NETWORK,
+            TcPeditHeaderType::Eth => TCA_PEDIT_KEY_EX_HDR_TYPE_ETH,
+            TcPeditHeaderType::Ip4 => TCA_PEDIT_KEY_EX_HDR_TYPE_IP4,
+            TcPeditHeaderType::Ip6 => TCA_PEDIT_KEY_EX_HDR_TYPE_IP6,
+            TcPeditHeaderType::Tcp => TCA_PEDIT_KEY_EX_HDR_TYPE_TCP,
+            TcPeditHeaderType::Udp => TCA_PEDIT_KEY_EX_HDR_TYPE_UDP,
+            TcPeditHeaderType::Other(d) => d,
+        }
+    }
+}
+
+const TCA_PEDIT_KEY_EX_CMD_SET: u16 = 0;
+const TCA_PEDIT_KEY_EX_CMD_ADD: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcPeditCmd {
+    #[default]
+    Set,
+    Add,
+    Other(u16),
+}
+
+impl From<u16> for TcPeditCmd {
+    fn from(d: u16) -> Self {
+        match d {
+            TCA_PEDIT_KEY_EX_CMD_SET => Self::Set,
+            TCA_PEDIT_KEY_EX_CMD_ADD => Self::Add,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcPeditCmd> for u16 {
+    fn from(v: TcPeditCmd) -> u16 {
+        match v {
+            TcPeditCmd::Set => TCA_PEDIT_KEY_EX_CMD_SET,
+            TcPeditCmd::Add => TCA_PEDIT_KEY_EX_CMD_ADD,
+            TcPeditCmd::Other(d) => d,
+        }
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_i32, parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{TcActionTimestamp, TcActionTimestampBuffer, TcActionType};
+use crate::tc::{rate_spec::TC_RATE_SPEC_LEN, TcRateSpec, TcRateSpecBuffer};
+
+const TCA_POLICE_TBF: u16 = 1;
+const TCA_POLICE_RATE: u16 = 2;
+const TCA_POLICE_PEAKRATE: u16 = 3;
+const TCA_POLICE_AVRATE: u16 = 4;
+const TCA_POLICE_RESULT: u16 = 5;
+const TCA_POLICE_TM: u16 = 6;
+// const TCA_POLICE_PAD: u16 = 7;
+const TCA_POLICE_RATE64: u16 = 8;
+const TCA_POLICE_PEAKRATE64: u16 = 9;
+const TCA_POLICE_PKTRATE64: u16 = 10;
+const TCA_POLICE_PKTBURST64: u16 = 11;
+
+/// Options of `police` action which limits the rate of packets
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionPoliceOption {
+    Tbf(TcPolice),
+    /// Rate table with 256 `u32` slots for [TcPolice::rate]
+    Rate(Vec<u8>),
+    /// Rate table with 256 `u32` slots for [TcPolice::peakrate]
+    PeakRate(Vec<u8>),
+    /// Average rate in bytes per second, needs a rate estimator
+    AvRate(u32),
+    /// Verdict when the packet conforms to the rate
+    Result(TcActionType),
+    Tm(TcActionTimestamp),
+    /// Rate in bytes per second when it does not fit into `u32`
+    Rate64(u64),
+    /// Peak rate in bytes per second when it does not fit into `u32`
+    PeakRate64(u64),
+    /// Rate in packets per second
+    PktRate64(u64),
+    PktBurst64(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionPoliceOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tbf(v) => v.buffer_len(),
+            Self::Rate(v) | Self::PeakRate(v) => v.len(),
+            Self::AvRate(_) | Self::Result(_) => 4,
+            Self::Tm(v) => v.buffer_len(),
+            Self::Rate64(_)
+            | Self::PeakRate64(_)
+            | Self::PktRate64(_)
+            | Self::PktBurst64(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tbf(v) => v.emit(buffer),
+            Self::Rate(v) | Self::PeakRate(v) => {
+                buffer.copy_from_slice(v.as_slice())
+            }
+            Self::AvRate(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Result(v) => NativeEndian::write_i32(buffer, (*v).into()),
+            Self::Tm(v) => v.emit(buffer),
+            Self::Rate64(v)
+            | Self::PeakRate64(v)
+            | Self::PktRate64(v)
+            | Self::PktBurst64(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tbf(_) => TCA_POLICE_TBF,
+            Self::Rate(_) => TCA_POLICE_RATE,
+            Self::PeakRate(_) => TCA_POLICE_PEAKRATE,
+            Self::AvRate(_) => TCA_POLICE_AVRATE,
+            Self::Result(_) => TCA_POLICE_RESULT,
+            Self::Tm(_) => TCA_POLICE_TM,
+            Self::Rate64(_) => TCA_POLICE_RATE64,
+            Self::PeakRate64(_) => TCA_POLICE_PEAKRATE64,
+            Self::PktRate64(_) => TCA_POLICE_PKTRATE64,
+            Self::PktBurst64(_) => TCA_POLICE_PKTBURST64,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionPoliceOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_POLICE_TBF => Self::Tbf(TcPolice::parse(
+                &TcPoliceBuffer::new_checked(payload)?,
+            )?),
+            TCA_POLICE_RATE => Self::Rate(payload.to_vec()),
+            TCA_POLICE_PEAKRATE => Self::PeakRate(payload.to_vec()),
+            TCA_POLICE_AVRATE => Self::AvRate(parse_u32(payload)?),
+            TCA_POLICE_RESULT => Self::Result(parse_i32(payload)?.into()),
+            TCA_POLICE_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_POLICE_RATE64 => Self::Rate64(parse_u64(payload)?),
+            TCA_POLICE_PEAKRATE64 => Self::PeakRate64(parse_u64(payload)?),
+            TCA_POLICE_PKTRATE64 => Self::PktRate64(parse_u64(payload)?),
+            TCA_POLICE_PKTBURST64 => Self::PktBurst64(parse_u64(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TC_POLICE_LEN: usize = 20 + TC_RATE_SPEC_LEN * 2 + 12;
+
+buffer!(TcPoliceBuffer(TC_POLICE_LEN) {
+    index: (u32, 0..4),
+    action: (i32, 4..8),
+    limit: (u32, 8..12),
+    burst: (u32, 12..16),
+    mtu: (u32, 16..20),
+    rate: (slice, 20..(20 + TC_RATE_SPEC_LEN)),
+    peakrate: (slice, (20 + TC_RATE_SPEC_LEN)..(20 + TC_RATE_SPEC_LEN * 2)),
+    refcnt: (i32, (20 + TC_RATE_SPEC_LEN * 2)..(24 + TC_RATE_SPEC_LEN * 2)),
+    bindcnt: (i32, (24 + TC_RATE_SPEC_LEN * 2)..(28 + TC_RATE_SPEC_LEN * 2)),
+    capab: (u32, (28 + TC_RATE_SPEC_LEN * 2)..TC_POLICE_LEN),
+});
+
+/// Token bucket parameters(`struct tc_police`) of `police` action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcPolice {
+    pub index: u32,
+    /// Verdict when the packet exceeds the rate
+    pub action: TcActionType,
+    pub limit: u32,
+    /// Bucket size in time units(`psched` ticks)
+    pub burst: u32,
+    pub mtu: u32,
+    pub rate: TcRateSpec,
+    pub peakrate: TcRateSpec,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+    pub capab: u32,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcPoliceBuffer<&'a T>>
+    for TcPolice
+{
+    fn parse(buf: &TcPoliceBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            action: buf.action().into(),
+            limit: buf.limit(),
+            burst: buf.burst(),
+            mtu: buf.mtu(),
+            rate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.rate()))?,
+            peakrate: TcRateSpec::parse(&TcRateSpecBuffer::new(
+                buf.peakrate(),
+            ))?,
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            capab: buf.capab(),
+        })
+    }
+}
+
+impl Emitable for TcPolice {
+    fn buffer_len(&self) -> usize {
+        TC_POLICE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcPoliceBuffer::new(buffer);
+        buffer.set_index(self.index);
+        buffer.set_action(self.action.into());
+        buffer.set_limit(self.limit);
+        buffer.set_burst(self.burst);
+        buffer.set_mtu(self.mtu);
+        self.rate.emit(buffer.rate_mut());
+        self.peakrate.emit(buffer.peakrate_mut());
+        buffer.set_refcnt(self.refcnt);
+        buffer.set_bindcnt(self.bindcnt);
+        buffer.set_capab(self.capab);
+    }
+}
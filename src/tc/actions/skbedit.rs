@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    TcActionGeneric, TcActionGenericBuffer, TcActionTimestamp,
+    TcActionTimestampBuffer,
+};
+
+const TCA_SKBEDIT_TM: u16 = 1;
+const TCA_SKBEDIT_PARMS: u16 = 2;
+const TCA_SKBEDIT_PRIORITY: u16 = 3;
+const TCA_SKBEDIT_QUEUE_MAPPING: u16 = 4;
+const TCA_SKBEDIT_MARK: u16 = 5;
+// const TCA_SKBEDIT_PAD: u16 = 6;
+const TCA_SKBEDIT_PTYPE: u16 = 7;
+const TCA_SKBEDIT_MASK: u16 = 8;
+const TCA_SKBEDIT_FLAGS: u16 = 9;
+const TCA_SKBEDIT_QUEUE_MAPPING_MAX: u16 = 10;
+
+const SKBEDIT_F_INHERITDSFIELD: u64 = 1 << 0;
+const SKBEDIT_F_TXQ_SKBHASH: u64 = 1 << 1;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcSkbEditFlags: u64 {
+        /// Set the priority from the DS field of IP header
+        const InheritDsField = SKBEDIT_F_INHERITDSFIELD;
+        /// Pick the transmit queue by hash of packet within the range of
+        /// `QueueMapping` and `QueueMappingMax`
+        const TxqSkbHash = SKBEDIT_F_TXQ_SKBHASH;
+        const _ = !0;
+    }
+}
+
+impl Default for TcSkbEditFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Options of `skbedit` action which edits the metadata of packet
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionSkbEditOption {
+    Tm(TcActionTimestamp),
+    Parms(TcActionGeneric),
+    Priority(u32),
+    /// Transmit queue
+    QueueMapping(u16),
+    Mark(u32),
+    /// Packet type, e.g. `PACKET_HOST`
+    Ptype(u16),
+    /// Mask applied to [Self::Mark]
+    Mask(u32),
+    Flags(TcSkbEditFlags),
+    QueueMappingMax(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionSkbEditOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::Priority(_) | Self::Mark(_) | Self::Mask(_) => 4,
+            Self::QueueMapping(_)
+            | Self::Ptype(_)
+            | Self::QueueMappingMax(_) => 2,
+            Self::Flags(_) => 8,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(v) => v.emit(buffer),
+            Self::Priority(v) | Self::Mark(v) | Self::Mask(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::QueueMapping(v)
+            | Self::Ptype(v)
+            | Self::QueueMappingMax(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Flags(v) => NativeEndian::write_u64(buffer, v.bits()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_SKBEDIT_TM,
+            Self::Parms(_) => TCA_SKBEDIT_PARMS,
+            Self::Priority(_) => TCA_SKBEDIT_PRIORITY,
+            Self::QueueMapping(_) => TCA_SKBEDIT_QUEUE_MAPPING,
+            Self::Mark(_) => TCA_SKBEDIT_MARK,
+            Self::Ptype(_) => TCA_SKBEDIT_PTYPE,
+            Self::Mask(_) => TCA_SKBEDIT_MASK,
+            Self::Flags(_) => TCA_SKBEDIT_FLAGS,
+            Self::QueueMappingMax(_) => TCA_SKBEDIT_QUEUE_MAPPING_MAX,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionSkbEditOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_SKBEDIT_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_SKBEDIT_PARMS => Self::Parms(TcActionGeneric::parse(
+                &TcActionGenericBuffer::new_checked(payload)?,
+            )?),
+            TCA_SKBEDIT_PRIORITY => Self::Priority(parse_u32(payload)?),
+            TCA_SKBEDIT_QUEUE_MAPPING => {
+                Self::QueueMapping(parse_u16(payload)?)
+            }
+            TCA_SKBEDIT_MARK => Self::Mark(parse_u32(payload)?),
+            TCA_SKBEDIT_PTYPE => Self::Ptype(parse_u16(payload)?),
+            TCA_SKBEDIT_MASK => Self::Mask(parse_u32(payload)?),
+            TCA_SKBEDIT_FLAGS => Self::Flags(TcSkbEditFlags::from_bits_retain(
+                parse_u64(payload)?,
+            )),
+            TCA_SKBEDIT_QUEUE_MAPPING_MAX => {
+                Self::QueueMappingMax(parse_u16(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
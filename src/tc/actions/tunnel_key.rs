@@ -0,0 +1,477 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16_be, parse_u32, parse_u32_be, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    generic::TC_GEN_LEN, TcActionGeneric, TcActionGenericBuffer,
+    TcActionTimestamp, TcActionTimestampBuffer,
+};
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+
+const TCA_TUNNEL_KEY_TM: u16 = 1;
+const TCA_TUNNEL_KEY_PARMS: u16 = 2;
+const TCA_TUNNEL_KEY_ENC_IPV4_SRC: u16 = 3;
+const TCA_TUNNEL_KEY_ENC_IPV4_DST: u16 = 4;
+const TCA_TUNNEL_KEY_ENC_IPV6_SRC: u16 = 5;
+const TCA_TUNNEL_KEY_ENC_IPV6_DST: u16 = 6;
+const TCA_TUNNEL_KEY_ENC_KEY_ID: u16 = 7;
+// const TCA_TUNNEL_KEY_PAD: u16 = 8;
+const TCA_TUNNEL_KEY_ENC_DST_PORT: u16 = 9;
+const TCA_TUNNEL_KEY_NO_CSUM: u16 = 10;
+const TCA_TUNNEL_KEY_ENC_OPTS: u16 = 11;
+const TCA_TUNNEL_KEY_ENC_TOS: u16 = 12;
+const TCA_TUNNEL_KEY_ENC_TTL: u16 = 13;
+const TCA_TUNNEL_KEY_NO_FRAG: u16 = 14;
+
+/// Options of `tunnel_key` action which sets or releases the tunnel
+/// metadata used by collect metadata tunnel interfaces
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionTunnelKeyOption {
+    Tm(TcActionTimestamp),
+    Parms(TcTunnelKey),
+    EncIpv4Src(Ipv4Addr),
+    EncIpv4Dst(Ipv4Addr),
+    EncIpv6Src(Ipv6Addr),
+    EncIpv6Dst(Ipv6Addr),
+    /// Tunnel ID, e.g. VNI of VXLAN
+    EncKeyId(u32),
+    EncDstPort(u16),
+    /// Do not calculate the UDP checksum of outer header
+    NoCsum(bool),
+    EncOpts(Vec<TcTunnelKeyEncOpt>),
+    EncTos(u8),
+    EncTtl(u8),
+    /// Do not allow fragmentation of outer header
+    NoFrag,
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionTunnelKeyOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::EncIpv4Src(_) | Self::EncIpv4Dst(_) | Self::EncKeyId(_) => 4,
+            Self::EncIpv6Src(_) | Self::EncIpv6Dst(_) => 16,
+            Self::EncDstPort(_) => 2,
+            Self::NoCsum(_) | Self::EncTos(_) | Self::EncTtl(_) => 1,
+            Self::EncOpts(v) => v.as_slice().buffer_len(),
+            Self::NoFrag => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(v) => v.emit(buffer),
+            Self::EncIpv4Src(v) | Self::EncIpv4Dst(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::EncIpv6Src(v) | Self::EncIpv6Dst(v) => {
+                buffer.copy_from_slice(&v.octets())
+            }
+            Self::EncKeyId(v) => BigEndian::write_u32(buffer, *v),
+            Self::EncDstPort(v) => BigEndian::write_u16(buffer, *v),
+            Self::NoCsum(v) => buffer[0] = (*v).into(),
+            Self::EncOpts(v) => v.as_slice().emit(buffer),
+            Self::EncTos(v) | Self::EncTtl(v) => buffer[0] = *v,
+            Self::NoFrag => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_TUNNEL_KEY_TM,
+            Self::Parms(_) => TCA_TUNNEL_KEY_PARMS,
+            Self::EncIpv4Src(_) => TCA_TUNNEL_KEY_ENC_IPV4_SRC,
+            Self::EncIpv4Dst(_) => TCA_TUNNEL_KEY_ENC_IPV4_DST,
+            Self::EncIpv6Src(_) => TCA_TUNNEL_KEY_ENC_IPV6_SRC,
+            Self::EncIpv6Dst(_) => TCA_TUNNEL_KEY_ENC_IPV6_DST,
+            Self::EncKeyId(_) => TCA_TUNNEL_KEY_ENC_KEY_ID,
+            Self::EncDstPort(_) => TCA_TUNNEL_KEY_ENC_DST_PORT,
+            Self::NoCsum(_) => TCA_TUNNEL_KEY_NO_CSUM,
+            Self::EncOpts(_) => TCA_TUNNEL_KEY_ENC_OPTS | NLA_F_NESTED,
+            Self::EncTos(_) => TCA_TUNNEL_KEY_ENC_TOS,
+            Self::EncTtl(_) => TCA_TUNNEL_KEY_ENC_TTL,
+            Self::NoFrag => TCA_TUNNEL_KEY_NO_FRAG,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionTunnelKeyOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TUNNEL_KEY_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_TUNNEL_KEY_PARMS => Self::Parms(TcTunnelKey::parse(
+                &TcTunnelKeyBuffer::new_checked(payload)?,
+            )?),
+            TCA_TUNNEL_KEY_ENC_IPV4_SRC => {
+                Self::EncIpv4Src(parse_ipv4_addr(payload)?)
+            }
+            TCA_TUNNEL_KEY_ENC_IPV4_DST => {
+                Self::EncIpv4Dst(parse_ipv4_addr(payload)?)
+            }
+            TCA_TUNNEL_KEY_ENC_IPV6_SRC => {
+                Self::EncIpv6Src(parse_ipv6_addr(payload)?)
+            }
+            TCA_TUNNEL_KEY_ENC_IPV6_DST => {
+                Self::EncIpv6Dst(parse_ipv6_addr(payload)?)
+            }
+            TCA_TUNNEL_KEY_ENC_KEY_ID => Self::EncKeyId(parse_u32_be(payload)?),
+            TCA_TUNNEL_KEY_ENC_DST_PORT => {
+                Self::EncDstPort(parse_u16_be(payload)?)
+            }
+            TCA_TUNNEL_KEY_NO_CSUM => Self::NoCsum(parse_u8(payload)? > 0),
+            TCA_TUNNEL_KEY_ENC_OPTS => {
+                let mut opts = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    opts.push(TcTunnelKeyEncOpt::parse(&nla?)?);
+                }
+                Self::EncOpts(opts)
+            }
+            TCA_TUNNEL_KEY_ENC_TOS => Self::EncTos(parse_u8(payload)?),
+            TCA_TUNNEL_KEY_ENC_TTL => Self::EncTtl(parse_u8(payload)?),
+            TCA_TUNNEL_KEY_NO_FRAG => Self::NoFrag,
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TCA_TUNNEL_KEY_ACT_SET: i32 = 1;
+const TCA_TUNNEL_KEY_ACT_RELEASE: i32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcTunnelKeyActionType {
+    #[default]
+    Set,
+    /// Unset the tunnel metadata
+    Release,
+    Other(i32),
+}
+
+impl From<i32> for TcTunnelKeyActionType {
+    fn from(d: i32) -> Self {
+        match d {
+            TCA_TUNNEL_KEY_ACT_SET => Self::Set,
+            TCA_TUNNEL_KEY_ACT_RELEASE => Self::Release,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcTunnelKeyActionType> for i32 {
+    fn from(v: TcTunnelKeyActionType) -> i32 {
+        match v {
+            TcTunnelKeyActionType::Set => TCA_TUNNEL_KEY_ACT_SET,
+            TcTunnelKeyActionType::Release => TCA_TUNNEL_KEY_ACT_RELEASE,
+            TcTunnelKeyActionType::Other(d) => d,
+        }
+    }
+}
+
+const TC_TUNNEL_KEY_LEN: usize = TC_GEN_LEN + 4;
+
+buffer!(TcTunnelKeyBuffer(TC_TUNNEL_KEY_LEN) {
+    generic: (slice, 0..TC_GEN_LEN),
+    t_action: (i32, TC_GEN_LEN..TC_TUNNEL_KEY_LEN),
+});
+
+/// Parameters(`struct tc_tunnel_key`) of `tunnel_key` action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcTunnelKey {
+    pub generic: TcActionGeneric,
+    pub t_action: TcTunnelKeyActionType,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcTunnelKeyBuffer<&'a T>>
+    for TcTunnelKey
+{
+    fn parse(buf: &TcTunnelKeyBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+            t_action: buf.t_action().into(),
+        })
+    }
+}
+
+impl Emitable for TcTunnelKey {
+    fn buffer_len(&self) -> usize {
+        TC_TUNNEL_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcTunnelKeyBuffer::new(buffer);
+        self.generic.emit(buffer.generic_mut());
+        buffer.set_t_action(self.t_action.into());
+    }
+}
+
+const TCA_TUNNEL_KEY_ENC_OPTS_GENEVE: u16 = 1;
+const TCA_TUNNEL_KEY_ENC_OPTS_VXLAN: u16 = 2;
+const TCA_TUNNEL_KEY_ENC_OPTS_ERSPAN: u16 = 3;
+
+/// Tunnel type specific options in `TCA_TUNNEL_KEY_ENC_OPTS`, also used
+/// by `TCA_FLOWER_KEY_ENC_OPTS` of `flower` filter. Geneve allows
+/// multiple options, each in its own [Self::Geneve].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcTunnelKeyEncOpt {
+    Geneve(Vec<TcTunnelKeyGeneveOpt>),
+    Vxlan(Vec<TcTunnelKeyVxlanOpt>),
+    Erspan(Vec<TcTunnelKeyErspanOpt>),
+    Other(DefaultNla),
+}
+
+impl Nla for TcTunnelKeyEncOpt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Geneve(v) => v.as_slice().buffer_len(),
+            Self::Vxlan(v) => v.as_slice().buffer_len(),
+            Self::Erspan(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Geneve(v) => v.as_slice().emit(buffer),
+            Self::Vxlan(v) => v.as_slice().emit(buffer),
+            Self::Erspan(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Geneve(_) => TCA_TUNNEL_KEY_ENC_OPTS_GENEVE | NLA_F_NESTED,
+            Self::Vxlan(_) => TCA_TUNNEL_KEY_ENC_OPTS_VXLAN | NLA_F_NESTED,
+            Self::Erspan(_) => TCA_TUNNEL_KEY_ENC_OPTS_ERSPAN | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcTunnelKeyEncOpt
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TUNNEL_KEY_ENC_OPTS_GENEVE => {
+                let mut opts = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    opts.push(TcTunnelKeyGeneveOpt::parse(&nla?)?);
+                }
+                Self::Geneve(opts)
+            }
+            TCA_TUNNEL_KEY_ENC_OPTS_VXLAN => {
+                let mut opts = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    opts.push(TcTunnelKeyVxlanOpt::parse(&nla?)?);
+                }
+                Self::Vxlan(opts)
+            }
+            TCA_TUNNEL_KEY_ENC_OPTS_ERSPAN => {
+                let mut opts = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    opts.push(TcTunnelKeyErspanOpt::parse(&nla?)?);
+                }
+                Self::Erspan(opts)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TCA_TUNNEL_KEY_ENC_OPT_GENEVE_CLASS: u16 = 1;
+const TCA_TUNNEL_KEY_ENC_OPT_GENEVE_TYPE: u16 = 2;
+const TCA_TUNNEL_KEY_ENC_OPT_GENEVE_DATA: u16 = 3;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcTunnelKeyGeneveOpt {
+    Class(u16),
+    Type(u8),
+    /// Option data, the length should be multiple of 4 bytes
+    Data(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for TcTunnelKeyGeneveOpt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Class(_) => 2,
+            Self::Type(_) => 1,
+            Self::Data(v) => v.len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Class(v) => BigEndian::write_u16(buffer, *v),
+            Self::Type(v) => buffer[0] = *v,
+            Self::Data(v) => buffer.copy_from_slice(v.as_slice()),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Class(_) => TCA_TUNNEL_KEY_ENC_OPT_GENEVE_CLASS,
+            Self::Type(_) => TCA_TUNNEL_KEY_ENC_OPT_GENEVE_TYPE,
+            Self::Data(_) => TCA_TUNNEL_KEY_ENC_OPT_GENEVE_DATA,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcTunnelKeyGeneveOpt
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TUNNEL_KEY_ENC_OPT_GENEVE_CLASS => {
+                Self::Class(parse_u16_be(payload)?)
+            }
+            TCA_TUNNEL_KEY_ENC_OPT_GENEVE_TYPE => {
+                Self::Type(parse_u8(payload)?)
+            }
+            TCA_TUNNEL_KEY_ENC_OPT_GENEVE_DATA => Self::Data(payload.to_vec()),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TCA_TUNNEL_KEY_ENC_OPT_VXLAN_GBP: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcTunnelKeyVxlanOpt {
+    /// Group Based Policy ID
+    Gbp(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcTunnelKeyVxlanOpt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Gbp(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Gbp(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Gbp(_) => TCA_TUNNEL_KEY_ENC_OPT_VXLAN_GBP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcTunnelKeyVxlanOpt
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TUNNEL_KEY_ENC_OPT_VXLAN_GBP => Self::Gbp(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_VER: u16 = 1;
+const TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_INDEX: u16 = 2;
+const TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_DIR: u16 = 3;
+const TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_HWID: u16 = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcTunnelKeyErspanOpt {
+    Ver(u8),
+    /// Session index, ERSPAN version 1 only
+    Index(u32),
+    /// Direction, ERSPAN version 2 only
+    Dir(u8),
+    /// Hardware ID, ERSPAN version 2 only
+    Hwid(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for TcTunnelKeyErspanOpt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Ver(_) | Self::Dir(_) | Self::Hwid(_) => 1,
+            Self::Index(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ver(v) | Self::Dir(v) | Self::Hwid(v) => buffer[0] = *v,
+            Self::Index(v) => BigEndian::write_u32(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Ver(_) => TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_VER,
+            Self::Index(_) => TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_INDEX,
+            Self::Dir(_) => TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_DIR,
+            Self::Hwid(_) => TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_HWID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcTunnelKeyErspanOpt
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_VER => Self::Ver(parse_u8(payload)?),
+            TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_INDEX => {
+                Self::Index(parse_u32_be(payload)?)
+            }
+            TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_DIR => Self::Dir(parse_u8(payload)?),
+            TCA_TUNNEL_KEY_ENC_OPT_ERSPAN_HWID => {
+                Self::Hwid(parse_u8(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
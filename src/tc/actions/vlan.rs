@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_mac, parse_u16, parse_u16_be, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{
+    generic::TC_GEN_LEN, TcActionGeneric, TcActionGenericBuffer,
+    TcActionTimestamp, TcActionTimestampBuffer,
+};
+
+const TCA_VLAN_TM: u16 = 1;
+const TCA_VLAN_PARMS: u16 = 2;
+const TCA_VLAN_PUSH_VLAN_ID: u16 = 3;
+const TCA_VLAN_PUSH_VLAN_PROTOCOL: u16 = 4;
+// const TCA_VLAN_PAD: u16 = 5;
+const TCA_VLAN_PUSH_VLAN_PRIORITY: u16 = 6;
+const TCA_VLAN_PUSH_ETH_DST: u16 = 7;
+const TCA_VLAN_PUSH_ETH_SRC: u16 = 8;
+
+/// Options of `vlan` action which pushes, pops or modifies the VLAN tag
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcActionVlanOption {
+    Tm(TcActionTimestamp),
+    Parms(TcVlan),
+    PushVlanId(u16),
+    /// VLAN protocol, e.g. `0x8100` for 802.1Q
+    PushVlanProtocol(u16),
+    PushVlanPriority(u8),
+    PushEthDst([u8; 6]),
+    PushEthSrc([u8; 6]),
+    Other(DefaultNla),
+}
+
+impl Nla for TcActionVlanOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Tm(v) => v.buffer_len(),
+            Self::Parms(v) => v.buffer_len(),
+            Self::PushVlanId(_) | Self::PushVlanProtocol(_) => 2,
+            Self::PushVlanPriority(_) => 1,
+            Self::PushEthDst(_) | Self::PushEthSrc(_) => 6,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Tm(v) => v.emit(buffer),
+            Self::Parms(v) => v.emit(buffer),
+            Self::PushVlanId(v) => NativeEndian::write_u16(buffer, *v),
+            Self::PushVlanProtocol(v) => BigEndian::write_u16(buffer, *v),
+            Self::PushVlanPriority(v) => buffer[0] = *v,
+            Self::PushEthDst(v) | Self::PushEthSrc(v) => {
+                buffer.copy_from_slice(v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Tm(_) => TCA_VLAN_TM,
+            Self::Parms(_) => TCA_VLAN_PARMS,
+            Self::PushVlanId(_) => TCA_VLAN_PUSH_VLAN_ID,
+            Self::PushVlanProtocol(_) => TCA_VLAN_PUSH_VLAN_PROTOCOL,
+            Self::PushVlanPriority(_) => TCA_VLAN_PUSH_VLAN_PRIORITY,
+            Self::PushEthDst(_) => TCA_VLAN_PUSH_ETH_DST,
+            Self::PushEthSrc(_) => TCA_VLAN_PUSH_ETH_SRC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcActionVlanOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_VLAN_TM => Self::Tm(TcActionTimestamp::parse(
+                &TcActionTimestampBuffer::new_checked(payload)?,
+            )?),
+            TCA_VLAN_PARMS => Self::Parms(TcVlan::parse(
+                &TcVlanBuffer::new_checked(payload)?,
+            )?),
+            TCA_VLAN_PUSH_VLAN_ID => Self::PushVlanId(parse_u16(payload)?),
+            TCA_VLAN_PUSH_VLAN_PROTOCOL => {
+                Self::PushVlanProtocol(parse_u16_be(payload)?)
+            }
+            TCA_VLAN_PUSH_VLAN_PRIORITY => {
+                Self::PushVlanPriority(parse_u8(payload)?)
+            }
+            TCA_VLAN_PUSH_ETH_DST => Self::PushEthDst(parse_mac(payload)?),
+            TCA_VLAN_PUSH_ETH_SRC => Self::PushEthSrc(parse_mac(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TCA_VLAN_ACT_POP: i32 = 1;
+const TCA_VLAN_ACT_PUSH: i32 = 2;
+const TCA_VLAN_ACT_MODIFY: i32 = 3;
+const TCA_VLAN_ACT_POP_ETH: i32 = 4;
+const TCA_VLAN_ACT_PUSH_ETH: i32 = 5;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum TcVlanActionType {
+    #[default]
+    Pop,
+    Push,
+    Modify,
+    /// Pop the Ethernet header
+    PopEth,
+    /// Push an Ethernet header with `PushEthDst` and `PushEthSrc`
+    PushEth,
+    Other(i32),
+}
+
+impl From<i32> for TcVlanActionType {
+    fn from(d: i32) -> Self {
+        match d {
+            TCA_VLAN_ACT_POP => Self::Pop,
+            TCA_VLAN_ACT_PUSH => Self::Push,
+            TCA_VLAN_ACT_MODIFY => Self::Modify,
+            TCA_VLAN_ACT_POP_ETH => Self::PopEth,
+            TCA_VLAN_ACT_PUSH_ETH => Self::PushEth,
+            _ => Self::Other(d),
+        }
+    }
+}
+
+impl From<TcVlanActionType> for i32 {
+    fn from(v: TcVlanActionType) -> i32 {
+        match v {
+            TcVlanActionType::Pop => TCA_VLAN_ACT_POP,
+            TcVlanActionType::Push => TCA_VLAN_ACT_PUSH,
+            TcVlanActionType::Modify => TCA_VLAN_ACT_MODIFY,
+            TcVlanActionType::PopEth => TCA_VLAN_ACT_POP_ETH,
+            TcVlanActionType::PushEth => TCA_VLAN_ACT_PUSH_ETH,
+            TcVlanActionType::Other(d) => d,
+        }
+    }
+}
+
+const TC_VLAN_LEN: usize = TC_GEN_LEN + 4;
+
+buffer!(TcVlanBuffer(TC_VLAN_LEN) {
+    generic: (slice, 0..TC_GEN_LEN),
+    v_action: (i32, TC_GEN_LEN..TC_VLAN_LEN),
+});
+
+/// Parameters(`struct tc_vlan`) of `vlan` action
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcVlan {
+    pub generic: TcActionGeneric,
+    pub v_action: TcVlanActionType,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcVlanBuffer<&'a T>> for TcVlan {
+    fn parse(buf: &TcVlanBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            generic: TcActionGeneric::parse(&TcActionGenericBuffer::new(
+                buf.generic(),
+            ))?,
+            v_action: buf.v_action().into(),
+        })
+    }
+}
+
+impl Emitable for TcVlan {
+    fn buffer_len(&self) -> usize {
+        TC_VLAN_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcVlanBuffer::new(buffer);
+        self.generic.emit(buffer.generic_mut());
+        buffer.set_v_action(self.v_action.into());
+    }
+}
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_string, parse_u32, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::tc::{
+    TcEstimator, TcEstimatorBuffer, TcOptions, TcStats, TcStats2,
+    TcStatsBuffer, TcXstats,
+};
+
+const TCA_KIND: u16 = 1;
+const TCA_OPTIONS: u16 = 2;
+const TCA_STATS: u16 = 3;
+const TCA_XSTATS: u16 = 4;
+const TCA_RATE: u16 = 5;
+// const TCA_FCNT: u16 = 6; // linux kernel has no actual user
+const TCA_STATS2: u16 = 7;
+// const TCA_STAB: u16 = 8;
+// const TCA_PAD: u16 = 9;
+const TCA_DUMP_INVISIBLE: u16 = 10;
+const TCA_CHAIN: u16 = 11;
+const TCA_HW_OFFLOAD: u16 = 12;
+const TCA_INGRESS_BLOCK: u16 = 13;
+const TCA_EGRESS_BLOCK: u16 = 14;
+// const TCA_DUMP_FLAGS: u16 = 15;
+const TCA_EXT_WARN_MSG: u16 = 16;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcAttribute {
+    /// Name of queueing discipline, class or filter, e.g. `fq_codel`
+    Kind(String),
+    Options(TcOptions),
+    Stats(TcStats),
+    Xstats(TcXstats),
+    Rate(TcEstimator),
+    Stats2(Vec<TcStats2>),
+    /// Request kernel to include invisible qdiscs in dump
+    DumpInvisible,
+    /// Filter chain index
+    Chain(u32),
+    HwOffload(bool),
+    IngressBlock(u32),
+    EgressBlock(u32),
+    /// Warning message from kernel
+    ExtWarnMsg(String),
+    Other(DefaultNla),
+}
+
+impl Nla for TcAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Kind(s) | Self::ExtWarnMsg(s) => s.len() + 1,
+            Self::Options(v) => v.buffer_len(),
+            Self::Stats(v) => v.buffer_len(),
+            Self::Xstats(v) => v.buffer_len(),
+            Self::Rate(v) => v.buffer_len(),
+            Self::Stats2(v) => v.as_slice().buffer_len(),
+            Self::DumpInvisible => 0,
+            Self::HwOffload(_) => 1,
+            Self::Chain(_) | Self::IngressBlock(_) | Self::EgressBlock(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Kind(s) | Self::ExtWarnMsg(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Options(v) => v.emit(buffer),
+            Self::Stats(v) => v.emit(buffer),
+            Self::Xstats(v) => v.emit(buffer),
+            Self::Rate(v) => v.emit(buffer),
+            Self::Stats2(v) => v.as_slice().emit(buffer),
+            Self::DumpInvisible => (),
+            Self::HwOffload(v) => buffer[0] = (*v).into(),
+            Self::Chain(v) | Self::IngressBlock(v) | Self::EgressBlock(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Kind(_) => TCA_KIND,
+            Self::Options(_) => TCA_OPTIONS,
+            Self::Stats(_) => TCA_STATS,
+            Self::Xstats(_) => TCA_XSTATS,
+            Self::Rate(_) => TCA_RATE,
+            Self::Stats2(_) => TCA_STATS2 | NLA_F_NESTED,
+            Self::DumpInvisible => TCA_DUMP_INVISIBLE,
+            Self::Chain(_) => TCA_CHAIN,
+            Self::HwOffload(_) => TCA_HW_OFFLOAD,
+            Self::IngressBlock(_) => TCA_INGRESS_BLOCK,
+            Self::EgressBlock(_) => TCA_EGRESS_BLOCK,
+            Self::ExtWarnMsg(_) => TCA_EXT_WARN_MSG,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str>
+    for TcAttribute
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        kind: &str,
+    ) -> Result<Self, DecodeError> {
+        Self::parse_with_kind(buf, kind, false)
+    }
+}
+
+impl TcAttribute {
+    // The `TCA_OPTIONS` of class might differ from qdisc of the same kind.
+    pub(crate) fn parse_with_kind<T: AsRef<[u8]> + ?Sized>(
+        buf: &NlaBuffer<&T>,
+        kind: &str,
+        is_class: bool,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_KIND => Self::Kind(parse_string(payload)?),
+            TCA_OPTIONS => Self::Options(TcOptions::parse_with_param(
+                payload, kind, is_class,
+            )?),
+            TCA_STATS => Self::Stats(TcStats::parse(
+                &TcStatsBuffer::new_checked(payload)?,
+            )?),
+            TCA_XSTATS => Self::Xstats(TcXstats::parse_with_param(buf, kind)?),
+            TCA_RATE => Self::Rate(TcEstimator::parse(
+                &TcEstimatorBuffer::new_checked(payload)?,
+            )?),
+            TCA_STATS2 => {
+                let mut stats2 = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    stats2.push(TcStats2::parse_with_param(&nla?, kind)?);
+                }
+                Self::Stats2(stats2)
+            }
+            TCA_DUMP_INVISIBLE => Self::DumpInvisible,
+            TCA_CHAIN => Self::Chain(parse_u32(payload)?),
+            TCA_HW_OFFLOAD => Self::HwOffload(parse_u8(payload)? > 0),
+            TCA_INGRESS_BLOCK => Self::IngressBlock(parse_u32(payload)?),
+            TCA_EGRESS_BLOCK => Self::EgressBlock(parse_u32(payload)?),
+            TCA_EXT_WARN_MSG => Self::ExtWarnMsg(parse_string(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TC_ESTIMATOR_LEN: usize = 2;
+
+buffer!(TcEstimatorBuffer(TC_ESTIMATOR_LEN) {
+    interval: (u8, 0),
+    ewma_log: (u8, 1),
+});
+
+/// Rate estimator configuration(`struct tc_estimator`) of `TCA_RATE`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcEstimator {
+    /// Sampling period, `2^interval` seconds with -2 meaning 250ms.
+    pub interval: i8,
+    /// Time constant of the averaging filter, `2^ewma_log` samples.
+    pub ewma_log: u8,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcEstimatorBuffer<T>> for TcEstimator {
+    fn parse(buf: &TcEstimatorBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            interval: buf.interval() as i8,
+            ewma_log: buf.ewma_log(),
+        })
+    }
+}
+
+impl Emitable for TcEstimator {
+    fn buffer_len(&self) -> usize {
+        TC_ESTIMATOR_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcEstimatorBuffer::new(buffer);
+        buffer.set_interval(self.interval as u8);
+        buffer.set_ewma_log(self.ewma_log);
+    }
+}
@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use axerrno::AxError;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NLA_F_NESTED},
+    parsers::{parse_string, parse_u16, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{
+    actions::parse_actions, options::parse_nlas, TcAction,
+    TcActionPoliceOption, TcFilterFlags, TcHandle,
+};
+
+const TCA_BPF_ACT: u16 = 1;
+const TCA_BPF_POLICE: u16 = 2;
+const TCA_BPF_CLASSID: u16 = 3;
+const TCA_BPF_OPS_LEN: u16 = 4;
+const TCA_BPF_OPS: u16 = 5;
+const TCA_BPF_FD: u16 = 6;
+const TCA_BPF_NAME: u16 = 7;
+const TCA_BPF_FLAGS: u16 = 8;
+const TCA_BPF_FLAGS_GEN: u16 = 9;
+const TCA_BPF_TAG: u16 = 10;
+const TCA_BPF_ID: u16 = 11;
+
+const TCA_BPF_FLAG_ACT_DIRECT: u32 = 1 << 0;
+
+const BPF_TAG_SIZE: usize = 8;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcBpfFlags: u32 {
+        /// Use the return code of BPF program as TC action instead of
+        /// class ID, also known as direct-action mode
+        const DirectAction = TCA_BPF_FLAG_ACT_DIRECT;
+        const _ = !0;
+    }
+}
+
+impl Default for TcBpfFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Options of `bpf` filter
+///
+/// The eBPF program is attached by [Self::Fd] and [Self::Name], while
+/// classic BPF program is provided by [Self::OpsLen] and [Self::Ops].
+/// Kernel reports [Self::Id] and [Self::Tag] of the attached eBPF program.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterBpfOption {
+    ClassId(TcHandle),
+    /// Actions applied to the matched packets
+    Action(Vec<TcAction>),
+    /// Legacy policer, use [Self::Action] with `police` action instead
+    Police(Vec<TcActionPoliceOption>),
+    /// Number of classic BPF instructions
+    OpsLen(u16),
+    /// Classic BPF instructions(array of `struct sock_filter`)
+    Ops(Vec<u8>),
+    /// File descriptor of eBPF program
+    Fd(u32),
+    Name(String),
+    Flags(TcBpfFlags),
+    FlagsGen(TcFilterFlags),
+    Tag([u8; BPF_TAG_SIZE]),
+    /// ID of eBPF program
+    Id(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterBpfOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::OpsLen(_) => 2,
+            Self::ClassId(_)
+            | Self::Fd(_)
+            | Self::Flags(_)
+            | Self::FlagsGen(_)
+            | Self::Id(_) => 4,
+            Self::Ops(v) => v.len(),
+            Self::Action(v) => v.as_slice().buffer_len(),
+            Self::Police(v) => v.as_slice().buffer_len(),
+            Self::Name(s) => s.len() + 1,
+            Self::Tag(_) => BPF_TAG_SIZE,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::OpsLen(v) => NativeEndian::write_u16(buffer, *v),
+            Self::Action(v) => v.as_slice().emit(buffer),
+            Self::Police(v) => v.as_slice().emit(buffer),
+            Self::Ops(v) => buffer[..v.len()].copy_from_slice(v.as_slice()),
+            Self::Fd(v) | Self::Id(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Name(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Flags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::FlagsGen(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::Tag(v) => buffer[..BPF_TAG_SIZE].copy_from_slice(v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Action(_) => TCA_BPF_ACT | NLA_F_NESTED,
+            Self::Police(_) => TCA_BPF_POLICE | NLA_F_NESTED,
+            Self::ClassId(_) => TCA_BPF_CLASSID,
+            Self::OpsLen(_) => TCA_BPF_OPS_LEN,
+            Self::Ops(_) => TCA_BPF_OPS,
+            Self::Fd(_) => TCA_BPF_FD,
+            Self::Name(_) => TCA_BPF_NAME,
+            Self::Flags(_) => TCA_BPF_FLAGS,
+            Self::FlagsGen(_) => TCA_BPF_FLAGS_GEN,
+            Self::Tag(_) => TCA_BPF_TAG,
+            Self::Id(_) => TCA_BPF_ID,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterBpfOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_BPF_ACT => Self::Action(parse_actions(payload)?),
+            TCA_BPF_POLICE => Self::Police(parse_nlas(payload)?),
+            TCA_BPF_CLASSID => Self::ClassId(parse_u32(payload)?.into()),
+            TCA_BPF_OPS_LEN => Self::OpsLen(parse_u16(payload)?),
+            TCA_BPF_OPS => Self::Ops(payload.to_vec()),
+            TCA_BPF_FD => Self::Fd(parse_u32(payload)?),
+            TCA_BPF_NAME => Self::Name(parse_string(payload)?),
+            TCA_BPF_FLAGS => {
+                Self::Flags(TcBpfFlags::from_bits_retain(parse_u32(payload)?))
+            }
+            TCA_BPF_FLAGS_GEN => Self::FlagsGen(
+                TcFilterFlags::from_bits_retain(parse_u32(payload)?),
+            ),
+            TCA_BPF_TAG => {
+                if payload.len() != BPF_TAG_SIZE {
+                    return Err(AxError::InvalidInput);
+                }
+                let mut tag = [0u8; BPF_TAG_SIZE];
+                tag.copy_from_slice(payload);
+                Self::Tag(tag)
+            }
+            TCA_BPF_ID => Self::Id(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+const TCA_CLS_FLAGS_SKIP_HW: u32 = 1 << 0;
+const TCA_CLS_FLAGS_SKIP_SW: u32 = 1 << 1;
+const TCA_CLS_FLAGS_IN_HW: u32 = 1 << 2;
+const TCA_CLS_FLAGS_NOT_IN_HW: u32 = 1 << 3;
+const TCA_CLS_FLAGS_VERBOSE: u32 = 1 << 4;
+
+bitflags! {
+    /// Classifier flags(`TCA_CLS_FLAGS_*`) shared by filters supporting
+    /// hardware offload.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcFilterFlags: u32 {
+        /// Do not offload the filter to hardware
+        const SkipHw = TCA_CLS_FLAGS_SKIP_HW;
+        /// Do not process the filter in software
+        const SkipSw = TCA_CLS_FLAGS_SKIP_SW;
+        const InHw = TCA_CLS_FLAGS_IN_HW;
+        const NotInHw = TCA_CLS_FLAGS_NOT_IN_HW;
+        /// Verbose logging of offload failures
+        const Verbose = TCA_CLS_FLAGS_VERBOSE;
+        const _ = !0;
+    }
+}
+
+impl Default for TcFilterFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,928 @@
+// SPDX-License-Identifier: MIT
+
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use axerrno::AxError;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{
+        parse_mac, parse_string, parse_u16, parse_u16_be, parse_u32,
+        parse_u32_be, parse_u8,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{
+    ip::{parse_ipv4_addr, parse_ipv6_addr},
+    tc::{
+        actions::parse_actions, TcAction, TcFilterFlags, TcHandle,
+        TcTunnelKeyEncOpt,
+    },
+    IpProtocol,
+};
+
+const TCA_FLOWER_KEY_CT_FLAGS_NEW: u16 = 1 << 0;
+const TCA_FLOWER_KEY_CT_FLAGS_ESTABLISHED: u16 = 1 << 1;
+const TCA_FLOWER_KEY_CT_FLAGS_RELATED: u16 = 1 << 2;
+const TCA_FLOWER_KEY_CT_FLAGS_TRACKED: u16 = 1 << 3;
+const TCA_FLOWER_KEY_CT_FLAGS_INVALID: u16 = 1 << 4;
+const TCA_FLOWER_KEY_CT_FLAGS_REPLY: u16 = 1 << 5;
+
+bitflags! {
+    /// Connection tracking state(`TCA_FLOWER_KEY_CT_FLAGS_*`)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcFlowerCtFlags: u16 {
+        const New = TCA_FLOWER_KEY_CT_FLAGS_NEW;
+        const Established = TCA_FLOWER_KEY_CT_FLAGS_ESTABLISHED;
+        const Related = TCA_FLOWER_KEY_CT_FLAGS_RELATED;
+        const Tracked = TCA_FLOWER_KEY_CT_FLAGS_TRACKED;
+        const Invalid = TCA_FLOWER_KEY_CT_FLAGS_INVALID;
+        const Reply = TCA_FLOWER_KEY_CT_FLAGS_REPLY;
+        const _ = !0;
+    }
+}
+
+impl Default for TcFlowerCtFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const TCA_FLOWER_CLASSID: u16 = 1;
+const TCA_FLOWER_INDEV: u16 = 2;
+const TCA_FLOWER_ACT: u16 = 3;
+const TCA_FLOWER_KEY_ETH_DST: u16 = 4;
+const TCA_FLOWER_KEY_ETH_DST_MASK: u16 = 5;
+const TCA_FLOWER_KEY_ETH_SRC: u16 = 6;
+const TCA_FLOWER_KEY_ETH_SRC_MASK: u16 = 7;
+const TCA_FLOWER_KEY_ETH_TYPE: u16 = 8;
+const TCA_FLOWER_KEY_IP_PROTO: u16 = 9;
+const TCA_FLOWER_KEY_IPV4_SRC: u16 = 10;
+const TCA_FLOWER_KEY_IPV4_SRC_MASK: u16 = 11;
+const TCA_FLOWER_KEY_IPV4_DST: u16 = 12;
+const TCA_FLOWER_KEY_IPV4_DST_MASK: u16 = 13;
+const TCA_FLOWER_KEY_IPV6_SRC: u16 = 14;
+const TCA_FLOWER_KEY_IPV6_SRC_MASK: u16 = 15;
+const TCA_FLOWER_KEY_IPV6_DST: u16 = 16;
+const TCA_FLOWER_KEY_IPV6_DST_MASK: u16 = 17;
+const TCA_FLOWER_KEY_TCP_SRC: u16 = 18;
+const TCA_FLOWER_KEY_TCP_DST: u16 = 19;
+const TCA_FLOWER_KEY_UDP_SRC: u16 = 20;
+const TCA_FLOWER_KEY_UDP_DST: u16 = 21;
+const TCA_FLOWER_FLAGS: u16 = 22;
+const TCA_FLOWER_KEY_VLAN_ID: u16 = 23;
+const TCA_FLOWER_KEY_VLAN_PRIO: u16 = 24;
+const TCA_FLOWER_KEY_VLAN_ETH_TYPE: u16 = 25;
+const TCA_FLOWER_KEY_ENC_KEY_ID: u16 = 26;
+const TCA_FLOWER_KEY_ENC_IPV4_SRC: u16 = 27;
+const TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK: u16 = 28;
+const TCA_FLOWER_KEY_ENC_IPV4_DST: u16 = 29;
+const TCA_FLOWER_KEY_ENC_IPV4_DST_MASK: u16 = 30;
+const TCA_FLOWER_KEY_ENC_IPV6_SRC: u16 = 31;
+const TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK: u16 = 32;
+const TCA_FLOWER_KEY_ENC_IPV6_DST: u16 = 33;
+const TCA_FLOWER_KEY_ENC_IPV6_DST_MASK: u16 = 34;
+const TCA_FLOWER_KEY_TCP_SRC_MASK: u16 = 35;
+const TCA_FLOWER_KEY_TCP_DST_MASK: u16 = 36;
+const TCA_FLOWER_KEY_UDP_SRC_MASK: u16 = 37;
+const TCA_FLOWER_KEY_UDP_DST_MASK: u16 = 38;
+const TCA_FLOWER_KEY_SCTP_SRC_MASK: u16 = 39;
+const TCA_FLOWER_KEY_SCTP_DST_MASK: u16 = 40;
+const TCA_FLOWER_KEY_SCTP_SRC: u16 = 41;
+const TCA_FLOWER_KEY_SCTP_DST: u16 = 42;
+const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT: u16 = 43;
+const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK: u16 = 44;
+const TCA_FLOWER_KEY_ENC_UDP_DST_PORT: u16 = 45;
+const TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK: u16 = 46;
+const TCA_FLOWER_KEY_FLAGS: u16 = 47;
+const TCA_FLOWER_KEY_FLAGS_MASK: u16 = 48;
+const TCA_FLOWER_KEY_ICMPV4_CODE: u16 = 49;
+const TCA_FLOWER_KEY_ICMPV4_CODE_MASK: u16 = 50;
+const TCA_FLOWER_KEY_ICMPV4_TYPE: u16 = 51;
+const TCA_FLOWER_KEY_ICMPV4_TYPE_MASK: u16 = 52;
+const TCA_FLOWER_KEY_ICMPV6_CODE: u16 = 53;
+const TCA_FLOWER_KEY_ICMPV6_CODE_MASK: u16 = 54;
+const TCA_FLOWER_KEY_ICMPV6_TYPE: u16 = 55;
+const TCA_FLOWER_KEY_ICMPV6_TYPE_MASK: u16 = 56;
+const TCA_FLOWER_KEY_ARP_SIP: u16 = 57;
+const TCA_FLOWER_KEY_ARP_SIP_MASK: u16 = 58;
+const TCA_FLOWER_KEY_ARP_TIP: u16 = 59;
+const TCA_FLOWER_KEY_ARP_TIP_MASK: u16 = 60;
+const TCA_FLOWER_KEY_ARP_OP: u16 = 61;
+const TCA_FLOWER_KEY_ARP_OP_MASK: u16 = 62;
+const TCA_FLOWER_KEY_ARP_SHA: u16 = 63;
+const TCA_FLOWER_KEY_ARP_SHA_MASK: u16 = 64;
+const TCA_FLOWER_KEY_ARP_THA: u16 = 65;
+const TCA_FLOWER_KEY_ARP_THA_MASK: u16 = 66;
+const TCA_FLOWER_KEY_MPLS_TTL: u16 = 67;
+const TCA_FLOWER_KEY_MPLS_BOS: u16 = 68;
+const TCA_FLOWER_KEY_MPLS_TC: u16 = 69;
+const TCA_FLOWER_KEY_MPLS_LABEL: u16 = 70;
+const TCA_FLOWER_KEY_TCP_FLAGS: u16 = 71;
+const TCA_FLOWER_KEY_TCP_FLAGS_MASK: u16 = 72;
+const TCA_FLOWER_KEY_IP_TOS: u16 = 73;
+const TCA_FLOWER_KEY_IP_TOS_MASK: u16 = 74;
+const TCA_FLOWER_KEY_IP_TTL: u16 = 75;
+const TCA_FLOWER_KEY_IP_TTL_MASK: u16 = 76;
+const TCA_FLOWER_KEY_CVLAN_ID: u16 = 77;
+const TCA_FLOWER_KEY_CVLAN_PRIO: u16 = 78;
+const TCA_FLOWER_KEY_CVLAN_ETH_TYPE: u16 = 79;
+const TCA_FLOWER_KEY_ENC_IP_TOS: u16 = 80;
+const TCA_FLOWER_KEY_ENC_IP_TOS_MASK: u16 = 81;
+const TCA_FLOWER_KEY_ENC_IP_TTL: u16 = 82;
+const TCA_FLOWER_KEY_ENC_IP_TTL_MASK: u16 = 83;
+const TCA_FLOWER_KEY_ENC_OPTS: u16 = 84;
+const TCA_FLOWER_KEY_ENC_OPTS_MASK: u16 = 85;
+const TCA_FLOWER_IN_HW_COUNT: u16 = 86;
+const TCA_FLOWER_KEY_PORT_SRC_MIN: u16 = 87;
+const TCA_FLOWER_KEY_PORT_SRC_MAX: u16 = 88;
+const TCA_FLOWER_KEY_PORT_DST_MIN: u16 = 89;
+const TCA_FLOWER_KEY_PORT_DST_MAX: u16 = 90;
+const TCA_FLOWER_KEY_CT_STATE: u16 = 91;
+const TCA_FLOWER_KEY_CT_STATE_MASK: u16 = 92;
+const TCA_FLOWER_KEY_CT_ZONE: u16 = 93;
+const TCA_FLOWER_KEY_CT_ZONE_MASK: u16 = 94;
+const TCA_FLOWER_KEY_CT_MARK: u16 = 95;
+const TCA_FLOWER_KEY_CT_MARK_MASK: u16 = 96;
+const TCA_FLOWER_KEY_CT_LABELS: u16 = 97;
+const TCA_FLOWER_KEY_CT_LABELS_MASK: u16 = 98;
+// const TCA_FLOWER_KEY_MPLS_OPTS: u16 = 99;
+const TCA_FLOWER_KEY_HASH: u16 = 100;
+const TCA_FLOWER_KEY_HASH_MASK: u16 = 101;
+const TCA_FLOWER_KEY_NUM_OF_VLANS: u16 = 102;
+const TCA_FLOWER_KEY_PPPOE_SID: u16 = 103;
+const TCA_FLOWER_KEY_PPP_PROTO: u16 = 104;
+const TCA_FLOWER_KEY_L2TPV3_SID: u16 = 105;
+const TCA_FLOWER_L2_MISS: u16 = 106;
+// const TCA_FLOWER_KEY_CFM: u16 = 107;
+const TCA_FLOWER_KEY_SPI: u16 = 108;
+const TCA_FLOWER_KEY_SPI_MASK: u16 = 109;
+// const TCA_FLOWER_KEY_ENC_FLAGS: u16 = 110;
+// const TCA_FLOWER_KEY_ENC_FLAGS_MASK: u16 = 111;
+
+/// Options of `flower` filter. Each key has an optional mask variant,
+/// the key is matched exactly when its mask is absent.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterFlowerOption {
+    /// Class the matched packets are classified into
+    ClassId(TcHandle),
+    /// Name of input interface to match
+    Indev(String),
+    /// Actions applied to the matched packets
+    Action(Vec<TcAction>),
+    KeyEthDst([u8; 6]),
+    KeyEthDstMask([u8; 6]),
+    KeyEthSrc([u8; 6]),
+    KeyEthSrcMask([u8; 6]),
+    /// Ethernet type(`ETH_P_*`)
+    KeyEthType(u16),
+    KeyIpProto(IpProtocol),
+    KeyIpv4Src(Ipv4Addr),
+    KeyIpv4SrcMask(Ipv4Addr),
+    KeyIpv4Dst(Ipv4Addr),
+    KeyIpv4DstMask(Ipv4Addr),
+    KeyIpv6Src(Ipv6Addr),
+    KeyIpv6SrcMask(Ipv6Addr),
+    KeyIpv6Dst(Ipv6Addr),
+    KeyIpv6DstMask(Ipv6Addr),
+    KeyTcpSrc(u16),
+    KeyTcpDst(u16),
+    KeyUdpSrc(u16),
+    KeyUdpDst(u16),
+    Flags(TcFilterFlags),
+    KeyVlanId(u16),
+    KeyVlanPrio(u8),
+    KeyVlanEthType(u16),
+    /// Tunnel ID, e.g. VXLAN VNI
+    KeyEncKeyId(u32),
+    KeyEncIpv4Src(Ipv4Addr),
+    KeyEncIpv4SrcMask(Ipv4Addr),
+    KeyEncIpv4Dst(Ipv4Addr),
+    KeyEncIpv4DstMask(Ipv4Addr),
+    KeyEncIpv6Src(Ipv6Addr),
+    KeyEncIpv6SrcMask(Ipv6Addr),
+    KeyEncIpv6Dst(Ipv6Addr),
+    KeyEncIpv6DstMask(Ipv6Addr),
+    KeyTcpSrcMask(u16),
+    KeyTcpDstMask(u16),
+    KeyUdpSrcMask(u16),
+    KeyUdpDstMask(u16),
+    KeySctpSrcMask(u16),
+    KeySctpDstMask(u16),
+    KeySctpSrc(u16),
+    KeySctpDst(u16),
+    KeyEncUdpSrcPort(u16),
+    KeyEncUdpSrcPortMask(u16),
+    KeyEncUdpDstPort(u16),
+    KeyEncUdpDstPortMask(u16),
+    /// `TCA_FLOWER_KEY_FLAGS_*`, e.g. is fragment
+    KeyFlags(u32),
+    KeyFlagsMask(u32),
+    KeyIcmpv4Code(u8),
+    KeyIcmpv4CodeMask(u8),
+    KeyIcmpv4Type(u8),
+    KeyIcmpv4TypeMask(u8),
+    KeyIcmpv6Code(u8),
+    KeyIcmpv6CodeMask(u8),
+    KeyIcmpv6Type(u8),
+    KeyIcmpv6TypeMask(u8),
+    KeyArpSip(Ipv4Addr),
+    KeyArpSipMask(Ipv4Addr),
+    KeyArpTip(Ipv4Addr),
+    KeyArpTipMask(Ipv4Addr),
+    KeyArpOp(u8),
+    KeyArpOpMask(u8),
+    KeyArpSha([u8; 6]),
+    KeyArpShaMask([u8; 6]),
+    KeyArpTha([u8; 6]),
+    KeyArpThaMask([u8; 6]),
+    KeyMplsTtl(u8),
+    KeyMplsBos(u8),
+    KeyMplsTc(u8),
+    KeyMplsLabel(u32),
+    KeyTcpFlags(u16),
+    KeyTcpFlagsMask(u16),
+    KeyIpTos(u8),
+    KeyIpTosMask(u8),
+    KeyIpTtl(u8),
+    KeyIpTtlMask(u8),
+    /// Inner VLAN ID of QinQ
+    KeyCvlanId(u16),
+    KeyCvlanPrio(u8),
+    KeyCvlanEthType(u16),
+    KeyEncIpTos(u8),
+    KeyEncIpTosMask(u8),
+    KeyEncIpTtl(u8),
+    KeyEncIpTtlMask(u8),
+    /// Tunnel options, e.g. Geneve TLVs, sharing the layout of
+    /// `TCA_TUNNEL_KEY_ENC_OPTS`
+    KeyEncOpts(Vec<TcTunnelKeyEncOpt>),
+    KeyEncOptsMask(Vec<TcTunnelKeyEncOpt>),
+    /// Number of hardware devices the filter is offloaded to
+    InHwCount(u32),
+    KeyPortSrcMin(u16),
+    KeyPortSrcMax(u16),
+    KeyPortDstMin(u16),
+    KeyPortDstMax(u16),
+    KeyCtState(TcFlowerCtFlags),
+    KeyCtStateMask(TcFlowerCtFlags),
+    KeyCtZone(u16),
+    KeyCtZoneMask(u16),
+    KeyCtMark(u32),
+    KeyCtMarkMask(u32),
+    KeyCtLabels([u8; 16]),
+    KeyCtLabelsMask([u8; 16]),
+    KeyHash(u32),
+    KeyHashMask(u32),
+    KeyNumOfVlans(u8),
+    KeyPppoeSid(u16),
+    KeyPppProto(u16),
+    KeyL2tpv3Sid(u32),
+    /// Match packets which missed the bridge FDB/MDB lookup
+    L2Miss(bool),
+    /// IPsec security parameter index
+    KeySpi(u32),
+    KeySpiMask(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterFlowerOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ClassId(_)
+            | Self::KeyIpv4Src(_)
+            | Self::KeyIpv4SrcMask(_)
+            | Self::KeyIpv4Dst(_)
+            | Self::KeyIpv4DstMask(_)
+            | Self::Flags(_)
+            | Self::KeyEncKeyId(_)
+            | Self::KeyEncIpv4Src(_)
+            | Self::KeyEncIpv4SrcMask(_)
+            | Self::KeyEncIpv4Dst(_)
+            | Self::KeyEncIpv4DstMask(_)
+            | Self::KeyFlags(_)
+            | Self::KeyFlagsMask(_)
+            | Self::KeyArpSip(_)
+            | Self::KeyArpSipMask(_)
+            | Self::KeyArpTip(_)
+            | Self::KeyArpTipMask(_)
+            | Self::KeyMplsLabel(_)
+            | Self::InHwCount(_)
+            | Self::KeyCtMark(_)
+            | Self::KeyCtMarkMask(_)
+            | Self::KeyHash(_)
+            | Self::KeyHashMask(_)
+            | Self::KeyL2tpv3Sid(_)
+            | Self::KeySpi(_)
+            | Self::KeySpiMask(_) => 4,
+            Self::Indev(s) => s.len() + 1,
+            Self::Action(v) => v.as_slice().buffer_len(),
+            Self::KeyEncOpts(v) | Self::KeyEncOptsMask(v) => {
+                v.as_slice().buffer_len()
+            }
+            Self::KeyEthDst(_)
+            | Self::KeyEthDstMask(_)
+            | Self::KeyEthSrc(_)
+            | Self::KeyEthSrcMask(_)
+            | Self::KeyArpSha(_)
+            | Self::KeyArpShaMask(_)
+            | Self::KeyArpTha(_)
+            | Self::KeyArpThaMask(_) => 6,
+            Self::KeyEthType(_)
+            | Self::KeyTcpSrc(_)
+            | Self::KeyTcpDst(_)
+            | Self::KeyUdpSrc(_)
+            | Self::KeyUdpDst(_)
+            | Self::KeyVlanId(_)
+            | Self::KeyVlanEthType(_)
+            | Self::KeyTcpSrcMask(_)
+            | Self::KeyTcpDstMask(_)
+            | Self::KeyUdpSrcMask(_)
+            | Self::KeyUdpDstMask(_)
+            | Self::KeySctpSrcMask(_)
+            | Self::KeySctpDstMask(_)
+            | Self::KeySctpSrc(_)
+            | Self::KeySctpDst(_)
+            | Self::KeyEncUdpSrcPort(_)
+            | Self::KeyEncUdpSrcPortMask(_)
+            | Self::KeyEncUdpDstPort(_)
+            | Self::KeyEncUdpDstPortMask(_)
+            | Self::KeyTcpFlags(_)
+            | Self::KeyTcpFlagsMask(_)
+            | Self::KeyCvlanId(_)
+            | Self::KeyCvlanEthType(_)
+            | Self::KeyPortSrcMin(_)
+            | Self::KeyPortSrcMax(_)
+            | Self::KeyPortDstMin(_)
+            | Self::KeyPortDstMax(_)
+            | Self::KeyCtState(_)
+            | Self::KeyCtStateMask(_)
+            | Self::KeyCtZone(_)
+            | Self::KeyCtZoneMask(_)
+            | Self::KeyPppoeSid(_)
+            | Self::KeyPppProto(_) => 2,
+            Self::KeyIpProto(_)
+            | Self::KeyVlanPrio(_)
+            | Self::KeyIcmpv4Code(_)
+            | Self::KeyIcmpv4CodeMask(_)
+            | Self::KeyIcmpv4Type(_)
+            | Self::KeyIcmpv4TypeMask(_)
+            | Self::KeyIcmpv6Code(_)
+            | Self::KeyIcmpv6CodeMask(_)
+            | Self::KeyIcmpv6Type(_)
+            | Self::KeyIcmpv6TypeMask(_)
+            | Self::KeyArpOp(_)
+            | Self::KeyArpOpMask(_)
+            | Self::KeyMplsTtl(_)
+            | Self::KeyMplsBos(_)
+            | Self::KeyMplsTc(_)
+            | Self::KeyIpTos(_)
+            | Self::KeyIpTosMask(_)
+            | Self::KeyIpTtl(_)
+            | Self::KeyIpTtlMask(_)
+            | Self::KeyCvlanPrio(_)
+            | Self::KeyEncIpTos(_)
+            | Self::KeyEncIpTosMask(_)
+            | Self::KeyEncIpTtl(_)
+            | Self::KeyEncIpTtlMask(_)
+            | Self::KeyNumOfVlans(_)
+            | Self::L2Miss(_) => 1,
+            Self::KeyIpv6Src(_)
+            | Self::KeyIpv6SrcMask(_)
+            | Self::KeyIpv6Dst(_)
+            | Self::KeyIpv6DstMask(_)
+            | Self::KeyEncIpv6Src(_)
+            | Self::KeyEncIpv6SrcMask(_)
+            | Self::KeyEncIpv6Dst(_)
+            | Self::KeyEncIpv6DstMask(_)
+            | Self::KeyCtLabels(_)
+            | Self::KeyCtLabelsMask(_) => 16,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Indev(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Action(v) => v.as_slice().emit(buffer),
+            Self::KeyEncOpts(v) | Self::KeyEncOptsMask(v) => {
+                v.as_slice().emit(buffer)
+            }
+            Self::KeyEthDst(v)
+            | Self::KeyEthDstMask(v)
+            | Self::KeyEthSrc(v)
+            | Self::KeyEthSrcMask(v)
+            | Self::KeyArpSha(v)
+            | Self::KeyArpShaMask(v)
+            | Self::KeyArpTha(v)
+            | Self::KeyArpThaMask(v) => buffer.copy_from_slice(v),
+            Self::KeyEthType(v)
+            | Self::KeyTcpSrc(v)
+            | Self::KeyTcpDst(v)
+            | Self::KeyUdpSrc(v)
+            | Self::KeyUdpDst(v)
+            | Self::KeyVlanEthType(v)
+            | Self::KeyTcpSrcMask(v)
+            | Self::KeyTcpDstMask(v)
+            | Self::KeyUdpSrcMask(v)
+            | Self::KeyUdpDstMask(v)
+            | Self::KeySctpSrcMask(v)
+            | Self::KeySctpDstMask(v)
+            | Self::KeySctpSrc(v)
+            | Self::KeySctpDst(v)
+            | Self::KeyEncUdpSrcPort(v)
+            | Self::KeyEncUdpSrcPortMask(v)
+            | Self::KeyEncUdpDstPort(v)
+            | Self::KeyEncUdpDstPortMask(v)
+            | Self::KeyTcpFlags(v)
+            | Self::KeyTcpFlagsMask(v)
+            | Self::KeyCvlanEthType(v)
+            | Self::KeyPortSrcMin(v)
+            | Self::KeyPortSrcMax(v)
+            | Self::KeyPortDstMin(v)
+            | Self::KeyPortDstMax(v)
+            | Self::KeyPppoeSid(v)
+            | Self::KeyPppProto(v) => BigEndian::write_u16(buffer, *v),
+            Self::KeyIpProto(v) => buffer[0] = i32::from(*v) as u8,
+            Self::KeyIpv4Src(v)
+            | Self::KeyIpv4SrcMask(v)
+            | Self::KeyIpv4Dst(v)
+            | Self::KeyIpv4DstMask(v)
+            | Self::KeyEncIpv4Src(v)
+            | Self::KeyEncIpv4SrcMask(v)
+            | Self::KeyEncIpv4Dst(v)
+            | Self::KeyEncIpv4DstMask(v)
+            | Self::KeyArpSip(v)
+            | Self::KeyArpSipMask(v)
+            | Self::KeyArpTip(v)
+            | Self::KeyArpTipMask(v) => buffer.copy_from_slice(&v.octets()),
+            Self::KeyIpv6Src(v)
+            | Self::KeyIpv6SrcMask(v)
+            | Self::KeyIpv6Dst(v)
+            | Self::KeyIpv6DstMask(v)
+            | Self::KeyEncIpv6Src(v)
+            | Self::KeyEncIpv6SrcMask(v)
+            | Self::KeyEncIpv6Dst(v)
+            | Self::KeyEncIpv6DstMask(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Flags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::KeyVlanId(v)
+            | Self::KeyCvlanId(v)
+            | Self::KeyCtZone(v)
+            | Self::KeyCtZoneMask(v) => NativeEndian::write_u16(buffer, *v),
+            Self::KeyVlanPrio(v)
+            | Self::KeyIcmpv4Code(v)
+            | Self::KeyIcmpv4CodeMask(v)
+            | Self::KeyIcmpv4Type(v)
+            | Self::KeyIcmpv4TypeMask(v)
+            | Self::KeyIcmpv6Code(v)
+            | Self::KeyIcmpv6CodeMask(v)
+            | Self::KeyIcmpv6Type(v)
+            | Self::KeyIcmpv6TypeMask(v)
+            | Self::KeyArpOp(v)
+            | Self::KeyArpOpMask(v)
+            | Self::KeyMplsTtl(v)
+            | Self::KeyMplsBos(v)
+            | Self::KeyMplsTc(v)
+            | Self::KeyIpTos(v)
+            | Self::KeyIpTosMask(v)
+            | Self::KeyIpTtl(v)
+            | Self::KeyIpTtlMask(v)
+            | Self::KeyCvlanPrio(v)
+            | Self::KeyEncIpTos(v)
+            | Self::KeyEncIpTosMask(v)
+            | Self::KeyEncIpTtl(v)
+            | Self::KeyEncIpTtlMask(v)
+            | Self::KeyNumOfVlans(v) => buffer[0] = *v,
+            Self::KeyEncKeyId(v)
+            | Self::KeyFlags(v)
+            | Self::KeyFlagsMask(v)
+            | Self::KeyL2tpv3Sid(v)
+            | Self::KeySpi(v)
+            | Self::KeySpiMask(v) => BigEndian::write_u32(buffer, *v),
+            Self::KeyMplsLabel(v)
+            | Self::InHwCount(v)
+            | Self::KeyCtMark(v)
+            | Self::KeyCtMarkMask(v)
+            | Self::KeyHash(v)
+            | Self::KeyHashMask(v) => NativeEndian::write_u32(buffer, *v),
+            Self::KeyCtState(v) | Self::KeyCtStateMask(v) => {
+                NativeEndian::write_u16(buffer, v.bits())
+            }
+            Self::KeyCtLabels(v) | Self::KeyCtLabelsMask(v) => {
+                buffer.copy_from_slice(v)
+            }
+            Self::L2Miss(v) => buffer[0] = (*v).into(),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ClassId(_) => TCA_FLOWER_CLASSID,
+            Self::Indev(_) => TCA_FLOWER_INDEV,
+            Self::Action(_) => TCA_FLOWER_ACT | NLA_F_NESTED,
+            Self::KeyEthDst(_) => TCA_FLOWER_KEY_ETH_DST,
+            Self::KeyEthDstMask(_) => TCA_FLOWER_KEY_ETH_DST_MASK,
+            Self::KeyEthSrc(_) => TCA_FLOWER_KEY_ETH_SRC,
+            Self::KeyEthSrcMask(_) => TCA_FLOWER_KEY_ETH_SRC_MASK,
+            Self::KeyEthType(_) => TCA_FLOWER_KEY_ETH_TYPE,
+            Self::KeyIpProto(_) => TCA_FLOWER_KEY_IP_PROTO,
+            Self::KeyIpv4Src(_) => TCA_FLOWER_KEY_IPV4_SRC,
+            Self::KeyIpv4SrcMask(_) => TCA_FLOWER_KEY_IPV4_SRC_MASK,
+            Self::KeyIpv4Dst(_) => TCA_FLOWER_KEY_IPV4_DST,
+            Self::KeyIpv4DstMask(_) => TCA_FLOWER_KEY_IPV4_DST_MASK,
+            Self::KeyIpv6Src(_) => TCA_FLOWER_KEY_IPV6_SRC,
+            Self::KeyIpv6SrcMask(_) => TCA_FLOWER_KEY_IPV6_SRC_MASK,
+            Self::KeyIpv6Dst(_) => TCA_FLOWER_KEY_IPV6_DST,
+            Self::KeyIpv6DstMask(_) => TCA_FLOWER_KEY_IPV6_DST_MASK,
+            Self::KeyTcpSrc(_) => TCA_FLOWER_KEY_TCP_SRC,
+            Self::KeyTcpDst(_) => TCA_FLOWER_KEY_TCP_DST,
+            Self::KeyUdpSrc(_) => TCA_FLOWER_KEY_UDP_SRC,
+            Self::KeyUdpDst(_) => TCA_FLOWER_KEY_UDP_DST,
+            Self::Flags(_) => TCA_FLOWER_FLAGS,
+            Self::KeyVlanId(_) => TCA_FLOWER_KEY_VLAN_ID,
+            Self::KeyVlanPrio(_) => TCA_FLOWER_KEY_VLAN_PRIO,
+            Self::KeyVlanEthType(_) => TCA_FLOWER_KEY_VLAN_ETH_TYPE,
+            Self::KeyEncKeyId(_) => TCA_FLOWER_KEY_ENC_KEY_ID,
+            Self::KeyEncIpv4Src(_) => TCA_FLOWER_KEY_ENC_IPV4_SRC,
+            Self::KeyEncIpv4SrcMask(_) => TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK,
+            Self::KeyEncIpv4Dst(_) => TCA_FLOWER_KEY_ENC_IPV4_DST,
+            Self::KeyEncIpv4DstMask(_) => TCA_FLOWER_KEY_ENC_IPV4_DST_MASK,
+            Self::KeyEncIpv6Src(_) => TCA_FLOWER_KEY_ENC_IPV6_SRC,
+            Self::KeyEncIpv6SrcMask(_) => TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK,
+            Self::KeyEncIpv6Dst(_) => TCA_FLOWER_KEY_ENC_IPV6_DST,
+            Self::KeyEncIpv6DstMask(_) => TCA_FLOWER_KEY_ENC_IPV6_DST_MASK,
+            Self::KeyTcpSrcMask(_) => TCA_FLOWER_KEY_TCP_SRC_MASK,
+            Self::KeyTcpDstMask(_) => TCA_FLOWER_KEY_TCP_DST_MASK,
+            Self::KeyUdpSrcMask(_) => TCA_FLOWER_KEY_UDP_SRC_MASK,
+            Self::KeyUdpDstMask(_) => TCA_FLOWER_KEY_UDP_DST_MASK,
+            Self::KeySctpSrcMask(_) => TCA_FLOWER_KEY_SCTP_SRC_MASK,
+            Self::KeySctpDstMask(_) => TCA_FLOWER_KEY_SCTP_DST_MASK,
+            Self::KeySctpSrc(_) => TCA_FLOWER_KEY_SCTP_SRC,
+            Self::KeySctpDst(_) => TCA_FLOWER_KEY_SCTP_DST,
+            Self::KeyEncUdpSrcPort(_) => TCA_FLOWER_KEY_ENC_UDP_SRC_PORT,
+            Self::KeyEncUdpSrcPortMask(_) => {
+                TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK
+            }
+            Self::KeyEncUdpDstPort(_) => TCA_FLOWER_KEY_ENC_UDP_DST_PORT,
+            Self::KeyEncUdpDstPortMask(_) => {
+                TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK
+            }
+            Self::KeyFlags(_) => TCA_FLOWER_KEY_FLAGS,
+            Self::KeyFlagsMask(_) => TCA_FLOWER_KEY_FLAGS_MASK,
+            Self::KeyIcmpv4Code(_) => TCA_FLOWER_KEY_ICMPV4_CODE,
+            Self::KeyIcmpv4CodeMask(_) => TCA_FLOWER_KEY_ICMPV4_CODE_MASK,
+            Self::KeyIcmpv4Type(_) => TCA_FLOWER_KEY_ICMPV4_TYPE,
+            Self::KeyIcmpv4TypeMask(_) => TCA_FLOWER_KEY_ICMPV4_TYPE_MASK,
+            Self::KeyIcmpv6Code(_) => TCA_FLOWER_KEY_ICMPV6_CODE,
+            Self::KeyIcmpv6CodeMask(_) => TCA_FLOWER_KEY_ICMPV6_CODE_MASK,
+            Self::KeyIcmpv6Type(_) => TCA_FLOWER_KEY_ICMPV6_TYPE,
+            Self::KeyIcmpv6TypeMask(_) => TCA_FLOWER_KEY_ICMPV6_TYPE_MASK,
+            Self::KeyArpSip(_) => TCA_FLOWER_KEY_ARP_SIP,
+            Self::KeyArpSipMask(_) => TCA_FLOWER_KEY_ARP_SIP_MASK,
+            Self::KeyArpTip(_) => TCA_FLOWER_KEY_ARP_TIP,
+            Self::KeyArpTipMask(_) => TCA_FLOWER_KEY_ARP_TIP_MASK,
+            Self::KeyArpOp(_) => TCA_FLOWER_KEY_ARP_OP,
+            Self::KeyArpOpMask(_) => TCA_FLOWER_KEY_ARP_OP_MASK,
+            Self::KeyArpSha(_) => TCA_FLOWER_KEY_ARP_SHA,
+            Self::KeyArpShaMask(_) => TCA_FLOWER_KEY_ARP_SHA_MASK,
+            Self::KeyArpTha(_) => TCA_FLOWER_KEY_ARP_THA,
+            Self::KeyArpThaMask(_) => TCA_FLOWER_KEY_ARP_THA_MASK,
+            Self::KeyMplsTtl(_) => TCA_FLOWER_KEY_MPLS_TTL,
+            Self::KeyMplsBos(_) => TCA_FLOWER_KEY_MPLS_BOS,
+            Self::KeyMplsTc(_) => TCA_FLOWER_KEY_MPLS_TC,
+            Self::KeyMplsLabel(_) => TCA_FLOWER_KEY_MPLS_LABEL,
+            Self::KeyTcpFlags(_) => TCA_FLOWER_KEY_TCP_FLAGS,
+            Self::KeyTcpFlagsMask(_) => TCA_FLOWER_KEY_TCP_FLAGS_MASK,
+            Self::KeyIpTos(_) => TCA_FLOWER_KEY_IP_TOS,
+            Self::KeyIpTosMask(_) => TCA_FLOWER_KEY_IP_TOS_MASK,
+            Self::KeyIpTtl(_) => TCA_FLOWER_KEY_IP_TTL,
+            Self::KeyIpTtlMask(_) => TCA_FLOWER_KEY_IP_TTL_MASK,
+            Self::KeyCvlanId(_) => TCA_FLOWER_KEY_CVLAN_ID,
+            Self::KeyCvlanPrio(_) => TCA_FLOWER_KEY_CVLAN_PRIO,
+            Self::KeyCvlanEthType(_) => TCA_FLOWER_KEY_CVLAN_ETH_TYPE,
+            Self::KeyEncIpTos(_) => TCA_FLOWER_KEY_ENC_IP_TOS,
+            Self::KeyEncIpTosMask(_) => TCA_FLOWER_KEY_ENC_IP_TOS_MASK,
+            Self::KeyEncIpTtl(_) => TCA_FLOWER_KEY_ENC_IP_TTL,
+            Self::KeyEncIpTtlMask(_) => TCA_FLOWER_KEY_ENC_IP_TTL_MASK,
+            Self::KeyEncOpts(_) => TCA_FLOWER_KEY_ENC_OPTS | NLA_F_NESTED,
+            Self::KeyEncOptsMask(_) => {
+                TCA_FLOWER_KEY_ENC_OPTS_MASK | NLA_F_NESTED
+            }
+            Self::InHwCount(_) => TCA_FLOWER_IN_HW_COUNT,
+            Self::KeyPortSrcMin(_) => TCA_FLOWER_KEY_PORT_SRC_MIN,
+            Self::KeyPortSrcMax(_) => TCA_FLOWER_KEY_PORT_SRC_MAX,
+            Self::KeyPortDstMin(_) => TCA_FLOWER_KEY_PORT_DST_MIN,
+            Self::KeyPortDstMax(_) => TCA_FLOWER_KEY_PORT_DST_MAX,
+            Self::KeyCtState(_) => TCA_FLOWER_KEY_CT_STATE,
+            Self::KeyCtStateMask(_) => TCA_FLOWER_KEY_CT_STATE_MASK,
+            Self::KeyCtZone(_) => TCA_FLOWER_KEY_CT_ZONE,
+            Self::KeyCtZoneMask(_) => TCA_FLOWER_KEY_CT_ZONE_MASK,
+            Self::KeyCtMark(_) => TCA_FLOWER_KEY_CT_MARK,
+            Self::KeyCtMarkMask(_) => TCA_FLOWER_KEY_CT_MARK_MASK,
+            Self::KeyCtLabels(_) => TCA_FLOWER_KEY_CT_LABELS,
+            Self::KeyCtLabelsMask(_) => TCA_FLOWER_KEY_CT_LABELS_MASK,
+            Self::KeyHash(_) => TCA_FLOWER_KEY_HASH,
+            Self::KeyHashMask(_) => TCA_FLOWER_KEY_HASH_MASK,
+            Self::KeyNumOfVlans(_) => TCA_FLOWER_KEY_NUM_OF_VLANS,
+            Self::KeyPppoeSid(_) => TCA_FLOWER_KEY_PPPOE_SID,
+            Self::KeyPppProto(_) => TCA_FLOWER_KEY_PPP_PROTO,
+            Self::KeyL2tpv3Sid(_) => TCA_FLOWER_KEY_L2TPV3_SID,
+            Self::L2Miss(_) => TCA_FLOWER_L2_MISS,
+            Self::KeySpi(_) => TCA_FLOWER_KEY_SPI,
+            Self::KeySpiMask(_) => TCA_FLOWER_KEY_SPI_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterFlowerOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOWER_CLASSID => Self::ClassId(parse_u32(payload)?.into()),
+            TCA_FLOWER_INDEV => Self::Indev(parse_string(payload)?),
+            TCA_FLOWER_ACT => Self::Action(parse_actions(payload)?),
+            TCA_FLOWER_KEY_ETH_DST => Self::KeyEthDst(parse_mac(payload)?),
+            TCA_FLOWER_KEY_ETH_DST_MASK => {
+                Self::KeyEthDstMask(parse_mac(payload)?)
+            }
+            TCA_FLOWER_KEY_ETH_SRC => Self::KeyEthSrc(parse_mac(payload)?),
+            TCA_FLOWER_KEY_ETH_SRC_MASK => {
+                Self::KeyEthSrcMask(parse_mac(payload)?)
+            }
+            TCA_FLOWER_KEY_ETH_TYPE => Self::KeyEthType(parse_u16_be(payload)?),
+            TCA_FLOWER_KEY_IP_PROTO => {
+                Self::KeyIpProto(IpProtocol::from(parse_u8(payload)? as i32))
+            }
+            TCA_FLOWER_KEY_IPV4_SRC => {
+                Self::KeyIpv4Src(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_IPV4_SRC_MASK => {
+                Self::KeyIpv4SrcMask(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_IPV4_DST => {
+                Self::KeyIpv4Dst(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_IPV4_DST_MASK => {
+                Self::KeyIpv4DstMask(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_IPV6_SRC => {
+                Self::KeyIpv6Src(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_IPV6_SRC_MASK => {
+                Self::KeyIpv6SrcMask(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_IPV6_DST => {
+                Self::KeyIpv6Dst(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_IPV6_DST_MASK => {
+                Self::KeyIpv6DstMask(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_TCP_SRC => Self::KeyTcpSrc(parse_u16_be(payload)?),
+            TCA_FLOWER_KEY_TCP_DST => Self::KeyTcpDst(parse_u16_be(payload)?),
+            TCA_FLOWER_KEY_UDP_SRC => Self::KeyUdpSrc(parse_u16_be(payload)?),
+            TCA_FLOWER_KEY_UDP_DST => Self::KeyUdpDst(parse_u16_be(payload)?),
+            TCA_FLOWER_FLAGS => Self::Flags(TcFilterFlags::from_bits_retain(
+                parse_u32(payload)?,
+            )),
+            TCA_FLOWER_KEY_VLAN_ID => Self::KeyVlanId(parse_u16(payload)?),
+            TCA_FLOWER_KEY_VLAN_PRIO => Self::KeyVlanPrio(parse_u8(payload)?),
+            TCA_FLOWER_KEY_VLAN_ETH_TYPE => {
+                Self::KeyVlanEthType(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_KEY_ID => {
+                Self::KeyEncKeyId(parse_u32_be(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV4_SRC => {
+                Self::KeyEncIpv4Src(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK => {
+                Self::KeyEncIpv4SrcMask(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV4_DST => {
+                Self::KeyEncIpv4Dst(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV4_DST_MASK => {
+                Self::KeyEncIpv4DstMask(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV6_SRC => {
+                Self::KeyEncIpv6Src(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK => {
+                Self::KeyEncIpv6SrcMask(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV6_DST => {
+                Self::KeyEncIpv6Dst(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IPV6_DST_MASK => {
+                Self::KeyEncIpv6DstMask(parse_ipv6_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_TCP_SRC_MASK => {
+                Self::KeyTcpSrcMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_TCP_DST_MASK => {
+                Self::KeyTcpDstMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_UDP_SRC_MASK => {
+                Self::KeyUdpSrcMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_UDP_DST_MASK => {
+                Self::KeyUdpDstMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_SCTP_SRC_MASK => {
+                Self::KeySctpSrcMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_SCTP_DST_MASK => {
+                Self::KeySctpDstMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_SCTP_SRC => Self::KeySctpSrc(parse_u16_be(payload)?),
+            TCA_FLOWER_KEY_SCTP_DST => Self::KeySctpDst(parse_u16_be(payload)?),
+            TCA_FLOWER_KEY_ENC_UDP_SRC_PORT => {
+                Self::KeyEncUdpSrcPort(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK => {
+                Self::KeyEncUdpSrcPortMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_UDP_DST_PORT => {
+                Self::KeyEncUdpDstPort(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK => {
+                Self::KeyEncUdpDstPortMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_FLAGS => Self::KeyFlags(parse_u32_be(payload)?),
+            TCA_FLOWER_KEY_FLAGS_MASK => {
+                Self::KeyFlagsMask(parse_u32_be(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV4_CODE => {
+                Self::KeyIcmpv4Code(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV4_CODE_MASK => {
+                Self::KeyIcmpv4CodeMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV4_TYPE => {
+                Self::KeyIcmpv4Type(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV4_TYPE_MASK => {
+                Self::KeyIcmpv4TypeMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV6_CODE => {
+                Self::KeyIcmpv6Code(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV6_CODE_MASK => {
+                Self::KeyIcmpv6CodeMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV6_TYPE => {
+                Self::KeyIcmpv6Type(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ICMPV6_TYPE_MASK => {
+                Self::KeyIcmpv6TypeMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ARP_SIP => {
+                Self::KeyArpSip(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ARP_SIP_MASK => {
+                Self::KeyArpSipMask(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ARP_TIP => {
+                Self::KeyArpTip(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ARP_TIP_MASK => {
+                Self::KeyArpTipMask(parse_ipv4_addr(payload)?)
+            }
+            TCA_FLOWER_KEY_ARP_OP => Self::KeyArpOp(parse_u8(payload)?),
+            TCA_FLOWER_KEY_ARP_OP_MASK => {
+                Self::KeyArpOpMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ARP_SHA => Self::KeyArpSha(parse_mac(payload)?),
+            TCA_FLOWER_KEY_ARP_SHA_MASK => {
+                Self::KeyArpShaMask(parse_mac(payload)?)
+            }
+            TCA_FLOWER_KEY_ARP_THA => Self::KeyArpTha(parse_mac(payload)?),
+            TCA_FLOWER_KEY_ARP_THA_MASK => {
+                Self::KeyArpThaMask(parse_mac(payload)?)
+            }
+            TCA_FLOWER_KEY_MPLS_TTL => Self::KeyMplsTtl(parse_u8(payload)?),
+            TCA_FLOWER_KEY_MPLS_BOS => Self::KeyMplsBos(parse_u8(payload)?),
+            TCA_FLOWER_KEY_MPLS_TC => Self::KeyMplsTc(parse_u8(payload)?),
+            TCA_FLOWER_KEY_MPLS_LABEL => {
+                Self::KeyMplsLabel(parse_u32(payload)?)
+            }
+            TCA_FLOWER_KEY_TCP_FLAGS => {
+                Self::KeyTcpFlags(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_TCP_FLAGS_MASK => {
+                Self::KeyTcpFlagsMask(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_IP_TOS => Self::KeyIpTos(parse_u8(payload)?),
+            TCA_FLOWER_KEY_IP_TOS_MASK => {
+                Self::KeyIpTosMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_IP_TTL => Self::KeyIpTtl(parse_u8(payload)?),
+            TCA_FLOWER_KEY_IP_TTL_MASK => {
+                Self::KeyIpTtlMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_CVLAN_ID => Self::KeyCvlanId(parse_u16(payload)?),
+            TCA_FLOWER_KEY_CVLAN_PRIO => Self::KeyCvlanPrio(parse_u8(payload)?),
+            TCA_FLOWER_KEY_CVLAN_ETH_TYPE => {
+                Self::KeyCvlanEthType(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IP_TOS => Self::KeyEncIpTos(parse_u8(payload)?),
+            TCA_FLOWER_KEY_ENC_IP_TOS_MASK => {
+                Self::KeyEncIpTosMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_IP_TTL => Self::KeyEncIpTtl(parse_u8(payload)?),
+            TCA_FLOWER_KEY_ENC_IP_TTL_MASK => {
+                Self::KeyEncIpTtlMask(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_OPTS => {
+                Self::KeyEncOpts(parse_enc_opts(payload)?)
+            }
+            TCA_FLOWER_KEY_ENC_OPTS_MASK => {
+                Self::KeyEncOptsMask(parse_enc_opts(payload)?)
+            }
+            TCA_FLOWER_IN_HW_COUNT => Self::InHwCount(parse_u32(payload)?),
+            TCA_FLOWER_KEY_PORT_SRC_MIN => {
+                Self::KeyPortSrcMin(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_PORT_SRC_MAX => {
+                Self::KeyPortSrcMax(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_PORT_DST_MIN => {
+                Self::KeyPortDstMin(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_PORT_DST_MAX => {
+                Self::KeyPortDstMax(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_CT_STATE => Self::KeyCtState(
+                TcFlowerCtFlags::from_bits_retain(parse_u16(payload)?),
+            ),
+            TCA_FLOWER_KEY_CT_STATE_MASK => Self::KeyCtStateMask(
+                TcFlowerCtFlags::from_bits_retain(parse_u16(payload)?),
+            ),
+            TCA_FLOWER_KEY_CT_ZONE => Self::KeyCtZone(parse_u16(payload)?),
+            TCA_FLOWER_KEY_CT_ZONE_MASK => {
+                Self::KeyCtZoneMask(parse_u16(payload)?)
+            }
+            TCA_FLOWER_KEY_CT_MARK => Self::KeyCtMark(parse_u32(payload)?),
+            TCA_FLOWER_KEY_CT_MARK_MASK => {
+                Self::KeyCtMarkMask(parse_u32(payload)?)
+            }
+            TCA_FLOWER_KEY_CT_LABELS => {
+                Self::KeyCtLabels(parse_ct_labels(payload)?)
+            }
+            TCA_FLOWER_KEY_CT_LABELS_MASK => {
+                Self::KeyCtLabelsMask(parse_ct_labels(payload)?)
+            }
+            TCA_FLOWER_KEY_HASH => Self::KeyHash(parse_u32(payload)?),
+            TCA_FLOWER_KEY_HASH_MASK => Self::KeyHashMask(parse_u32(payload)?),
+            TCA_FLOWER_KEY_NUM_OF_VLANS => {
+                Self::KeyNumOfVlans(parse_u8(payload)?)
+            }
+            TCA_FLOWER_KEY_PPPOE_SID => {
+                Self::KeyPppoeSid(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_PPP_PROTO => {
+                Self::KeyPppProto(parse_u16_be(payload)?)
+            }
+            TCA_FLOWER_KEY_L2TPV3_SID => {
+                Self::KeyL2tpv3Sid(parse_u32_be(payload)?)
+            }
+            TCA_FLOWER_L2_MISS => Self::L2Miss(parse_u8(payload)? > 0),
+            TCA_FLOWER_KEY_SPI => Self::KeySpi(parse_u32_be(payload)?),
+            TCA_FLOWER_KEY_SPI_MASK => Self::KeySpiMask(parse_u32_be(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+fn parse_ct_labels(payload: &[u8]) -> Result<[u8; 16], DecodeError> {
+    if payload.len() != 16 {
+        return Err(AxError::InvalidInput);
+    }
+    let mut labels = [0u8; 16];
+    labels.copy_from_slice(payload);
+    Ok(labels)
+}
+
+fn parse_enc_opts(
+    payload: &[u8],
+) -> Result<Vec<TcTunnelKeyEncOpt>, DecodeError> {
+    let mut opts = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        opts.push(TcTunnelKeyEncOpt::parse(&nla?)?);
+    }
+    Ok(opts)
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NLA_F_NESTED},
+    parsers::{parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{actions::parse_actions, TcAction, TcFilterFlags, TcHandle};
+
+const TCA_MATCHALL_CLASSID: u16 = 1;
+const TCA_MATCHALL_ACT: u16 = 2;
+const TCA_MATCHALL_FLAGS: u16 = 3;
+const TCA_MATCHALL_PCNT: u16 = 4;
+// const TCA_MATCHALL_PAD: u16 = 5;
+
+/// Options of `matchall` filter which matches every packet
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterMatchAllOption {
+    ClassId(TcHandle),
+    /// Actions applied to every packet
+    Action(Vec<TcAction>),
+    /// Use [TcFilterFlags::SkipHw] or [TcFilterFlags::SkipSw] to control
+    /// hardware offload
+    Flags(TcFilterFlags),
+    /// Number of packets hit(`struct tc_matchall_pcnt`)
+    Pcnt(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterMatchAllOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ClassId(_) | Self::Flags(_) => 4,
+            Self::Pcnt(_) => 8,
+            Self::Action(v) => v.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Flags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::Pcnt(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Action(v) => v.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ClassId(_) => TCA_MATCHALL_CLASSID,
+            Self::Flags(_) => TCA_MATCHALL_FLAGS,
+            Self::Pcnt(_) => TCA_MATCHALL_PCNT,
+            Self::Action(_) => TCA_MATCHALL_ACT | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterMatchAllOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_MATCHALL_CLASSID => Self::ClassId(parse_u32(payload)?.into()),
+            TCA_MATCHALL_FLAGS => Self::Flags(TcFilterFlags::from_bits_retain(
+                parse_u32(payload)?,
+            )),
+            TCA_MATCHALL_ACT => Self::Action(parse_actions(payload)?),
+            TCA_MATCHALL_PCNT => Self::Pcnt(parse_u64(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
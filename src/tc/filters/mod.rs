@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+
+mod bpf;
+mod flags;
+mod flower;
+mod matchall;
+mod u32;
+
+pub use self::bpf::{TcBpfFlags, TcFilterBpfOption};
+pub use self::flags::TcFilterFlags;
+pub use self::flower::{TcFilterFlowerOption, TcFlowerCtFlags};
+pub use self::matchall::TcFilterMatchAllOption;
+pub use self::u32::{
+    TcFilterU32Option, TcU32Key, TcU32KeyBuffer, TcU32Mark, TcU32MarkBuffer,
+    TcU32Pcnt, TcU32PcntBuffer, TcU32Selector, TcU32SelectorBuffer,
+    TcU32SelectorFlags,
+};
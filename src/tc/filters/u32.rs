@@ -0,0 +1,422 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use axerrno::AxError;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NLA_F_NESTED},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{
+    actions::parse_actions, options::parse_nlas, TcAction,
+    TcActionPoliceOption, TcFilterFlags, TcHandle,
+};
+
+const TCA_U32_CLASSID: u16 = 1;
+const TCA_U32_HASH: u16 = 2;
+const TCA_U32_LINK: u16 = 3;
+const TCA_U32_DIVISOR: u16 = 4;
+const TCA_U32_SEL: u16 = 5;
+const TCA_U32_POLICE: u16 = 6;
+const TCA_U32_ACT: u16 = 7;
+const TCA_U32_INDEV: u16 = 8;
+const TCA_U32_PCNT: u16 = 9;
+const TCA_U32_MARK: u16 = 10;
+const TCA_U32_FLAGS: u16 = 11;
+// const TCA_U32_PAD: u16 = 12;
+
+/// Options of `u32` filter.
+///
+/// The `u32` filter matches 32 bits words at given offsets of the packet.
+/// Filters are organized in hash tables: [Self::Divisor] creates a hash
+/// table with given number of buckets, and [Self::Link] jumps from a
+/// filter to another hash table.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcFilterU32Option {
+    ClassId(TcHandle),
+    /// Handle of the hash table and bucket this filter is placed in
+    Hash(u32),
+    /// Handle of the hash table to continue matching in
+    Link(u32),
+    /// Number of buckets when creating a hash table
+    Divisor(u32),
+    Selector(TcU32Selector),
+    /// Legacy policer, use [Self::Action] with `police` action instead
+    Police(Vec<TcActionPoliceOption>),
+    /// Actions applied to the matched packets
+    Action(Vec<TcAction>),
+    /// Name of input interface to match
+    Indev(String),
+    Pcnt(TcU32Pcnt),
+    Mark(TcU32Mark),
+    Flags(TcFilterFlags),
+    Other(DefaultNla),
+}
+
+impl Nla for TcFilterU32Option {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::ClassId(_)
+            | Self::Hash(_)
+            | Self::Link(_)
+            | Self::Divisor(_)
+            | Self::Flags(_) => 4,
+            Self::Selector(v) => v.buffer_len(),
+            Self::Police(v) => v.as_slice().buffer_len(),
+            Self::Action(v) => v.as_slice().buffer_len(),
+            Self::Indev(s) => s.len() + 1,
+            Self::Pcnt(v) => v.buffer_len(),
+            Self::Mark(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::ClassId(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Hash(v) | Self::Link(v) | Self::Divisor(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Flags(v) => NativeEndian::write_u32(buffer, v.bits()),
+            Self::Selector(v) => v.emit(buffer),
+            Self::Police(v) => v.as_slice().emit(buffer),
+            Self::Action(v) => v.as_slice().emit(buffer),
+            Self::Indev(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            Self::Pcnt(v) => v.emit(buffer),
+            Self::Mark(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::ClassId(_) => TCA_U32_CLASSID,
+            Self::Hash(_) => TCA_U32_HASH,
+            Self::Link(_) => TCA_U32_LINK,
+            Self::Divisor(_) => TCA_U32_DIVISOR,
+            Self::Selector(_) => TCA_U32_SEL,
+            Self::Police(_) => TCA_U32_POLICE | NLA_F_NESTED,
+            Self::Action(_) => TCA_U32_ACT | NLA_F_NESTED,
+            Self::Indev(_) => TCA_U32_INDEV,
+            Self::Pcnt(_) => TCA_U32_PCNT,
+            Self::Mark(_) => TCA_U32_MARK,
+            Self::Flags(_) => TCA_U32_FLAGS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcFilterU32Option
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_U32_CLASSID => Self::ClassId(parse_u32(payload)?.into()),
+            TCA_U32_HASH => Self::Hash(parse_u32(payload)?),
+            TCA_U32_LINK => Self::Link(parse_u32(payload)?),
+            TCA_U32_DIVISOR => Self::Divisor(parse_u32(payload)?),
+            TCA_U32_SEL => Self::Selector(TcU32Selector::parse(
+                &TcU32SelectorBuffer::new_checked(payload)?,
+            )?),
+            TCA_U32_POLICE => Self::Police(parse_nlas(payload)?),
+            TCA_U32_ACT => Self::Action(parse_actions(payload)?),
+            TCA_U32_INDEV => Self::Indev(parse_string(payload)?),
+            TCA_U32_PCNT => Self::Pcnt(TcU32Pcnt::parse(
+                &TcU32PcntBuffer::new_checked(payload)?,
+            )?),
+            TCA_U32_MARK => Self::Mark(TcU32Mark::parse(
+                &TcU32MarkBuffer::new_checked(payload)?,
+            )?),
+            TCA_U32_FLAGS => Self::Flags(TcFilterFlags::from_bits_retain(
+                parse_u32(payload)?,
+            )),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TC_U32_TERMINAL: u8 = 1;
+const TC_U32_OFFSET: u8 = 2;
+const TC_U32_VAROFFSET: u8 = 4;
+const TC_U32_EAT: u8 = 8;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TcU32SelectorFlags: u8 {
+        /// Stop matching and apply the result of this filter
+        const Terminal = TC_U32_TERMINAL;
+        const Offset = TC_U32_OFFSET;
+        const VarOffset = TC_U32_VAROFFSET;
+        /// Move the matching offset for the linked hash table
+        const Eat = TC_U32_EAT;
+        const _ = !0;
+    }
+}
+
+impl Default for TcU32SelectorFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+const TC_U32_SEL_LEN: usize = 16;
+const TC_U32_KEY_LEN: usize = 16;
+
+buffer!(TcU32SelectorBuffer(TC_U32_SEL_LEN) {
+    flags: (u8, 0),
+    offshift: (u8, 1),
+    nkeys: (u8, 2),
+    pad: (u8, 3),
+    offmask: (u16, 4..6),
+    off: (u16, 6..8),
+    offoff: (i16, 8..10),
+    hoff: (i16, 10..12),
+    hmask: (u32, 12..TC_U32_SEL_LEN),
+    keys: (slice, TC_U32_SEL_LEN..),
+});
+
+/// Selector(`struct tc_u32_sel`) of `u32` filter. The `offmask` and
+/// `hmask` are stored in host byte order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcU32Selector {
+    pub flags: TcU32SelectorFlags,
+    pub offshift: u8,
+    pub offmask: u16,
+    pub off: u16,
+    pub offoff: i16,
+    pub hoff: i16,
+    /// Mask applied to the hash key when selecting the bucket
+    pub hmask: u32,
+    keys: Vec<TcU32Key>,
+}
+
+impl TcU32Selector {
+    /// The number of keys is stored in a `u8`
+    pub const MAX_KEYS: usize = u8::MAX as usize;
+
+    pub fn keys(&self) -> &[TcU32Key] {
+        self.keys.as_slice()
+    }
+
+    pub fn keys_mut(&mut self) -> &mut [TcU32Key] {
+        self.keys.as_mut_slice()
+    }
+
+    /// Append a key, fails when the selector already holds
+    /// [Self::MAX_KEYS] keys.
+    pub fn push_key(&mut self, key: TcU32Key) -> Result<(), AxError> {
+        if self.keys.len() >= Self::MAX_KEYS {
+            return Err(AxError::InvalidInput);
+        }
+        self.keys.push(key);
+        Ok(())
+    }
+
+    /// Replace all keys, fails when there are more than [Self::MAX_KEYS]
+    /// keys.
+    pub fn set_keys(&mut self, keys: Vec<TcU32Key>) -> Result<(), AxError> {
+        if keys.len() > Self::MAX_KEYS {
+            return Err(AxError::InvalidInput);
+        }
+        self.keys = keys;
+        Ok(())
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcU32SelectorBuffer<&'a T>>
+    for TcU32Selector
+{
+    fn parse(buf: &TcU32SelectorBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let nkeys = buf.nkeys() as usize;
+        let payload = buf.keys();
+        if payload.len() < nkeys * TC_U32_KEY_LEN {
+            return Err(AxError::InvalidInput);
+        }
+        let mut keys = Vec::with_capacity(nkeys);
+        for key_buf in payload.chunks_exact(TC_U32_KEY_LEN).take(nkeys) {
+            keys.push(TcU32Key::parse(&TcU32KeyBuffer::new(key_buf))?);
+        }
+        Ok(Self {
+            flags: TcU32SelectorFlags::from_bits_retain(buf.flags()),
+            offshift: buf.offshift(),
+            offmask: u16::from_be(buf.offmask()),
+            off: buf.off(),
+            offoff: buf.offoff(),
+            hoff: buf.hoff(),
+            hmask: u32::from_be(buf.hmask()),
+            keys,
+        })
+    }
+}
+
+impl Emitable for TcU32Selector {
+    fn buffer_len(&self) -> usize {
+        TC_U32_SEL_LEN + self.keys.len() * TC_U32_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcU32SelectorBuffer::new(buffer);
+        buffer.set_flags(self.flags.bits());
+        buffer.set_offshift(self.offshift);
+        // Bounded by `MAX_KEYS`
+        buffer.set_nkeys(self.keys.len() as u8);
+        buffer.set_pad(0);
+        buffer.set_offmask(self.offmask.to_be());
+        buffer.set_off(self.off);
+        buffer.set_offoff(self.offoff);
+        buffer.set_hoff(self.hoff);
+        buffer.set_hmask(self.hmask.to_be());
+        for (key, key_buf) in self
+            .keys
+            .iter()
+            .zip(buffer.keys_mut().chunks_exact_mut(TC_U32_KEY_LEN))
+        {
+            key.emit(key_buf);
+        }
+    }
+}
+
+buffer!(TcU32KeyBuffer(TC_U32_KEY_LEN) {
+    mask: (u32, 0..4),
+    val: (u32, 4..8),
+    off: (i32, 8..12),
+    offmask: (i32, 12..TC_U32_KEY_LEN),
+});
+
+/// Key(`struct tc_u32_key`) of `u32` selector, matching
+/// `packet[off] & mask == val`. The `mask` and `val` are stored in host
+/// byte order.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcU32Key {
+    pub mask: u32,
+    pub val: u32,
+    pub off: i32,
+    pub offmask: i32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcU32KeyBuffer<T>> for TcU32Key {
+    fn parse(buf: &TcU32KeyBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            mask: u32::from_be(buf.mask()),
+            val: u32::from_be(buf.val()),
+            off: buf.off(),
+            offmask: buf.offmask(),
+        })
+    }
+}
+
+impl Emitable for TcU32Key {
+    fn buffer_len(&self) -> usize {
+        TC_U32_KEY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcU32KeyBuffer::new(buffer);
+        buffer.set_mask(self.mask.to_be());
+        buffer.set_val(self.val.to_be());
+        buffer.set_off(self.off);
+        buffer.set_offmask(self.offmask);
+    }
+}
+
+const TC_U32_PCNT_LEN: usize = 16;
+
+buffer!(TcU32PcntBuffer(TC_U32_PCNT_LEN) {
+    rcnt: (u64, 0..8),
+    rhit: (u64, 8..16),
+    kcnts: (slice, TC_U32_PCNT_LEN..),
+});
+
+/// Performance counters(`struct tc_u32_pcnt`) of `u32` filter
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcU32Pcnt {
+    /// Number of packets looked up
+    pub rcnt: u64,
+    /// Number of packets matched
+    pub rhit: u64,
+    /// Number of hits per key
+    pub kcnts: Vec<u64>,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcU32PcntBuffer<&'a T>>
+    for TcU32Pcnt
+{
+    fn parse(buf: &TcU32PcntBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rcnt: buf.rcnt(),
+            rhit: buf.rhit(),
+            kcnts: buf
+                .kcnts()
+                .chunks_exact(8)
+                .map(NativeEndian::read_u64)
+                .collect(),
+        })
+    }
+}
+
+impl Emitable for TcU32Pcnt {
+    fn buffer_len(&self) -> usize {
+        TC_U32_PCNT_LEN + self.kcnts.len() * 8
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcU32PcntBuffer::new(buffer);
+        buffer.set_rcnt(self.rcnt);
+        buffer.set_rhit(self.rhit);
+        NativeEndian::write_u64_into(&self.kcnts, buffer.kcnts_mut());
+    }
+}
+
+const TC_U32_MARK_LEN: usize = 12;
+
+buffer!(TcU32MarkBuffer(TC_U32_MARK_LEN) {
+    val: (u32, 0..4),
+    mask: (u32, 4..8),
+    success: (u32, 8..12),
+});
+
+/// Firewall mark matching(`struct tc_u32_mark`) of `u32` filter
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcU32Mark {
+    pub val: u32,
+    pub mask: u32,
+    /// Number of packets matched the mark, out only
+    pub success: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcU32MarkBuffer<T>> for TcU32Mark {
+    fn parse(buf: &TcU32MarkBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            val: buf.val(),
+            mask: buf.mask(),
+            success: buf.success(),
+        })
+    }
+}
+
+impl Emitable for TcU32Mark {
+    fn buffer_len(&self) -> usize {
+        TC_U32_MARK_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcU32MarkBuffer::new(buffer);
+        buffer.set_val(self.val);
+        buffer.set_mask(self.mask);
+        buffer.set_success(self.success);
+    }
+}
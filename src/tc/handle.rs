@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT
+
+/// Traffic control handle in the form of `major:minor`, as displayed by the
+/// `tc` command.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcHandle {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl TcHandle {
+    pub const UNSPEC: Self = Self { major: 0, minor: 0 };
+    pub const ROOT: Self = Self {
+        major: 0xFFFF,
+        minor: 0xFFFF,
+    };
+    pub const INGRESS: Self = Self {
+        major: 0xFFFF,
+        minor: 0xFFF1,
+    };
+    /// The `clsact` qdisc shares the handle of the `ingress` qdisc.
+    pub const CLSACT: Self = Self::INGRESS;
+
+    pub const MIN_PRIORITY: u16 = 0xFFE0;
+    pub const MIN_INGRESS: u16 = 0xFFF2;
+    pub const MIN_EGRESS: u16 = 0xFFF3;
+
+    pub fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl From<u32> for TcHandle {
+    fn from(d: u32) -> Self {
+        Self {
+            major: (d >> 16) as u16,
+            minor: (d & 0xFFFF) as u16,
+        }
+    }
+}
+
+impl From<TcHandle> for u32 {
+    fn from(v: TcHandle) -> u32 {
+        ((v.major as u32) << 16) | v.minor as u32
+    }
+}
+
+impl core::fmt::Display for TcHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::ROOT => write!(f, "root"),
+            Self::UNSPEC => write!(f, "none"),
+            _ => write!(f, "{:x}:{:x}", self.major, self.minor),
+        }
+    }
+}
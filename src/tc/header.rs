@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::{tc::TcHandle, AddressFamily};
+
+const TC_HEADER_LEN: usize = 20;
+
+buffer!(TcMessageBuffer(TC_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    index: (i32, 4..8),
+    handle: (u32, 8..12),
+    parent: (u32, 12..16),
+    info: (u32, 16..TC_HEADER_LEN),
+    payload: (slice, TC_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> TcMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `struct tcmsg` shared by qdisc, class,
+/// filter and chain messages.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcHeader {
+    pub family: AddressFamily,
+    /// Interface index
+    pub index: i32,
+    /// Qdisc, class or filter handle
+    pub handle: TcHandle,
+    /// Parent qdisc or class
+    pub parent: TcHandle,
+    /// For filters, the upper 16 bits hold the priority and the lower 16
+    /// bits hold the protocol in network byte order.
+    pub info: u32,
+}
+
+impl Emitable for TcHeader {
+    fn buffer_len(&self) -> usize {
+        TC_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TcMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_index(self.index);
+        packet.set_handle(self.handle.into());
+        packet.set_parent(self.parent.into());
+        packet.set_info(self.info);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcMessageBuffer<T>> for TcHeader {
+    fn parse(buf: &TcMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family().into(),
+            index: buf.index(),
+            handle: buf.handle().into(),
+            parent: buf.parent().into(),
+            info: buf.info(),
+        })
+    }
+}
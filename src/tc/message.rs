@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use crate::tc::{TcAttribute, TcHeader, TcMessageBuffer};
+
+const RTM_NEWTCLASS: u16 = 40;
+const RTM_DELTCLASS: u16 = 41;
+const RTM_GETTCLASS: u16 = 42;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcMessage {
+    pub header: TcHeader,
+    pub attributes: Vec<TcAttribute>,
+}
+
+impl TcMessage {
+    /// Create a new `TcMessage` with the given interface index
+    pub fn with_index(index: i32) -> Self {
+        Self {
+            header: TcHeader {
+                index,
+                ..Default::default()
+            },
+            attributes: Vec::new(),
+        }
+    }
+}
+
+impl Emitable for TcMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+/// Parse as qdisc, filter or chain message, use
+/// [ParseableParametrized] for class messages.
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TcMessageBuffer<&'a T>> for TcMessage {
+    fn parse(buf: &TcMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Self::parse_with_class(buf, false)
+    }
+}
+
+/// The parameter is the netlink message type, as the `TCA_OPTIONS` of
+/// class might differ from qdisc of the same kind.
+impl<'a, T: AsRef<[u8]> + 'a> ParseableParametrized<TcMessageBuffer<&'a T>, u16>
+    for TcMessage
+{
+    fn parse_with_param(
+        buf: &TcMessageBuffer<&'a T>,
+        message_type: u16,
+    ) -> Result<Self, DecodeError> {
+        Self::parse_with_class(
+            buf,
+            matches!(
+                message_type,
+                RTM_NEWTCLASS | RTM_DELTCLASS | RTM_GETTCLASS
+            ),
+        )
+    }
+}
+
+impl TcMessage {
+    fn parse_with_class<T: AsRef<[u8]> + ?Sized>(
+        buf: &TcMessageBuffer<&T>,
+        is_class: bool,
+    ) -> Result<Self, DecodeError> {
+        let header = TcHeader::parse(buf)?;
+        let mut attributes = Vec::new();
+        // TCA_OPTIONS and TCA_XSTATS can only be parsed after TCA_KIND
+        let mut kind = String::new();
+        for nla_buf in buf.attributes() {
+            let attr =
+                TcAttribute::parse_with_kind(&nla_buf?, &kind, is_class)?;
+            if let TcAttribute::Kind(k) = &attr {
+                kind = k.clone();
+            }
+            attributes.push(attr);
+        }
+        Ok(TcMessage { header, attributes })
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+mod actions;
+mod attribute;
+mod estimator;
+mod filters;
+mod handle;
+mod header;
+mod message;
+mod options;
+mod qdiscs;
+mod rate_spec;
+mod stats;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::actions::{
+    TcAction, TcActionAttribute, TcActionCsumOption, TcActionCtOption,
+    TcActionFlags, TcActionGactOption, TcActionGeneric, TcActionGenericBuffer,
+    TcActionHwStats, TcActionMessage, TcActionMessageAttribute,
+    TcActionMessageBuffer, TcActionMessageHeader, TcActionMirredOption,
+    TcActionOptions, TcActionPeditOption, TcActionPoliceOption,
+    TcActionRootFlags, TcActionSkbEditOption, TcActionTimestamp,
+    TcActionTimestampBuffer, TcActionTunnelKeyOption, TcActionType,
+    TcActionVlanOption, TcBitfield32, TcCsum, TcCsumBuffer, TcCsumUpdateFlags,
+    TcCtActionFlags, TcGactProbability, TcGactProbabilityBuffer,
+    TcGactProbabilityType, TcMirred, TcMirredActionType, TcMirredBuffer,
+    TcPeditCmd, TcPeditHeaderType, TcPeditKey, TcPeditKeyBuffer, TcPeditKeyEx,
+    TcPeditSelector, TcPeditSelectorBuffer, TcPolice, TcPoliceBuffer,
+    TcSkbEditFlags, TcTunnelKey, TcTunnelKeyActionType, TcTunnelKeyBuffer,
+    TcTunnelKeyEncOpt, TcTunnelKeyErspanOpt, TcTunnelKeyGeneveOpt,
+    TcTunnelKeyVxlanOpt, TcVlan, TcVlanActionType, TcVlanBuffer,
+};
+pub use self::attribute::TcAttribute;
+pub use self::estimator::{TcEstimator, TcEstimatorBuffer};
+pub use self::filters::{
+    TcBpfFlags, TcFilterBpfOption, TcFilterFlags, TcFilterFlowerOption,
+    TcFilterMatchAllOption, TcFilterU32Option, TcFlowerCtFlags, TcU32Key,
+    TcU32KeyBuffer, TcU32Mark, TcU32MarkBuffer, TcU32Pcnt, TcU32PcntBuffer,
+    TcU32Selector, TcU32SelectorBuffer, TcU32SelectorFlags,
+};
+pub use self::handle::TcHandle;
+pub use self::header::{TcHeader, TcMessageBuffer};
+pub use self::message::TcMessage;
+pub use self::options::TcOptions;
+pub use self::qdiscs::{
+    TcHfscOption, TcHfscServiceCurve, TcHfscServiceCurveBuffer, TcHtbGlob,
+    TcHtbGlobBuffer, TcHtbOpt, TcHtbOptBuffer, TcHtbOption, TcHtbXstats,
+    TcHtbXstatsBuffer, TcNetemCorr, TcNetemCorrBuffer, TcNetemLossModel,
+    TcNetemProbability, TcNetemProbabilityBuffer, TcNetemQopt,
+    TcNetemQoptBuffer, TcNetemRate, TcNetemRateBuffer, TcNetemSlot,
+    TcNetemSlotBuffer, TcQdiscFqCodelOption, TcQdiscFqOption, TcQdiscHfsc,
+    TcQdiscHfscBuffer, TcQdiscNetem, TcQdiscNetemOption, TcQdiscPrio,
+    TcQdiscPrioBuffer, TcQdiscTbfOption, TcTbfQopt, TcTbfQoptBuffer,
+};
+pub use self::rate_spec::{TcRateSpec, TcRateSpecBuffer};
+pub use self::stats::{
+    TcStats, TcStats2, TcStatsBasic, TcStatsBasicBuffer, TcStatsBuffer,
+    TcStatsQueue, TcStatsQueueBuffer, TcStatsRateEst, TcStatsRateEstBuffer,
+    TcXstats,
+};
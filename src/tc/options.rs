@@ -0,0 +1,147 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    DecodeError, Emitable, Parseable,
+};
+
+use crate::tc::{
+    TcFilterBpfOption, TcFilterFlowerOption, TcFilterMatchAllOption,
+    TcFilterU32Option, TcHfscOption, TcHtbOption, TcNetemQoptBuffer,
+    TcQdiscFqCodelOption, TcQdiscFqOption, TcQdiscHfsc, TcQdiscHfscBuffer,
+    TcQdiscNetem, TcQdiscPrio, TcQdiscPrioBuffer, TcQdiscTbfOption,
+};
+
+const INGRESS: &str = "ingress";
+const CLSACT: &str = "clsact";
+const PFIFO_FAST: &str = "pfifo_fast";
+const PRIO: &str = "prio";
+const TBF: &str = "tbf";
+const FQ_CODEL: &str = "fq_codel";
+const FQ: &str = "fq";
+const NETEM: &str = "netem";
+const MQ: &str = "mq";
+const HTB: &str = "htb";
+const HFSC: &str = "hfsc";
+const FLOWER: &str = "flower";
+const U32: &str = "u32";
+const MATCHALL: &str = "matchall";
+const BPF: &str = "bpf";
+
+/// The payload of `TCA_OPTIONS`, its layout depends on the preceding
+/// `TCA_KIND`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcOptions {
+    Ingress,
+    Clsact,
+    PfifoFast(TcQdiscPrio),
+    Prio(TcQdiscPrio),
+    Tbf(Vec<TcQdiscTbfOption>),
+    FqCodel(Vec<TcQdiscFqCodelOption>),
+    Fq(Vec<TcQdiscFqOption>),
+    Netem(TcQdiscNetem),
+    Mq,
+    /// Options of `htb` qdisc or class
+    Htb(Vec<TcHtbOption>),
+    Hfsc(TcQdiscHfsc),
+    HfscClass(Vec<TcHfscOption>),
+    Flower(Vec<TcFilterFlowerOption>),
+    U32(Vec<TcFilterU32Option>),
+    MatchAll(Vec<TcFilterMatchAllOption>),
+    Bpf(Vec<TcFilterBpfOption>),
+    Other(Vec<u8>),
+}
+
+impl Emitable for TcOptions {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Ingress | Self::Clsact | Self::Mq => 0,
+            Self::PfifoFast(v) | Self::Prio(v) => v.buffer_len(),
+            Self::Tbf(nlas) => nlas.as_slice().buffer_len(),
+            Self::FqCodel(nlas) => nlas.as_slice().buffer_len(),
+            Self::Fq(nlas) => nlas.as_slice().buffer_len(),
+            Self::Netem(v) => v.buffer_len(),
+            Self::Htb(nlas) => nlas.as_slice().buffer_len(),
+            Self::Hfsc(v) => v.buffer_len(),
+            Self::HfscClass(nlas) => nlas.as_slice().buffer_len(),
+            Self::Flower(nlas) => nlas.as_slice().buffer_len(),
+            Self::U32(nlas) => nlas.as_slice().buffer_len(),
+            Self::MatchAll(nlas) => nlas.as_slice().buffer_len(),
+            Self::Bpf(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(v) => v.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Ingress | Self::Clsact | Self::Mq => (),
+            Self::PfifoFast(v) | Self::Prio(v) => v.emit(buffer),
+            Self::Tbf(nlas) => nlas.as_slice().emit(buffer),
+            Self::FqCodel(nlas) => nlas.as_slice().emit(buffer),
+            Self::Fq(nlas) => nlas.as_slice().emit(buffer),
+            Self::Netem(v) => v.emit(buffer),
+            Self::Htb(nlas) => nlas.as_slice().emit(buffer),
+            Self::Hfsc(v) => v.emit(buffer),
+            Self::HfscClass(nlas) => nlas.as_slice().emit(buffer),
+            Self::Flower(nlas) => nlas.as_slice().emit(buffer),
+            Self::U32(nlas) => nlas.as_slice().emit(buffer),
+            Self::MatchAll(nlas) => nlas.as_slice().emit(buffer),
+            Self::Bpf(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
+        }
+    }
+}
+
+impl TcOptions {
+    pub(crate) fn parse_with_param(
+        payload: &[u8],
+        kind: &str,
+        is_class: bool,
+    ) -> Result<TcOptions, DecodeError> {
+        Ok(match kind {
+            INGRESS => TcOptions::Ingress,
+            CLSACT => TcOptions::Clsact,
+            MQ => TcOptions::Mq,
+            PFIFO_FAST => TcOptions::PfifoFast(TcQdiscPrio::parse(
+                &TcQdiscPrioBuffer::new_checked(payload)?,
+            )?),
+            PRIO => TcOptions::Prio(TcQdiscPrio::parse(
+                &TcQdiscPrioBuffer::new_checked(payload)?,
+            )?),
+            TBF => TcOptions::Tbf(parse_nlas(payload)?),
+            FQ_CODEL => TcOptions::FqCodel(parse_nlas(payload)?),
+            FQ => TcOptions::Fq(parse_nlas(payload)?),
+            NETEM => TcOptions::Netem(TcQdiscNetem::parse(
+                &TcNetemQoptBuffer::new_checked(payload)?,
+            )?),
+            HTB => TcOptions::Htb(parse_nlas(payload)?),
+            // The qdisc options is a bare `struct tc_hfsc_qopt`, while the
+            // class options are nested service curves. The root class has
+            // no service curve, hence dumped with empty options.
+            HFSC if is_class || payload.is_empty() => {
+                TcOptions::HfscClass(parse_nlas(payload)?)
+            }
+            HFSC => TcOptions::Hfsc(TcQdiscHfsc::parse(
+                &TcQdiscHfscBuffer::new_checked(payload)?,
+            )?),
+            FLOWER => TcOptions::Flower(parse_nlas(payload)?),
+            U32 => TcOptions::U32(parse_nlas(payload)?),
+            MATCHALL => TcOptions::MatchAll(parse_nlas(payload)?),
+            BPF => TcOptions::Bpf(parse_nlas(payload)?),
+            _ => TcOptions::Other(payload.to_vec()),
+        })
+    }
+}
+
+pub(crate) fn parse_nlas<T>(payload: &[u8]) -> Result<Vec<T>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        nlas.push(T::parse(&nla?)?);
+    }
+    Ok(nlas)
+}
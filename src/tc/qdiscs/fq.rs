@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use super::{TcQdiscPrio, TcQdiscPrioBuffer};
+
+const TCA_FQ_PLIMIT: u16 = 1;
+const TCA_FQ_FLOW_PLIMIT: u16 = 2;
+const TCA_FQ_QUANTUM: u16 = 3;
+const TCA_FQ_INITIAL_QUANTUM: u16 = 4;
+const TCA_FQ_RATE_ENABLE: u16 = 5;
+// const TCA_FQ_FLOW_DEFAULT_RATE: u16 = 6; // obsolete, do not use
+const TCA_FQ_FLOW_MAX_RATE: u16 = 7;
+const TCA_FQ_BUCKETS_LOG: u16 = 8;
+const TCA_FQ_FLOW_REFILL_DELAY: u16 = 9;
+const TCA_FQ_ORPHAN_MASK: u16 = 10;
+const TCA_FQ_LOW_RATE_THRESHOLD: u16 = 11;
+const TCA_FQ_CE_THRESHOLD: u16 = 12;
+const TCA_FQ_TIMER_SLACK: u16 = 13;
+const TCA_FQ_HORIZON: u16 = 14;
+const TCA_FQ_HORIZON_DROP: u16 = 15;
+const TCA_FQ_PRIOMAP: u16 = 16;
+// const TCA_FQ_WEIGHTS: u16 = 17;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcQdiscFqOption {
+    /// Limit of total number of packets in queue
+    Plimit(u32),
+    /// Limit of packets per flow
+    FlowPlimit(u32),
+    /// Quantum in bytes for each round robin turn
+    Quantum(u32),
+    /// Initial quantum in bytes for new flows
+    InitialQuantum(u32),
+    /// Enable pacing
+    RateEnable(bool),
+    /// Per flow max rate in bytes per second
+    FlowMaxRate(u32),
+    /// `log2` of the number of buckets
+    BucketsLog(u32),
+    /// Flow credit refill delay in microseconds
+    FlowRefillDelay(u32),
+    /// Mask applied to orphaned skb hashes
+    OrphanMask(u32),
+    /// Per packet delay under this rate in bytes per second
+    LowRateThreshold(u32),
+    /// DCTCP-like CE marking threshold in microseconds
+    CeThreshold(u32),
+    /// Timer slack in nanoseconds
+    TimerSlack(u32),
+    /// Time horizon in microseconds
+    Horizon(u32),
+    /// Drop packets beyond horizon, or cap their EDT
+    HorizonDrop(bool),
+    /// Map from packet priority to band
+    Priomap(TcQdiscPrio),
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscFqOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Plimit(_)
+            | Self::FlowPlimit(_)
+            | Self::Quantum(_)
+            | Self::InitialQuantum(_)
+            | Self::RateEnable(_)
+            | Self::FlowMaxRate(_)
+            | Self::BucketsLog(_)
+            | Self::FlowRefillDelay(_)
+            | Self::OrphanMask(_)
+            | Self::LowRateThreshold(_)
+            | Self::CeThreshold(_)
+            | Self::TimerSlack(_)
+            | Self::Horizon(_) => 4,
+            Self::HorizonDrop(_) => 1,
+            Self::Priomap(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Plimit(d)
+            | Self::FlowPlimit(d)
+            | Self::Quantum(d)
+            | Self::InitialQuantum(d)
+            | Self::FlowMaxRate(d)
+            | Self::BucketsLog(d)
+            | Self::FlowRefillDelay(d)
+            | Self::OrphanMask(d)
+            | Self::LowRateThreshold(d)
+            | Self::CeThreshold(d)
+            | Self::TimerSlack(d)
+            | Self::Horizon(d) => NativeEndian::write_u32(buffer, *d),
+            Self::RateEnable(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::HorizonDrop(d) => buffer[0] = (*d).into(),
+            Self::Priomap(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Plimit(_) => TCA_FQ_PLIMIT,
+            Self::FlowPlimit(_) => TCA_FQ_FLOW_PLIMIT,
+            Self::Quantum(_) => TCA_FQ_QUANTUM,
+            Self::InitialQuantum(_) => TCA_FQ_INITIAL_QUANTUM,
+            Self::RateEnable(_) => TCA_FQ_RATE_ENABLE,
+            Self::FlowMaxRate(_) => TCA_FQ_FLOW_MAX_RATE,
+            Self::BucketsLog(_) => TCA_FQ_BUCKETS_LOG,
+            Self::FlowRefillDelay(_) => TCA_FQ_FLOW_REFILL_DELAY,
+            Self::OrphanMask(_) => TCA_FQ_ORPHAN_MASK,
+            Self::LowRateThreshold(_) => TCA_FQ_LOW_RATE_THRESHOLD,
+            Self::CeThreshold(_) => TCA_FQ_CE_THRESHOLD,
+            Self::TimerSlack(_) => TCA_FQ_TIMER_SLACK,
+            Self::Horizon(_) => TCA_FQ_HORIZON,
+            Self::HorizonDrop(_) => TCA_FQ_HORIZON_DROP,
+            Self::Priomap(_) => TCA_FQ_PRIOMAP,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscFqOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FQ_PLIMIT => Self::Plimit(parse_u32(payload)?),
+            TCA_FQ_FLOW_PLIMIT => Self::FlowPlimit(parse_u32(payload)?),
+            TCA_FQ_QUANTUM => Self::Quantum(parse_u32(payload)?),
+            TCA_FQ_INITIAL_QUANTUM => Self::InitialQuantum(parse_u32(payload)?),
+            TCA_FQ_RATE_ENABLE => Self::RateEnable(parse_u32(payload)? > 0),
+            TCA_FQ_FLOW_MAX_RATE => Self::FlowMaxRate(parse_u32(payload)?),
+            TCA_FQ_BUCKETS_LOG => Self::BucketsLog(parse_u32(payload)?),
+            TCA_FQ_FLOW_REFILL_DELAY => {
+                Self::FlowRefillDelay(parse_u32(payload)?)
+            }
+            TCA_FQ_ORPHAN_MASK => Self::OrphanMask(parse_u32(payload)?),
+            TCA_FQ_LOW_RATE_THRESHOLD => {
+                Self::LowRateThreshold(parse_u32(payload)?)
+            }
+            TCA_FQ_CE_THRESHOLD => Self::CeThreshold(parse_u32(payload)?),
+            TCA_FQ_TIMER_SLACK => Self::TimerSlack(parse_u32(payload)?),
+            TCA_FQ_HORIZON => Self::Horizon(parse_u32(payload)?),
+            TCA_FQ_HORIZON_DROP => Self::HorizonDrop(parse_u8(payload)? > 0),
+            TCA_FQ_PRIOMAP => Self::Priomap(TcQdiscPrio::parse(
+                &TcQdiscPrioBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+const TCA_FQ_CODEL_TARGET: u16 = 1;
+const TCA_FQ_CODEL_LIMIT: u16 = 2;
+const TCA_FQ_CODEL_INTERVAL: u16 = 3;
+const TCA_FQ_CODEL_ECN: u16 = 4;
+const TCA_FQ_CODEL_FLOWS: u16 = 5;
+const TCA_FQ_CODEL_QUANTUM: u16 = 6;
+const TCA_FQ_CODEL_CE_THRESHOLD: u16 = 7;
+const TCA_FQ_CODEL_DROP_BATCH_SIZE: u16 = 8;
+const TCA_FQ_CODEL_MEMORY_LIMIT: u16 = 9;
+const TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR: u16 = 10;
+const TCA_FQ_CODEL_CE_THRESHOLD_MASK: u16 = 11;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcQdiscFqCodelOption {
+    /// Acceptable minimum standing queue delay in microseconds
+    Target(u32),
+    /// Hard limit on the queue size in packets
+    Limit(u32),
+    /// Width of the moving window in microseconds
+    Interval(u32),
+    Ecn(bool),
+    /// Number of flows into which the incoming packets are classified
+    Flows(u32),
+    /// Number of bytes used as deficit in the fair queuing algorithm
+    Quantum(u32),
+    /// Threshold in microseconds above which packets are ECN marked
+    CeThreshold(u32),
+    DropBatchSize(u32),
+    /// Memory limit in bytes
+    MemoryLimit(u32),
+    CeThresholdSelector(u8),
+    CeThresholdMask(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscFqCodelOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Target(_)
+            | Self::Limit(_)
+            | Self::Interval(_)
+            | Self::Ecn(_)
+            | Self::Flows(_)
+            | Self::Quantum(_)
+            | Self::CeThreshold(_)
+            | Self::DropBatchSize(_)
+            | Self::MemoryLimit(_) => 4,
+            Self::CeThresholdSelector(_) | Self::CeThresholdMask(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Target(d)
+            | Self::Limit(d)
+            | Self::Interval(d)
+            | Self::Flows(d)
+            | Self::Quantum(d)
+            | Self::CeThreshold(d)
+            | Self::DropBatchSize(d)
+            | Self::MemoryLimit(d) => NativeEndian::write_u32(buffer, *d),
+            Self::Ecn(d) => NativeEndian::write_u32(buffer, (*d).into()),
+            Self::CeThresholdSelector(d) | Self::CeThresholdMask(d) => {
+                buffer[0] = *d
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Target(_) => TCA_FQ_CODEL_TARGET,
+            Self::Limit(_) => TCA_FQ_CODEL_LIMIT,
+            Self::Interval(_) => TCA_FQ_CODEL_INTERVAL,
+            Self::Ecn(_) => TCA_FQ_CODEL_ECN,
+            Self::Flows(_) => TCA_FQ_CODEL_FLOWS,
+            Self::Quantum(_) => TCA_FQ_CODEL_QUANTUM,
+            Self::CeThreshold(_) => TCA_FQ_CODEL_CE_THRESHOLD,
+            Self::DropBatchSize(_) => TCA_FQ_CODEL_DROP_BATCH_SIZE,
+            Self::MemoryLimit(_) => TCA_FQ_CODEL_MEMORY_LIMIT,
+            Self::CeThresholdSelector(_) => TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR,
+            Self::CeThresholdMask(_) => TCA_FQ_CODEL_CE_THRESHOLD_MASK,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscFqCodelOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FQ_CODEL_TARGET => Self::Target(parse_u32(payload)?),
+            TCA_FQ_CODEL_LIMIT => Self::Limit(parse_u32(payload)?),
+            TCA_FQ_CODEL_INTERVAL => Self::Interval(parse_u32(payload)?),
+            TCA_FQ_CODEL_ECN => Self::Ecn(parse_u32(payload)? > 0),
+            TCA_FQ_CODEL_FLOWS => Self::Flows(parse_u32(payload)?),
+            TCA_FQ_CODEL_QUANTUM => Self::Quantum(parse_u32(payload)?),
+            TCA_FQ_CODEL_CE_THRESHOLD => Self::CeThreshold(parse_u32(payload)?),
+            TCA_FQ_CODEL_DROP_BATCH_SIZE => {
+                Self::DropBatchSize(parse_u32(payload)?)
+            }
+            TCA_FQ_CODEL_MEMORY_LIMIT => Self::MemoryLimit(parse_u32(payload)?),
+            TCA_FQ_CODEL_CE_THRESHOLD_SELECTOR => {
+                Self::CeThresholdSelector(parse_u8(payload)?)
+            }
+            TCA_FQ_CODEL_CE_THRESHOLD_MASK => {
+                Self::CeThresholdMask(parse_u8(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TCA_HFSC_RSC: u16 = 1;
+const TCA_HFSC_FSC: u16 = 2;
+const TCA_HFSC_USC: u16 = 3;
+
+const TC_QDISC_HFSC_LEN: usize = 2;
+
+buffer!(TcQdiscHfscBuffer(TC_QDISC_HFSC_LEN) {
+    defcls: (u16, 0..2),
+});
+
+/// Options(`struct tc_hfsc_qopt`) of `hfsc` qdisc. Like `prio`, this is
+/// the raw payload of `TCA_OPTIONS` instead of nested attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcQdiscHfsc {
+    /// Default class minor ID for unclassified packets
+    pub defcls: u16,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcQdiscHfscBuffer<T>> for TcQdiscHfsc {
+    fn parse(buf: &TcQdiscHfscBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            defcls: buf.defcls(),
+        })
+    }
+}
+
+impl Emitable for TcQdiscHfsc {
+    fn buffer_len(&self) -> usize {
+        TC_QDISC_HFSC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcQdiscHfscBuffer::new(buffer);
+        buffer.set_defcls(self.defcls);
+    }
+}
+
+const TC_HFSC_SERVICE_CURVE_LEN: usize = 12;
+
+buffer!(TcHfscServiceCurveBuffer(TC_HFSC_SERVICE_CURVE_LEN) {
+    m1: (u32, 0..4),
+    d: (u32, 4..8),
+    m2: (u32, 8..12),
+});
+
+/// Two-piece linear service curve(`struct tc_service_curve`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcHfscServiceCurve {
+    /// Slope of the first segment in bytes per second
+    pub m1: u32,
+    /// x-projection of the first segment in microseconds
+    pub d: u32,
+    /// Slope of the second segment in bytes per second
+    pub m2: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcHfscServiceCurveBuffer<T>>
+    for TcHfscServiceCurve
+{
+    fn parse(buf: &TcHfscServiceCurveBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            m1: buf.m1(),
+            d: buf.d(),
+            m2: buf.m2(),
+        })
+    }
+}
+
+impl Emitable for TcHfscServiceCurve {
+    fn buffer_len(&self) -> usize {
+        TC_HFSC_SERVICE_CURVE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcHfscServiceCurveBuffer::new(buffer);
+        buffer.set_m1(self.m1);
+        buffer.set_d(self.d);
+        buffer.set_m2(self.m2);
+    }
+}
+
+/// Class options of `hfsc`
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcHfscOption {
+    /// Real-time service curve
+    Rsc(TcHfscServiceCurve),
+    /// Link-sharing service curve
+    Fsc(TcHfscServiceCurve),
+    /// Upper-limit service curve
+    Usc(TcHfscServiceCurve),
+    Other(DefaultNla),
+}
+
+impl Nla for TcHfscOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Rsc(v) | Self::Fsc(v) | Self::Usc(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Rsc(v) | Self::Fsc(v) | Self::Usc(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Rsc(_) => TCA_HFSC_RSC,
+            Self::Fsc(_) => TCA_HFSC_FSC,
+            Self::Usc(_) => TCA_HFSC_USC,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcHfscOption {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_HFSC_RSC => Self::Rsc(TcHfscServiceCurve::parse(
+                &TcHfscServiceCurveBuffer::new_checked(payload)?,
+            )?),
+            TCA_HFSC_FSC => Self::Fsc(TcHfscServiceCurve::parse(
+                &TcHfscServiceCurveBuffer::new_checked(payload)?,
+            )?),
+            TCA_HFSC_USC => Self::Usc(TcHfscServiceCurve::parse(
+                &TcHfscServiceCurveBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
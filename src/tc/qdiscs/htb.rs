@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{rate_spec::TC_RATE_SPEC_LEN, TcRateSpec, TcRateSpecBuffer};
+
+const TCA_HTB_PARMS: u16 = 1;
+const TCA_HTB_INIT: u16 = 2;
+const TCA_HTB_CTAB: u16 = 3;
+const TCA_HTB_RTAB: u16 = 4;
+const TCA_HTB_DIRECT_QLEN: u16 = 5;
+const TCA_HTB_RATE64: u16 = 6;
+const TCA_HTB_CEIL64: u16 = 7;
+// const TCA_HTB_PAD: u16 = 8;
+const TCA_HTB_OFFLOAD: u16 = 9;
+
+const TC_HTB_GLOB_LEN: usize = 20;
+
+buffer!(TcHtbGlobBuffer(TC_HTB_GLOB_LEN) {
+    version: (u32, 0..4),
+    rate2quantum: (u32, 4..8),
+    defcls: (u32, 8..12),
+    debug: (u32, 12..16),
+    direct_pkts: (u32, 16..20),
+});
+
+/// Global options(`struct tc_htb_glob`) of `htb` qdisc
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcHtbGlob {
+    /// Version of the HTB protocol, should be 3
+    pub version: u32,
+    /// Divisor from rate to quantum of classes
+    pub rate2quantum: u32,
+    /// Default class minor ID for unclassified packets
+    pub defcls: u32,
+    pub debug: u32,
+    /// Number of packets sent directly without classification. Ignored
+    /// when sent to kernel.
+    pub direct_pkts: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcHtbGlobBuffer<T>> for TcHtbGlob {
+    fn parse(buf: &TcHtbGlobBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            version: buf.version(),
+            rate2quantum: buf.rate2quantum(),
+            defcls: buf.defcls(),
+            debug: buf.debug(),
+            direct_pkts: buf.direct_pkts(),
+        })
+    }
+}
+
+impl Emitable for TcHtbGlob {
+    fn buffer_len(&self) -> usize {
+        TC_HTB_GLOB_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcHtbGlobBuffer::new(buffer);
+        buffer.set_version(self.version);
+        buffer.set_rate2quantum(self.rate2quantum);
+        buffer.set_defcls(self.defcls);
+        buffer.set_debug(self.debug);
+        buffer.set_direct_pkts(self.direct_pkts);
+    }
+}
+
+const TC_HTB_OPT_LEN: usize = TC_RATE_SPEC_LEN * 2 + 20;
+
+buffer!(TcHtbOptBuffer(TC_HTB_OPT_LEN) {
+    rate: (slice, 0..TC_RATE_SPEC_LEN),
+    ceil: (slice, TC_RATE_SPEC_LEN..(TC_RATE_SPEC_LEN * 2)),
+    buffer: (u32, (TC_RATE_SPEC_LEN * 2)..(TC_RATE_SPEC_LEN * 2 + 4)),
+    cbuffer: (u32, (TC_RATE_SPEC_LEN * 2 + 4)..(TC_RATE_SPEC_LEN * 2 + 8)),
+    quantum: (u32, (TC_RATE_SPEC_LEN * 2 + 8)..(TC_RATE_SPEC_LEN * 2 + 12)),
+    level: (u32, (TC_RATE_SPEC_LEN * 2 + 12)..(TC_RATE_SPEC_LEN * 2 + 16)),
+    prio: (u32, (TC_RATE_SPEC_LEN * 2 + 16)..TC_HTB_OPT_LEN),
+});
+
+/// Class parameters(`struct tc_htb_opt`) of `htb`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcHtbOpt {
+    /// Guaranteed rate
+    pub rate: TcRateSpec,
+    /// Maximum rate when borrowing from parent
+    pub ceil: TcRateSpec,
+    /// Burst size at `rate` in time units(`psched` ticks)
+    pub buffer: u32,
+    /// Burst size at `ceil` in time units(`psched` ticks)
+    pub cbuffer: u32,
+    /// Number of bytes served before moving to next class
+    pub quantum: u32,
+    /// Level of the class in the hierarchy, out only
+    pub level: u32,
+    /// Lower value means higher priority
+    pub prio: u32,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcHtbOptBuffer<&'a T>>
+    for TcHtbOpt
+{
+    fn parse(buf: &TcHtbOptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.rate()))?,
+            ceil: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.ceil()))?,
+            buffer: buf.buffer(),
+            cbuffer: buf.cbuffer(),
+            quantum: buf.quantum(),
+            level: buf.level(),
+            prio: buf.prio(),
+        })
+    }
+}
+
+impl Emitable for TcHtbOpt {
+    fn buffer_len(&self) -> usize {
+        TC_HTB_OPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcHtbOptBuffer::new(buffer);
+        self.rate.emit(buffer.rate_mut());
+        self.ceil.emit(buffer.ceil_mut());
+        buffer.set_buffer(self.buffer);
+        buffer.set_cbuffer(self.cbuffer);
+        buffer.set_quantum(self.quantum);
+        buffer.set_level(self.level);
+        buffer.set_prio(self.prio);
+    }
+}
+
+/// Options of `htb` qdisc and its classes
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcHtbOption {
+    /// Class parameters
+    Parms(TcHtbOpt),
+    /// Qdisc global parameters
+    Init(TcHtbGlob),
+    /// Ceil rate table, 256 of `u32` transmission times
+    Ctab(Vec<u8>),
+    /// Rate table, 256 of `u32` transmission times
+    Rtab(Vec<u8>),
+    /// Queue length of the direct queue in packets
+    DirectQlen(u32),
+    /// Guaranteed rate in bytes per second when it does not fit into `u32`
+    Rate64(u64),
+    /// Ceil rate in bytes per second when it does not fit into `u32`
+    Ceil64(u64),
+    /// Hardware offload enabled
+    Offload,
+    Other(DefaultNla),
+}
+
+impl Nla for TcHtbOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Parms(v) => v.buffer_len(),
+            Self::Init(v) => v.buffer_len(),
+            Self::Ctab(v) | Self::Rtab(v) => v.len(),
+            Self::DirectQlen(_) => 4,
+            Self::Rate64(_) | Self::Ceil64(_) => 8,
+            Self::Offload => 0,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Parms(v) => v.emit(buffer),
+            Self::Init(v) => v.emit(buffer),
+            Self::Ctab(v) | Self::Rtab(v) => {
+                buffer.copy_from_slice(v.as_slice())
+            }
+            Self::DirectQlen(v) => NativeEndian::write_u32(buffer, *v),
+            Self::Rate64(v) | Self::Ceil64(v) => {
+                NativeEndian::write_u64(buffer, *v)
+            }
+            Self::Offload => (),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Parms(_) => TCA_HTB_PARMS,
+            Self::Init(_) => TCA_HTB_INIT,
+            Self::Ctab(_) => TCA_HTB_CTAB,
+            Self::Rtab(_) => TCA_HTB_RTAB,
+            Self::DirectQlen(_) => TCA_HTB_DIRECT_QLEN,
+            Self::Rate64(_) => TCA_HTB_RATE64,
+            Self::Ceil64(_) => TCA_HTB_CEIL64,
+            Self::Offload => TCA_HTB_OFFLOAD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for TcHtbOption {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_HTB_PARMS => Self::Parms(TcHtbOpt::parse(
+                &TcHtbOptBuffer::new_checked(payload)?,
+            )?),
+            TCA_HTB_INIT => Self::Init(TcHtbGlob::parse(
+                &TcHtbGlobBuffer::new_checked(payload)?,
+            )?),
+            TCA_HTB_CTAB => Self::Ctab(payload.to_vec()),
+            TCA_HTB_RTAB => Self::Rtab(payload.to_vec()),
+            TCA_HTB_DIRECT_QLEN => Self::DirectQlen(parse_u32(payload)?),
+            TCA_HTB_RATE64 => Self::Rate64(parse_u64(payload)?),
+            TCA_HTB_CEIL64 => Self::Ceil64(parse_u64(payload)?),
+            TCA_HTB_OFFLOAD => Self::Offload,
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const TC_HTB_XSTATS_LEN: usize = 20;
+
+buffer!(TcHtbXstatsBuffer(TC_HTB_XSTATS_LEN) {
+    lends: (u32, 0..4),
+    borrows: (u32, 4..8),
+    giants: (u32, 8..12),
+    tokens: (i32, 12..16),
+    ctokens: (i32, 16..20),
+});
+
+/// Class statistics(`struct tc_htb_xstats`) of `htb`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcHtbXstats {
+    /// Number of packets sent within the guaranteed rate
+    pub lends: u32,
+    /// Number of packets sent by borrowing from parent
+    pub borrows: u32,
+    /// Number of packets bigger than the MTU, always 0 nowadays
+    pub giants: u32,
+    /// Remaining tokens at `rate` in `psched` ticks
+    pub tokens: i32,
+    /// Remaining tokens at `ceil` in `psched` ticks
+    pub ctokens: i32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcHtbXstatsBuffer<T>> for TcHtbXstats {
+    fn parse(buf: &TcHtbXstatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            lends: buf.lends(),
+            borrows: buf.borrows(),
+            giants: buf.giants(),
+            tokens: buf.tokens(),
+            ctokens: buf.ctokens(),
+        })
+    }
+}
+
+impl Emitable for TcHtbXstats {
+    fn buffer_len(&self) -> usize {
+        TC_HTB_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcHtbXstatsBuffer::new(buffer);
+        buffer.set_lends(self.lends);
+        buffer.set_borrows(self.borrows);
+        buffer.set_giants(self.giants);
+        buffer.set_tokens(self.tokens);
+        buffer.set_ctokens(self.ctokens);
+    }
+}
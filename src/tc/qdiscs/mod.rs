@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+mod fq;
+mod fq_codel;
+mod hfsc;
+mod htb;
+mod netem;
+mod prio;
+mod tbf;
+
+pub use self::fq::TcQdiscFqOption;
+pub use self::fq_codel::TcQdiscFqCodelOption;
+pub use self::hfsc::{
+    TcHfscOption, TcHfscServiceCurve, TcHfscServiceCurveBuffer, TcQdiscHfsc,
+    TcQdiscHfscBuffer,
+};
+pub use self::htb::{
+    TcHtbGlob, TcHtbGlobBuffer, TcHtbOpt, TcHtbOptBuffer, TcHtbOption,
+    TcHtbXstats, TcHtbXstatsBuffer,
+};
+pub use self::netem::{
+    TcNetemCorr, TcNetemCorrBuffer, TcNetemLossModel, TcNetemProbability,
+    TcNetemProbabilityBuffer, TcNetemQopt, TcNetemQoptBuffer, TcNetemRate,
+    TcNetemRateBuffer, TcNetemSlot, TcNetemSlotBuffer, TcQdiscNetem,
+    TcQdiscNetemOption,
+};
+pub use self::prio::{TcQdiscPrio, TcQdiscPrioBuffer};
+pub use self::tbf::{TcQdiscTbfOption, TcTbfQopt, TcTbfQoptBuffer};
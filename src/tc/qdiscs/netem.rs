@@ -0,0 +1,506 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use axerrno::AxError;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TCA_NETEM_CORR: u16 = 1;
+const TCA_NETEM_DELAY_DIST: u16 = 2;
+const TCA_NETEM_REORDER: u16 = 3;
+const TCA_NETEM_CORRUPT: u16 = 4;
+const TCA_NETEM_LOSS: u16 = 5;
+const TCA_NETEM_RATE: u16 = 6;
+const TCA_NETEM_ECN: u16 = 7;
+const TCA_NETEM_RATE64: u16 = 8;
+// const TCA_NETEM_PAD: u16 = 9;
+const TCA_NETEM_LATENCY64: u16 = 10;
+const TCA_NETEM_JITTER64: u16 = 11;
+const TCA_NETEM_SLOT: u16 = 12;
+// const TCA_NETEM_SLOT_DIST: u16 = 13;
+const TCA_NETEM_PRNG_SEED: u16 = 14;
+
+const NETEM_LOSS_GI: u16 = 1;
+const NETEM_LOSS_GE: u16 = 2;
+
+const TC_NETEM_QOPT_LEN: usize = 24;
+
+buffer!(TcNetemQoptBuffer(TC_NETEM_QOPT_LEN) {
+    latency: (u32, 0..4),
+    limit: (u32, 4..8),
+    loss: (u32, 8..12),
+    gap: (u32, 12..16),
+    duplicate: (u32, 16..20),
+    jitter: (u32, 20..24),
+    payload: (slice, TC_NETEM_QOPT_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> TcNetemQoptBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// Options of `netem` qdisc. The payload of `TCA_OPTIONS` is a
+/// `struct tc_netem_qopt` followed by nested attributes.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TcQdiscNetem {
+    pub qopt: TcNetemQopt,
+    pub attributes: Vec<TcQdiscNetemOption>,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcNetemQoptBuffer<&'a T>>
+    for TcQdiscNetem
+{
+    fn parse(buf: &TcNetemQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut attributes = Vec::new();
+        for nla in buf.attributes() {
+            attributes.push(TcQdiscNetemOption::parse(&nla?)?);
+        }
+        Ok(Self {
+            qopt: TcNetemQopt::parse(buf)?,
+            attributes,
+        })
+    }
+}
+
+impl Emitable for TcQdiscNetem {
+    fn buffer_len(&self) -> usize {
+        self.qopt.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.qopt.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.qopt.buffer_len()..]);
+    }
+}
+
+/// The `struct tc_netem_qopt`. Probabilities are scaled so that
+/// `u32::MAX` means 100%.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcNetemQopt {
+    /// Added delay in `psched` ticks
+    pub latency: u32,
+    /// FIFO limit in packets
+    pub limit: u32,
+    /// Random packet loss probability
+    pub loss: u32,
+    /// Re-ordering gap, 0 for none
+    pub gap: u32,
+    /// Random packet duplication probability
+    pub duplicate: u32,
+    /// Random jitter in latency in `psched` ticks
+    pub jitter: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemQoptBuffer<T>> for TcNetemQopt {
+    fn parse(buf: &TcNetemQoptBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            latency: buf.latency(),
+            limit: buf.limit(),
+            loss: buf.loss(),
+            gap: buf.gap(),
+            duplicate: buf.duplicate(),
+            jitter: buf.jitter(),
+        })
+    }
+}
+
+impl Emitable for TcNetemQopt {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcNetemQoptBuffer::new(buffer);
+        buffer.set_latency(self.latency);
+        buffer.set_limit(self.limit);
+        buffer.set_loss(self.loss);
+        buffer.set_gap(self.gap);
+        buffer.set_duplicate(self.duplicate);
+        buffer.set_jitter(self.jitter);
+    }
+}
+
+const TC_NETEM_CORR_LEN: usize = 12;
+
+buffer!(TcNetemCorrBuffer(TC_NETEM_CORR_LEN) {
+    delay_corr: (u32, 0..4),
+    loss_corr: (u32, 4..8),
+    dup_corr: (u32, 8..12),
+});
+
+/// Correlations(`struct tc_netem_corr`) of delay, loss and duplication.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcNetemCorr {
+    pub delay_corr: u32,
+    pub loss_corr: u32,
+    pub dup_corr: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemCorrBuffer<T>> for TcNetemCorr {
+    fn parse(buf: &TcNetemCorrBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            delay_corr: buf.delay_corr(),
+            loss_corr: buf.loss_corr(),
+            dup_corr: buf.dup_corr(),
+        })
+    }
+}
+
+impl Emitable for TcNetemCorr {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_CORR_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcNetemCorrBuffer::new(buffer);
+        buffer.set_delay_corr(self.delay_corr);
+        buffer.set_loss_corr(self.loss_corr);
+        buffer.set_dup_corr(self.dup_corr);
+    }
+}
+
+const TC_NETEM_PROBABILITY_LEN: usize = 8;
+
+buffer!(TcNetemProbabilityBuffer(TC_NETEM_PROBABILITY_LEN) {
+    probability: (u32, 0..4),
+    correlation: (u32, 4..8),
+});
+
+/// Probability and its correlation, shared by `struct tc_netem_reorder`
+/// and `struct tc_netem_corrupt`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcNetemProbability {
+    pub probability: u32,
+    pub correlation: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemProbabilityBuffer<T>>
+    for TcNetemProbability
+{
+    fn parse(buf: &TcNetemProbabilityBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            probability: buf.probability(),
+            correlation: buf.correlation(),
+        })
+    }
+}
+
+impl Emitable for TcNetemProbability {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_PROBABILITY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcNetemProbabilityBuffer::new(buffer);
+        buffer.set_probability(self.probability);
+        buffer.set_correlation(self.correlation);
+    }
+}
+
+const TC_NETEM_RATE_LEN: usize = 16;
+
+buffer!(TcNetemRateBuffer(TC_NETEM_RATE_LEN) {
+    rate: (u32, 0..4),
+    packet_overhead: (i32, 4..8),
+    cell_size: (u32, 8..12),
+    cell_overhead: (i32, 12..16),
+});
+
+/// Rate emulation(`struct tc_netem_rate`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcNetemRate {
+    /// Rate in bytes per second
+    pub rate: u32,
+    pub packet_overhead: i32,
+    pub cell_size: u32,
+    pub cell_overhead: i32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemRateBuffer<T>> for TcNetemRate {
+    fn parse(buf: &TcNetemRateBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: buf.rate(),
+            packet_overhead: buf.packet_overhead(),
+            cell_size: buf.cell_size(),
+            cell_overhead: buf.cell_overhead(),
+        })
+    }
+}
+
+impl Emitable for TcNetemRate {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_RATE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcNetemRateBuffer::new(buffer);
+        buffer.set_rate(self.rate);
+        buffer.set_packet_overhead(self.packet_overhead);
+        buffer.set_cell_size(self.cell_size);
+        buffer.set_cell_overhead(self.cell_overhead);
+    }
+}
+
+const TC_NETEM_SLOT_LEN: usize = 40;
+
+buffer!(TcNetemSlotBuffer(TC_NETEM_SLOT_LEN) {
+    min_delay: (i64, 0..8),
+    max_delay: (i64, 8..16),
+    max_packets: (i32, 16..20),
+    max_bytes: (i32, 20..24),
+    dist_delay: (i64, 24..32),
+    dist_jitter: (i64, 32..40),
+});
+
+/// Slotting(`struct tc_netem_slot`), delays are in nanoseconds.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcNetemSlot {
+    pub min_delay: i64,
+    pub max_delay: i64,
+    pub max_packets: i32,
+    pub max_bytes: i32,
+    pub dist_delay: i64,
+    pub dist_jitter: i64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcNetemSlotBuffer<T>> for TcNetemSlot {
+    fn parse(buf: &TcNetemSlotBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            min_delay: buf.min_delay(),
+            max_delay: buf.max_delay(),
+            max_packets: buf.max_packets(),
+            max_bytes: buf.max_bytes(),
+            dist_delay: buf.dist_delay(),
+            dist_jitter: buf.dist_jitter(),
+        })
+    }
+}
+
+impl Emitable for TcNetemSlot {
+    fn buffer_len(&self) -> usize {
+        TC_NETEM_SLOT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcNetemSlotBuffer::new(buffer);
+        buffer.set_min_delay(self.min_delay);
+        buffer.set_max_delay(self.max_delay);
+        buffer.set_max_packets(self.max_packets);
+        buffer.set_max_bytes(self.max_bytes);
+        buffer.set_dist_delay(self.dist_delay);
+        buffer.set_dist_jitter(self.dist_jitter);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcQdiscNetemOption {
+    Corr(TcNetemCorr),
+    /// Delay distribution table
+    DelayDist(Vec<i16>),
+    Reorder(TcNetemProbability),
+    Corrupt(TcNetemProbability),
+    Loss(Vec<TcNetemLossModel>),
+    Rate(TcNetemRate),
+    /// Mark packets with ECN instead of dropping them
+    Ecn(bool),
+    /// Rate in bytes per second when it does not fit into `u32`
+    Rate64(u64),
+    /// Added delay in nanoseconds
+    Latency64(i64),
+    /// Random jitter in latency in nanoseconds
+    Jitter64(i64),
+    Slot(TcNetemSlot),
+    PrngSeed(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscNetemOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Corr(v) => v.buffer_len(),
+            Self::DelayDist(v) => v.len() * 2,
+            Self::Reorder(v) | Self::Corrupt(v) => v.buffer_len(),
+            Self::Loss(v) => v.as_slice().buffer_len(),
+            Self::Rate(v) => v.buffer_len(),
+            Self::Ecn(_) => 4,
+            Self::Rate64(_)
+            | Self::Latency64(_)
+            | Self::Jitter64(_)
+            | Self::PrngSeed(_) => 8,
+            Self::Slot(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Corr(v) => v.emit(buffer),
+            Self::DelayDist(v) => {
+                for (i, d) in v.iter().enumerate() {
+                    NativeEndian::write_i16(&mut buffer[i * 2..i * 2 + 2], *d);
+                }
+            }
+            Self::Reorder(v) | Self::Corrupt(v) => v.emit(buffer),
+            Self::Loss(v) => v.as_slice().emit(buffer),
+            Self::Rate(v) => v.emit(buffer),
+            Self::Ecn(v) => NativeEndian::write_u32(buffer, (*v).into()),
+            Self::Rate64(v) | Self::PrngSeed(v) => {
+                NativeEndian::write_u64(buffer, *v)
+            }
+            Self::Latency64(v) | Self::Jitter64(v) => {
+                NativeEndian::write_i64(buffer, *v)
+            }
+            Self::Slot(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Corr(_) => TCA_NETEM_CORR,
+            Self::DelayDist(_) => TCA_NETEM_DELAY_DIST,
+            Self::Reorder(_) => TCA_NETEM_REORDER,
+            Self::Corrupt(_) => TCA_NETEM_CORRUPT,
+            Self::Loss(_) => TCA_NETEM_LOSS | NLA_F_NESTED,
+            Self::Rate(_) => TCA_NETEM_RATE,
+            Self::Ecn(_) => TCA_NETEM_ECN,
+            Self::Rate64(_) => TCA_NETEM_RATE64,
+            Self::Latency64(_) => TCA_NETEM_LATENCY64,
+            Self::Jitter64(_) => TCA_NETEM_JITTER64,
+            Self::Slot(_) => TCA_NETEM_SLOT,
+            Self::PrngSeed(_) => TCA_NETEM_PRNG_SEED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscNetemOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_NETEM_CORR => Self::Corr(TcNetemCorr::parse(
+                &TcNetemCorrBuffer::new_checked(payload)?,
+            )?),
+            TCA_NETEM_DELAY_DIST => Self::DelayDist(
+                payload
+                    .chunks_exact(2)
+                    .map(NativeEndian::read_i16)
+                    .collect(),
+            ),
+            TCA_NETEM_REORDER => Self::Reorder(TcNetemProbability::parse(
+                &TcNetemProbabilityBuffer::new_checked(payload)?,
+            )?),
+            TCA_NETEM_CORRUPT => Self::Corrupt(TcNetemProbability::parse(
+                &TcNetemProbabilityBuffer::new_checked(payload)?,
+            )?),
+            TCA_NETEM_LOSS => {
+                let mut models = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    models.push(TcNetemLossModel::parse(&nla?)?);
+                }
+                Self::Loss(models)
+            }
+            TCA_NETEM_RATE => Self::Rate(TcNetemRate::parse(
+                &TcNetemRateBuffer::new_checked(payload)?,
+            )?),
+            TCA_NETEM_ECN => Self::Ecn(parse_u32(payload)? > 0),
+            TCA_NETEM_RATE64 => Self::Rate64(parse_u64(payload)?),
+            TCA_NETEM_LATENCY64 => Self::Latency64(parse_u64(payload)? as i64),
+            TCA_NETEM_JITTER64 => Self::Jitter64(parse_u64(payload)? as i64),
+            TCA_NETEM_SLOT => Self::Slot(TcNetemSlot::parse(
+                &TcNetemSlotBuffer::new_checked(payload)?,
+            )?),
+            TCA_NETEM_PRNG_SEED => Self::PrngSeed(parse_u64(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+/// Correlated loss models nested in `TCA_NETEM_LOSS`. Probabilities are
+/// scaled so that `u32::MAX` means 100%.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcNetemLossModel {
+    /// 4-state Markov chain model(`struct tc_netem_gimodel`) with the
+    /// transition probabilities `[p13, p31, p32, p14, p23]`
+    GeneralIntuitive([u32; 5]),
+    /// Gilbert-Elliot model(`struct tc_netem_gemodel`) with
+    /// `[p, r, h, k1]`
+    GilbertElliot([u32; 4]),
+    Other(DefaultNla),
+}
+
+impl Nla for TcNetemLossModel {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::GeneralIntuitive(v) => v.len() * 4,
+            Self::GilbertElliot(v) => v.len() * 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::GeneralIntuitive(v) => {
+                NativeEndian::write_u32_into(v, buffer)
+            }
+            Self::GilbertElliot(v) => NativeEndian::write_u32_into(v, buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::GeneralIntuitive(_) => NETEM_LOSS_GI,
+            Self::GilbertElliot(_) => NETEM_LOSS_GE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcNetemLossModel
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NETEM_LOSS_GI => {
+                if payload.len() < 20 {
+                    return Err(AxError::InvalidInput);
+                }
+                let mut v = [0u32; 5];
+                NativeEndian::read_u32_into(&payload[..20], &mut v);
+                Self::GeneralIntuitive(v)
+            }
+            NETEM_LOSS_GE => {
+                if payload.len() < 16 {
+                    return Err(AxError::InvalidInput);
+                }
+                let mut v = [0u32; 4];
+                NativeEndian::read_u32_into(&payload[..16], &mut v);
+                Self::GilbertElliot(v)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
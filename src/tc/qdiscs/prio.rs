@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TC_PRIO_MAX: usize = 15;
+const TC_QDISC_PRIO_LEN: usize = 20;
+
+buffer!(TcQdiscPrioBuffer(TC_QDISC_PRIO_LEN) {
+    bands: (i32, 0..4),
+    priomap: (slice, 4..TC_QDISC_PRIO_LEN),
+});
+
+/// Options(`struct tc_prio_qopt`) of `prio` and `pfifo_fast` qdisc. Unlike
+/// most qdiscs, these are not nested attributes but the raw payload of
+/// `TCA_OPTIONS`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcQdiscPrio {
+    /// Number of bands
+    pub bands: i32,
+    /// Map from packet priority(`TC_PRIO_*`) to band
+    pub priomap: [u8; TC_PRIO_MAX + 1],
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcQdiscPrioBuffer<&'a T>>
+    for TcQdiscPrio
+{
+    fn parse(buf: &TcQdiscPrioBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut priomap = [0u8; TC_PRIO_MAX + 1];
+        priomap.copy_from_slice(buf.priomap());
+        Ok(Self {
+            bands: buf.bands(),
+            priomap,
+        })
+    }
+}
+
+impl Emitable for TcQdiscPrio {
+    fn buffer_len(&self) -> usize {
+        TC_QDISC_PRIO_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcQdiscPrioBuffer::new(buffer);
+        buffer.set_bands(self.bands);
+        buffer.priomap_mut().copy_from_slice(&self.priomap);
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tc::{rate_spec::TC_RATE_SPEC_LEN, TcRateSpec, TcRateSpecBuffer};
+
+const TCA_TBF_PARMS: u16 = 1;
+const TCA_TBF_RTAB: u16 = 2;
+const TCA_TBF_PTAB: u16 = 3;
+const TCA_TBF_RATE64: u16 = 4;
+const TCA_TBF_PRATE64: u16 = 5;
+const TCA_TBF_BURST: u16 = 6;
+const TCA_TBF_PBURST: u16 = 7;
+// const TCA_TBF_PAD: u16 = 8;
+
+const TC_TBF_QOPT_LEN: usize = TC_RATE_SPEC_LEN * 2 + 12;
+
+buffer!(TcTbfQoptBuffer(TC_TBF_QOPT_LEN) {
+    rate: (slice, 0..TC_RATE_SPEC_LEN),
+    peakrate: (slice, TC_RATE_SPEC_LEN..(TC_RATE_SPEC_LEN * 2)),
+    limit: (u32, (TC_RATE_SPEC_LEN * 2)..(TC_RATE_SPEC_LEN * 2 + 4)),
+    buffer: (u32, (TC_RATE_SPEC_LEN * 2 + 4)..(TC_RATE_SPEC_LEN * 2 + 8)),
+    mtu: (u32, (TC_RATE_SPEC_LEN * 2 + 8)..TC_TBF_QOPT_LEN),
+});
+
+/// Token bucket filter parameters(`struct tc_tbf_qopt`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcTbfQopt {
+    pub rate: TcRateSpec,
+    pub peakrate: TcRateSpec,
+    /// Queue limit in bytes
+    pub limit: u32,
+    /// Bucket size in time units(`psched` ticks)
+    pub buffer: u32,
+    /// Size of the peak rate bucket in time units(`psched` ticks)
+    pub mtu: u32,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcTbfQoptBuffer<&'a T>>
+    for TcTbfQopt
+{
+    fn parse(buf: &TcTbfQoptBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.rate()))?,
+            peakrate: TcRateSpec::parse(&TcRateSpecBuffer::new(
+                buf.peakrate(),
+            ))?,
+            limit: buf.limit(),
+            buffer: buf.buffer(),
+            mtu: buf.mtu(),
+        })
+    }
+}
+
+impl Emitable for TcTbfQopt {
+    fn buffer_len(&self) -> usize {
+        TC_TBF_QOPT_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcTbfQoptBuffer::new(buffer);
+        self.rate.emit(buffer.rate_mut());
+        self.peakrate.emit(buffer.peakrate_mut());
+        buffer.set_limit(self.limit);
+        buffer.set_buffer(self.buffer);
+        buffer.set_mtu(self.mtu);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcQdiscTbfOption {
+    Parms(TcTbfQopt),
+    /// Rate table, 256 of `u32` transmission times
+    Rtab(Vec<u8>),
+    /// Peak rate table, 256 of `u32` transmission times
+    Ptab(Vec<u8>),
+    /// Rate in bytes per second when it does not fit into `u32`
+    Rate64(u64),
+    /// Peak rate in bytes per second when it does not fit into `u32`
+    Prate64(u64),
+    /// Bucket size in bytes
+    Burst(u32),
+    /// Peak rate bucket size in bytes
+    Pburst(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for TcQdiscTbfOption {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Parms(v) => v.buffer_len(),
+            Self::Rtab(v) | Self::Ptab(v) => v.len(),
+            Self::Rate64(_) | Self::Prate64(_) => 8,
+            Self::Burst(_) | Self::Pburst(_) => 4,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Parms(v) => v.emit(buffer),
+            Self::Rtab(v) | Self::Ptab(v) => {
+                buffer.copy_from_slice(v.as_slice())
+            }
+            Self::Rate64(v) | Self::Prate64(v) => {
+                NativeEndian::write_u64(buffer, *v)
+            }
+            Self::Burst(v) | Self::Pburst(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Parms(_) => TCA_TBF_PARMS,
+            Self::Rtab(_) => TCA_TBF_RTAB,
+            Self::Ptab(_) => TCA_TBF_PTAB,
+            Self::Rate64(_) => TCA_TBF_RATE64,
+            Self::Prate64(_) => TCA_TBF_PRATE64,
+            Self::Burst(_) => TCA_TBF_BURST,
+            Self::Pburst(_) => TCA_TBF_PBURST,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TcQdiscTbfOption
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_TBF_PARMS => Self::Parms(TcTbfQopt::parse(
+                &TcTbfQoptBuffer::new_checked(payload)?,
+            )?),
+            TCA_TBF_RTAB => Self::Rtab(payload.to_vec()),
+            TCA_TBF_PTAB => Self::Ptab(payload.to_vec()),
+            TCA_TBF_RATE64 => Self::Rate64(parse_u64(payload)?),
+            TCA_TBF_PRATE64 => Self::Prate64(parse_u64(payload)?),
+            TCA_TBF_BURST => Self::Burst(parse_u32(payload)?),
+            TCA_TBF_PBURST => Self::Pburst(parse_u32(payload)?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub(crate) const TC_RATE_SPEC_LEN: usize = 12;
+
+buffer!(TcRateSpecBuffer(TC_RATE_SPEC_LEN) {
+    cell_log: (u8, 0),
+    linklayer: (u8, 1),
+    overhead: (u16, 2..4),
+    cell_align: (i16, 4..6),
+    mpu: (u16, 6..8),
+    rate: (u32, 8..TC_RATE_SPEC_LEN),
+});
+
+/// Rate specification(`struct tc_ratespec`) used by shaping qdiscs and
+/// policers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcRateSpec {
+    pub cell_log: u8,
+    /// `TC_LINKLAYER_*`, 1 for ethernet and 2 for ATM.
+    pub linklayer: u8,
+    pub overhead: u16,
+    pub cell_align: i16,
+    pub mpu: u16,
+    /// Rate in bytes per second. When the rate is bigger than `u32::MAX`,
+    /// this is set to `u32::MAX` and the real rate is placed in a dedicated
+    /// 64 bits attribute.
+    pub rate: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcRateSpecBuffer<T>> for TcRateSpec {
+    fn parse(buf: &TcRateSpecBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            cell_log: buf.cell_log(),
+            linklayer: buf.linklayer(),
+            overhead: buf.overhead(),
+            cell_align: buf.cell_align(),
+            mpu: buf.mpu(),
+            rate: buf.rate(),
+        })
+    }
+}
+
+impl Emitable for TcRateSpec {
+    fn buffer_len(&self) -> usize {
+        TC_RATE_SPEC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcRateSpecBuffer::new(buffer);
+        buffer.set_cell_log(self.cell_log);
+        buffer.set_linklayer(self.linklayer);
+        buffer.set_overhead(self.overhead);
+        buffer.set_cell_align(self.cell_align);
+        buffer.set_mpu(self.mpu);
+        buffer.set_rate(self.rate);
+    }
+}
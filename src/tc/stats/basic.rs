@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TC_STATS_BASIC_LEN: usize = 12;
+
+buffer!(TcStatsBasicBuffer(TC_STATS_BASIC_LEN) {
+    bytes: (u64, 0..8),
+    packets: (u32, 8..12),
+});
+
+/// Byte/Packet throughput statistics(`struct gnet_stats_basic`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcStatsBasic {
+    /// number of seen bytes
+    pub bytes: u64,
+    /// number of seen packets
+    pub packets: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcStatsBasicBuffer<T>> for TcStatsBasic {
+    fn parse(buf: &TcStatsBasicBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(TcStatsBasic {
+            bytes: buf.bytes(),
+            packets: buf.packets(),
+        })
+    }
+}
+
+impl Emitable for TcStatsBasic {
+    fn buffer_len(&self) -> usize {
+        TC_STATS_BASIC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcStatsBasicBuffer::new(buffer);
+        buffer.set_bytes(self.bytes);
+        buffer.set_packets(self.packets);
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod basic;
+mod compat;
+mod queue;
+mod rate_est;
+mod stats2;
+mod xstats;
+
+pub use self::basic::{TcStatsBasic, TcStatsBasicBuffer};
+pub use self::compat::{TcStats, TcStatsBuffer};
+pub use self::queue::{TcStatsQueue, TcStatsQueueBuffer};
+pub use self::rate_est::{TcStatsRateEst, TcStatsRateEstBuffer};
+pub use self::stats2::TcStats2;
+pub use self::xstats::TcXstats;
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const TC_STATS_RATE_EST_LEN: usize = 8;
+
+buffer!(TcStatsRateEstBuffer(TC_STATS_RATE_EST_LEN) {
+    bps: (u32, 0..4),
+    pps: (u32, 4..8),
+});
+
+/// Rate estimator statistics(`struct gnet_stats_rate_est`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct TcStatsRateEst {
+    /// current byte rate
+    pub bps: u32,
+    /// current packet rate
+    pub pps: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcStatsRateEstBuffer<T>> for TcStatsRateEst {
+    fn parse(buf: &TcStatsRateEstBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            bps: buf.bps(),
+            pps: buf.pps(),
+        })
+    }
+}
+
+impl Emitable for TcStatsRateEst {
+    fn buffer_len(&self) -> usize {
+        TC_STATS_RATE_EST_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcStatsRateEstBuffer::new(buffer);
+        buffer.set_bps(self.bps);
+        buffer.set_pps(self.pps);
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+use super::{
+    TcStatsBasic, TcStatsBasicBuffer, TcStatsQueue, TcStatsQueueBuffer,
+    TcStatsRateEst, TcStatsRateEstBuffer, TcXstats,
+};
+
+const TCA_STATS_BASIC: u16 = 1;
+const TCA_STATS_RATE_EST: u16 = 2;
+const TCA_STATS_QUEUE: u16 = 3;
+const TCA_STATS_APP: u16 = 4;
+// const TCA_STATS_RATE_EST64: u16 = 5;
+// const TCA_STATS_PAD: u16 = 6;
+const TCA_STATS_BASIC_HW: u16 = 7;
+// const TCA_STATS_PKT64: u16 = 8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcStats2 {
+    /// Qdisc or class specific statistics, identical to the payload of
+    /// `TCA_XSTATS`.
+    App(TcXstats),
+    Basic(TcStatsBasic),
+    RateEst(TcStatsRateEst),
+    Queue(TcStatsQueue),
+    /// Statistics of packets processed by hardware offload
+    BasicHw(TcStatsBasic),
+    Other(DefaultNla),
+}
+
+impl Nla for TcStats2 {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::App(v) => v.buffer_len(),
+            Self::Basic(v) | Self::BasicHw(v) => v.buffer_len(),
+            Self::RateEst(v) => v.buffer_len(),
+            Self::Queue(v) => v.buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::App(v) => v.emit(buffer),
+            Self::Basic(v) | Self::BasicHw(v) => v.emit(buffer),
+            Self::RateEst(v) => v.emit(buffer),
+            Self::Queue(v) => v.emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::App(_) => TCA_STATS_APP,
+            Self::Basic(_) => TCA_STATS_BASIC,
+            Self::RateEst(_) => TCA_STATS_RATE_EST,
+            Self::Queue(_) => TCA_STATS_QUEUE,
+            Self::BasicHw(_) => TCA_STATS_BASIC_HW,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+/// The parameter is the kind of qdisc, class or action, which decides the
+/// layout of `TCA_STATS_APP`.
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str>
+    for TcStats2
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        kind: &str,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_STATS_APP => Self::App(TcXstats::parse_with_param(buf, kind)?),
+            TCA_STATS_BASIC => Self::Basic(TcStatsBasic::parse(
+                &TcStatsBasicBuffer::new_checked(payload)?,
+            )?),
+            TCA_STATS_RATE_EST => Self::RateEst(TcStatsRateEst::parse(
+                &TcStatsRateEstBuffer::new_checked(payload)?,
+            )?),
+            TCA_STATS_QUEUE => Self::Queue(TcStatsQueue::parse(
+                &TcStatsQueueBuffer::new_checked(payload)?,
+            )?),
+            TCA_STATS_BASIC_HW => Self::BasicHw(TcStatsBasic::parse(
+                &TcStatsBasicBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    nla::NlaBuffer, DecodeError, Emitable, Parseable, ParseableParametrized,
+};
+
+use crate::tc::{TcHtbXstats, TcHtbXstatsBuffer};
+
+const HTB: &str = "htb";
+
+// This is filled by qdisc via `struct Qdisc_ops.dump_stats` or by class
+// via `struct Qdisc_class_ops.dump_stats`, the layout depends on the kind
+// of qdisc.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TcXstats {
+    Htb(TcHtbXstats),
+    Other(Vec<u8>),
+}
+
+impl Emitable for TcXstats {
+    fn buffer_len(&self) -> usize {
+        match self {
+            Self::Htb(v) => v.buffer_len(),
+            Self::Other(v) => v.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Htb(v) => v.emit(buffer),
+            Self::Other(v) => buffer.copy_from_slice(v.as_slice()),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, &str>
+    for TcXstats
+{
+    fn parse_with_param(
+        buf: &NlaBuffer<&'a T>,
+        kind: &str,
+    ) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match kind {
+            HTB => Self::Htb(TcHtbXstats::parse(
+                &TcHtbXstatsBuffer::new_checked(payload)?,
+            )?),
+            _ => Self::Other(payload.to_vec()),
+        })
+    }
+}
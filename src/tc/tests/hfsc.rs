@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use netlink_packet_utils::ParseableParametrized;
+
+use crate::tc::{
+    TcAttribute, TcHandle, TcHeader, TcMessage, TcOptions, TcStats, TcStats2,
+    TcStatsBasic, TcStatsQueue, TcXstats,
+};
+use crate::{AddressFamily, RouteNetlinkMessage, RouteNetlinkMessageBuffer};
+
+const RTM_NEWTCLASS: u16 = 40;
+
+// Reply of `tc class show dev eth1` for the root class of `hfsc` qdisc
+// `1:`, which has no service curve and is dumped with empty TCA_OPTIONS.
+#[test]
+fn test_hfsc_root_class() {
+    let raw: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x09, 0x00, 0x01, 0x00,
+        0x68, 0x66, 0x73, 0x63, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x02, 0x00,
+        0x4c, 0x00, 0x07, 0x00, 0x14, 0x00, 0x01, 0x00, 0xdc, 0x05, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x18, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x04, 0x00, 0xdc, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00, 0x2c, 0x00, 0x03, 0x00, 0xdc, 0x05, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x1c, 0x00, 0x04, 0x00, 0xdc, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let app_stats = vec![
+        0xdc, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ];
+
+    let expected = RouteNetlinkMessage::NewTrafficClass(TcMessage {
+        header: TcHeader {
+            family: AddressFamily::Unspec,
+            index: 2,
+            handle: TcHandle::new(1, 0),
+            parent: TcHandle::ROOT,
+            info: 0,
+        },
+        attributes: vec![
+            TcAttribute::Kind("hfsc".to_string()),
+            TcAttribute::Options(TcOptions::HfscClass(vec![])),
+            TcAttribute::Stats2(vec![
+                TcStats2::Basic(TcStatsBasic {
+                    bytes: 1500,
+                    packets: 1,
+                }),
+                TcStats2::Queue(TcStatsQueue::default()),
+                TcStats2::App(TcXstats::Other(app_stats.clone())),
+            ]),
+            TcAttribute::Stats(TcStats {
+                bytes: 1500,
+                packets: 1,
+                ..Default::default()
+            }),
+            TcAttribute::Xstats(TcXstats::Other(app_stats)),
+        ],
+    });
+
+    assert_eq!(
+        expected,
+        RouteNetlinkMessage::parse_with_param(
+            &RouteNetlinkMessageBuffer::new(&raw),
+            RTM_NEWTCLASS
+        )
+        .unwrap()
+    );
+}
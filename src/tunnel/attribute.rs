@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u32, parse_u64},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::ip::{parse_ipv4_addr, parse_ipv6_addr};
+
+const VXLAN_VNIFILTER_ENTRY: u16 = 1;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum TunnelAttribute {
+    /// VNI or VNI range filtered by VXLAN device with `vnifilter` on
+    VniFilterEntry(Vec<VniFilterEntryAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for TunnelAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::VniFilterEntry(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::VniFilterEntry(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::VniFilterEntry(_) => VXLAN_VNIFILTER_ENTRY | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for TunnelAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VXLAN_VNIFILTER_ENTRY => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(VniFilterEntryAttribute::parse(&nla?)?);
+                }
+                Self::VniFilterEntry(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const VXLAN_VNIFILTER_ENTRY_START: u16 = 1;
+const VXLAN_VNIFILTER_ENTRY_END: u16 = 2;
+const VXLAN_VNIFILTER_ENTRY_GROUP: u16 = 3;
+const VXLAN_VNIFILTER_ENTRY_GROUP6: u16 = 4;
+const VXLAN_VNIFILTER_ENTRY_STATS: u16 = 5;
+
+/// Member of [TunnelAttribute::VniFilterEntry].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum VniFilterEntryAttribute {
+    /// VNI, the first VNI of range if [Self::End] exists.
+    Start(u32),
+    /// The last VNI of range
+    End(u32),
+    /// Multicast group or remote address
+    Group(Ipv4Addr),
+    /// IPv6 multicast group or remote address
+    Group6(Ipv6Addr),
+    Stats(Vec<VniFilterStatsAttribute>),
+    Other(DefaultNla),
+}
+
+impl Nla for VniFilterEntryAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Start(_) | Self::End(_) | Self::Group(_) => 4,
+            Self::Group6(_) => 16,
+            Self::Stats(attrs) => attrs.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Start(v) | Self::End(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Self::Group(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Group6(v) => buffer.copy_from_slice(&v.octets()),
+            Self::Stats(attrs) => attrs.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Start(_) => VXLAN_VNIFILTER_ENTRY_START,
+            Self::End(_) => VXLAN_VNIFILTER_ENTRY_END,
+            Self::Group(_) => VXLAN_VNIFILTER_ENTRY_GROUP,
+            Self::Group6(_) => VXLAN_VNIFILTER_ENTRY_GROUP6,
+            Self::Stats(_) => VXLAN_VNIFILTER_ENTRY_STATS | NLA_F_NESTED,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VniFilterEntryAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VXLAN_VNIFILTER_ENTRY_START => Self::Start(parse_u32(payload)?),
+            VXLAN_VNIFILTER_ENTRY_END => Self::End(parse_u32(payload)?),
+            VXLAN_VNIFILTER_ENTRY_GROUP => {
+                Self::Group(parse_ipv4_addr(payload)?)
+            }
+            VXLAN_VNIFILTER_ENTRY_GROUP6 => {
+                Self::Group6(parse_ipv6_addr(payload)?)
+            }
+            VXLAN_VNIFILTER_ENTRY_STATS => {
+                let mut attrs = Vec::new();
+                for nla in NlasIterator::new(payload) {
+                    attrs.push(VniFilterStatsAttribute::parse(&nla?)?);
+                }
+                Self::Stats(attrs)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
+
+const VNIFILTER_ENTRY_STATS_RX_BYTES: u16 = 1;
+const VNIFILTER_ENTRY_STATS_RX_PKTS: u16 = 2;
+const VNIFILTER_ENTRY_STATS_RX_DROPS: u16 = 3;
+const VNIFILTER_ENTRY_STATS_RX_ERRORS: u16 = 4;
+const VNIFILTER_ENTRY_STATS_TX_BYTES: u16 = 5;
+const VNIFILTER_ENTRY_STATS_TX_PKTS: u16 = 6;
+const VNIFILTER_ENTRY_STATS_TX_DROPS: u16 = 7;
+const VNIFILTER_ENTRY_STATS_TX_ERRORS: u16 = 8;
+// const VNIFILTER_ENTRY_STATS_PAD: u16 = 9;
+
+/// Member of [VniFilterEntryAttribute::Stats].
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum VniFilterStatsAttribute {
+    RxBytes(u64),
+    RxPackets(u64),
+    RxDrops(u64),
+    RxErrors(u64),
+    TxBytes(u64),
+    TxPackets(u64),
+    TxDrops(u64),
+    TxErrors(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for VniFilterStatsAttribute {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Other(attr) => attr.value_len(),
+            _ => 8,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::RxBytes(v)
+            | Self::RxPackets(v)
+            | Self::RxDrops(v)
+            | Self::RxErrors(v)
+            | Self::TxBytes(v)
+            | Self::TxPackets(v)
+            | Self::TxDrops(v)
+            | Self::TxErrors(v) => NativeEndian::write_u64(buffer, *v),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::RxBytes(_) => VNIFILTER_ENTRY_STATS_RX_BYTES,
+            Self::RxPackets(_) => VNIFILTER_ENTRY_STATS_RX_PKTS,
+            Self::RxDrops(_) => VNIFILTER_ENTRY_STATS_RX_DROPS,
+            Self::RxErrors(_) => VNIFILTER_ENTRY_STATS_RX_ERRORS,
+            Self::TxBytes(_) => VNIFILTER_ENTRY_STATS_TX_BYTES,
+            Self::TxPackets(_) => VNIFILTER_ENTRY_STATS_TX_PKTS,
+            Self::TxDrops(_) => VNIFILTER_ENTRY_STATS_TX_DROPS,
+            Self::TxErrors(_) => VNIFILTER_ENTRY_STATS_TX_ERRORS,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>>
+    for VniFilterStatsAttribute
+{
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            VNIFILTER_ENTRY_STATS_RX_BYTES => {
+                Self::RxBytes(parse_u64(payload)?)
+            }
+            VNIFILTER_ENTRY_STATS_RX_PKTS => {
+                Self::RxPackets(parse_u64(payload)?)
+            }
+            VNIFILTER_ENTRY_STATS_RX_DROPS => {
+                Self::RxDrops(parse_u64(payload)?)
+            }
+            VNIFILTER_ENTRY_STATS_RX_ERRORS => {
+                Self::RxErrors(parse_u64(payload)?)
+            }
+            VNIFILTER_ENTRY_STATS_TX_BYTES => {
+                Self::TxBytes(parse_u64(payload)?)
+            }
+            VNIFILTER_ENTRY_STATS_TX_PKTS => {
+                Self::TxPackets(parse_u64(payload)?)
+            }
+            VNIFILTER_ENTRY_STATS_TX_DROPS => {
+                Self::TxDrops(parse_u64(payload)?)
+            }
+            VNIFILTER_ENTRY_STATS_TX_ERRORS => {
+                Self::TxErrors(parse_u64(payload)?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf)?),
+        })
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_utils::{
+    nla::{NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::AddressFamily;
+
+const TUNNEL_HEADER_LEN: usize = 8;
+
+buffer!(TunnelMessageBuffer(TUNNEL_HEADER_LEN) {
+    family: (u8, 0),
+    flags: (u8, 1),
+    reserved: (u16, 2..4),
+    ifindex: (u32, 4..TUNNEL_HEADER_LEN),
+    payload: (slice, TUNNEL_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> TunnelMessageBuffer<&'a T> {
+    pub fn attributes(
+        &self,
+    ) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
+
+/// High level representation of `RTM_NEWTUNNEL`, `RTM_DELTUNNEL` and
+/// `RTM_GETTUNNEL` messages headers(`struct tunnel_msg`).
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TunnelHeader {
+    /// Always `AF_BRIDGE`.
+    pub family: AddressFamily,
+    pub flags: TunnelMessageFlags,
+    /// Interface index of the VXLAN device. Dump request could use 0 for
+    /// all interfaces.
+    pub ifindex: u32,
+}
+
+impl Emitable for TunnelHeader {
+    fn buffer_len(&self) -> usize {
+        TUNNEL_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = TunnelMessageBuffer::new(buffer);
+        packet.set_family(self.family.into());
+        packet.set_flags(self.flags.bits());
+        packet.set_reserved(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<TunnelMessageBuffer<T>> for TunnelHeader {
+    fn parse(buf: &TunnelMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(TunnelHeader {
+            family: buf.family().into(),
+            flags: TunnelMessageFlags::from_bits_retain(buf.flags()),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
+
+const TUNNEL_MSG_FLAG_STATS: u8 = 1 << 0;
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub struct TunnelMessageFlags: u8 {
+        /// Include [crate::tunnel::VniFilterEntryAttribute::Stats] in dump
+        const Stats = TUNNEL_MSG_FLAG_STATS;
+        const _ = !0;
+    }
+}
+
+impl Default for TunnelMessageFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+use netlink_packet_utils::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+use crate::tunnel::{TunnelAttribute, TunnelHeader, TunnelMessageBuffer};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[non_exhaustive]
+pub struct TunnelMessage {
+    pub header: TunnelHeader,
+    pub attributes: Vec<TunnelAttribute>,
+}
+
+impl Emitable for TunnelMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.attributes.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.attributes
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<TunnelMessageBuffer<&'a T>>
+    for TunnelMessage
+{
+    fn parse(buf: &TunnelMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let header = TunnelHeader::parse(buf)?;
+        let mut attributes = Vec::new();
+        for nla_buf in buf.attributes() {
+            attributes.push(TunnelAttribute::parse(&nla_buf?)?);
+        }
+        Ok(TunnelMessage { header, attributes })
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod attribute;
+mod header;
+mod message;
+
+pub use self::attribute::{
+    TunnelAttribute, VniFilterEntryAttribute, VniFilterStatsAttribute,
+};
+pub use self::header::{TunnelHeader, TunnelMessageBuffer, TunnelMessageFlags};
+pub use self::message::TunnelMessage;